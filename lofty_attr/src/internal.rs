@@ -60,11 +60,19 @@ pub(crate) fn init_write_lookup(
 		});
 	} else {
 		insert!(map, ID3v2, {
-			crate::id3::v2::tag::Id3v2TagRef {
-				flags: crate::id3::v2::ID3v2TagFlags::default(),
-				frames: crate::id3::v2::tag::tag_frames(tag),
+			// Native data has to be written through the original format, see `Tag#native-data`
+			if tag.has_passthrough() {
+				crate::traits::TagExt::save_to(
+					&crate::id3::v2::ID3v2Tag::from(tag.clone()),
+					data,
+				)
+			} else {
+				crate::id3::v2::tag::Id3v2TagRef {
+					flags: crate::id3::v2::ID3v2TagFlags::default(),
+					frames: crate::id3::v2::tag::tag_frames(tag),
+				}
+				.write_to(data)
 			}
-			.write_to(data)
 		});
 	}
 
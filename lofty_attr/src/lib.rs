@@ -186,6 +186,27 @@ fn parse(input: DeriveInput, errors: &mut Vec<syn::Error>) -> proc_macro2::Token
 
 	let getters = get_getters(&tag_fields, &struct_name);
 
+	let into_taggedfile_impl = if should_impl_into_taggedfile(&input.attrs) {
+		quote! {
+			impl std::convert::From<#struct_name> for lofty::TaggedFile {
+				fn from(input: #struct_name) -> Self {
+					lofty::TaggedFile::new(
+						lofty::FileType::#file_type,
+						lofty::FileProperties::from(input.properties),
+						{
+							let mut tags: Vec<lofty::Tag> = Vec::new();
+							#( #conditions )*
+
+							tags
+						}
+					)
+				}
+			}
+		}
+	} else {
+		proc_macro2::TokenStream::new()
+	};
+
 	let mut ret = quote! {
 		#assert_properties_impl
 
@@ -193,20 +214,7 @@ fn parse(input: DeriveInput, errors: &mut Vec<syn::Error>) -> proc_macro2::Token
 
 		#audiofile_impl
 
-		impl std::convert::From<#struct_name> for lofty::TaggedFile {
-			fn from(input: #struct_name) -> Self {
-				lofty::TaggedFile::new(
-					lofty::FileType::#file_type,
-					lofty::FileProperties::from(input.properties),
-					{
-						let mut tags: Vec<lofty::Tag> = Vec::new();
-						#( #conditions )*
-
-						tags
-					}
-				)
-			}
-		}
+		#into_taggedfile_impl
 
 		#( #getters )*
 	};
@@ -294,6 +302,16 @@ fn should_impl_audiofile(attrs: &[Attribute]) -> bool {
 	true
 }
 
+fn should_impl_into_taggedfile(attrs: &[Attribute]) -> bool {
+	for attr in attrs {
+		if util::has_path_attr(attr, "no_into_taggedfile_impl") {
+			return false;
+		}
+	}
+
+	true
+}
+
 fn get_getters<'a>(
 	tag_fields: &'a [FieldContents],
 	struct_name: &'a Ident,
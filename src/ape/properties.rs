@@ -9,6 +9,7 @@ use std::time::Duration;
 use byteorder::{LittleEndian, ReadBytesExt};
 
 #[derive(Clone, Debug, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[non_exhaustive]
 /// An APE file's audio properties
 pub struct ApeProperties {
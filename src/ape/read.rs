@@ -8,9 +8,9 @@ use crate::error::Result;
 use crate::id3::v1::tag::ID3v1Tag;
 #[cfg(feature = "id3v2")]
 use crate::id3::v2::{read::parse_id3v2, tag::ID3v2Tag};
-use crate::id3::{find_id3v1, find_id3v2, find_lyrics3v2, ID3FindResults};
+use crate::id3::{find_id3v1, find_id3v2, find_lyrics3, ID3FindResults};
 use crate::macros::decode_err;
-use crate::probe::ParseOptions;
+use crate::probe::{ParseOptions, ParsingMode};
 
 use std::io::{Read, Seek, SeekFrom};
 
@@ -46,7 +46,7 @@ where
 		{
 			let reader = &mut &*content;
 
-			let id3v2 = parse_id3v2(reader, header)?;
+			let id3v2 = parse_id3v2(reader, header, parse_options.parsing_mode)?;
 			id3v2_tag = Some(id3v2)
 		}
 	}
@@ -113,11 +113,11 @@ where
 		}
 	}
 
-	// Next, check for a Lyrics3v2 tag, and skip over it, as it's no use to us
-	let ID3FindResults(lyrics3_header, lyrics3v2_size) = find_lyrics3v2(data)?;
+	// Next, check for a Lyrics3 tag, and skip over it, as it's no use to us
+	let ID3FindResults(lyrics3_header, _) = find_lyrics3(data, false, ParsingMode::Strict)?;
 
-	if lyrics3_header.is_some() {
-		stream_len -= u64::from(lyrics3v2_size)
+	if let Some(lyrics3_header) = lyrics3_header {
+		stream_len -= u64::from(lyrics3_header.size)
 	}
 
 	// Next, search for an APE tag footer
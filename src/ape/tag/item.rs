@@ -7,6 +7,7 @@ use crate::tag::TagType;
 use std::convert::TryFrom;
 
 #[derive(Debug, PartialEq, Eq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 /// Represents an `APE` tag item
 ///
 /// The restrictions for `APE` lie in the key rather than the value,
@@ -81,6 +82,30 @@ impl TryFrom<TagItem> for ApeItem {
 	}
 }
 
+// Items are deserialized through `ApeItem::new`, so invalid keys are rejected
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for ApeItem {
+	fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+	where
+		D: serde::Deserializer<'de>,
+	{
+		#[derive(serde::Deserialize)]
+		#[serde(rename = "ApeItem")]
+		struct ApeItemFields {
+			read_only: bool,
+			key: String,
+			value: ItemValue,
+		}
+
+		let fields = ApeItemFields::deserialize(deserializer)?;
+
+		let mut item = Self::new(fields.key, fields.value).map_err(serde::de::Error::custom)?;
+		item.read_only = fields.read_only;
+
+		Ok(item)
+	}
+}
+
 pub(crate) struct ApeItemRef<'a> {
 	pub read_only: bool,
 	pub key: &'a str,
@@ -4,11 +4,14 @@ mod write;
 
 use crate::ape::tag::item::{ApeItem, ApeItemRef};
 use crate::error::{LoftyError, Result};
-use crate::tag::item::{ItemKey, ItemValue, TagItem};
+use crate::tag::item::{ItemKey, ItemValue, ItemValueRef, TagItem};
+use crate::tag::rating::Rating;
+use crate::tag::timestamp::{year_from_date, Timestamp};
+use crate::tag::utils;
 use crate::tag::{Tag, TagType};
 use crate::traits::{Accessor, TagExt};
 
-use std::convert::TryInto;
+use std::borrow::Cow;
 use std::fs::{File, OpenOptions};
 use std::io::Write;
 use std::path::Path;
@@ -44,10 +47,36 @@ macro_rules! impl_accessor {
 				}
 			)+
 		}
-	}
+	};
+	(@TYPED $($name:ident<$ty:ty> => $key:literal, $parse:path, $format:path;)+) => {
+		paste::paste! {
+			$(
+				fn $name(&self) -> Option<$ty> {
+					if let Some(ApeItem {
+						value: ItemValue::Text(ref text),
+						..
+					}) = self.get_key($key)
+					{
+						return $parse(text);
+					}
+
+					None
+				}
+
+				fn [<set_ $name>](&mut self, value: $ty) {
+					self.insert(ApeItem::text($key, $format(value)))
+				}
+
+				fn [<remove_ $name>](&mut self) {
+					self.remove_key($key);
+				}
+			)+
+		}
+	};
 }
 
 #[derive(Default, Debug, PartialEq, Eq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 /// An `APE` tag
 ///
 /// ## Supported file types
@@ -124,11 +153,34 @@ impl ApeTag {
 
 impl Accessor for ApeTag {
 	impl_accessor!(
-		artist  => "Artist";
-		title   => "Title";
-		album   => "Album";
-		genre   => "GENRE";
-		comment => "Comment";
+		artist                  => "Artist";
+		title                   => "Title";
+		album                   => "Album";
+		genre                   => "GENRE";
+		comment                 => "Comment";
+		album_artist            => "Album Artist" | "ALBUMARTIST";
+		composer                => "Composer";
+		lyrics                  => "Lyrics";
+		title_sort_order        => "TITLESORT";
+		artist_sort_order       => "ARTISTSORT";
+		album_sort_order        => "ALBUMSORT";
+		album_artist_sort_order => "ALBUMARTISTSORT";
+		composer_sort_order     => "COMPOSERSORT";
+		isrc                    => "ISRC";
+		label                   => "Label";
+		catalog_number          => "CatalogNumber";
+	);
+
+	impl_accessor!(
+		@TYPED
+		bpm<u32>                    => "BPM", utils::parse_number, utils::format_number;
+		replay_gain_track_gain<f32> => "REPLAYGAIN_TRACK_GAIN", utils::parse_replay_gain, utils::format_replay_gain;
+		replay_gain_track_peak<f32> => "REPLAYGAIN_TRACK_PEAK", utils::parse_replay_gain_peak, utils::format_replay_gain_peak;
+		replay_gain_album_gain<f32> => "REPLAYGAIN_ALBUM_GAIN", utils::parse_replay_gain, utils::format_replay_gain;
+		replay_gain_album_peak<f32> => "REPLAYGAIN_ALBUM_PEAK", utils::parse_replay_gain_peak, utils::format_replay_gain_peak;
+		compilation<bool>           => "Compilation", utils::parse_flag, utils::format_flag;
+		rating<Rating>              => "Rating", utils::parse_rating, utils::format_rating;
+		date<Timestamp>             => "Year", utils::parse_date, utils::format_date;
 	);
 
 	fn track(&self) -> Option<u32> {
@@ -202,7 +254,7 @@ impl Accessor for ApeTag {
 			..
 		}) = self.get_key("Year")
 		{
-			return text.chars().take(4).collect::<String>().parse::<u32>().ok();
+			return year_from_date(text);
 		}
 
 		None
@@ -314,6 +366,12 @@ impl From<ApeTag> for Tag {
 				{
 					continue
 				},
+				(k, ItemValue::Text(val)) if is_multi_value(&k) && val.contains('\0') => {
+					for value in val.split('\0') {
+						tag.items
+							.push(TagItem::new(k.clone(), ItemValue::Text(value.to_string())));
+					}
+				},
 				(k, _) => tag.items.push(TagItem::new(k, item.value)),
 			}
 		}
@@ -323,12 +381,14 @@ impl From<ApeTag> for Tag {
 }
 
 impl From<Tag> for ApeTag {
-	fn from(input: Tag) -> Self {
+	fn from(mut input: Tag) -> Self {
+		input.convert_typed_items_to_text();
+
 		let mut ape_tag = Self::default();
 
-		for item in input.items {
-			if let Ok(ape_item) = item.try_into() {
-				ape_tag.insert(ape_item)
+		for (key, value) in ape_items(&input.items, false) {
+			if let Ok(item) = ApeItem::new(key.to_string(), value.into()) {
+				ape_tag.insert(item)
 			}
 		}
 
@@ -346,6 +406,99 @@ impl From<Tag> for ApeTag {
 	}
 }
 
+// Keys that are stored as multiple values in a single item, separated by nulls
+fn is_multi_value(key: &ItemKey) -> bool {
+	matches!(
+		key,
+		ItemKey::TrackArtist
+			| ItemKey::AlbumArtist
+			| ItemKey::Arranger
+			| ItemKey::Writer
+			| ItemKey::Composer
+			| ItemKey::Conductor
+			| ItemKey::Engineer
+			| ItemKey::Lyricist
+			| ItemKey::MixDj
+			| ItemKey::MixEngineer
+			| ItemKey::InvolvedPeople
+			| ItemKey::MusicianCredits
+			| ItemKey::Performer
+			| ItemKey::Producer
+			| ItemKey::Remixer
+			| ItemKey::Genre
+			| ItemKey::Unknown(_)
+	)
+}
+
+// Converts the items to APE keys and values
+//
+// * "Track" and "Disc" are stored as "current/total"
+// * "Year" is read as a `RecordingDate`. `ItemKey::Year` (ex. from ID3v1) is only written in its
+//   place when there is no `RecordingDate`.
+// * Multiple values are joined for the keys in `is_multi_value`. For any other key, only the first
+//   value is kept, dropping any lower priority items mapped to the same key.
+fn ape_items(items: &[TagItem], allow_unknown: bool) -> Vec<(&str, ItemValueRef<'_>)> {
+	let first_text = |key: &ItemKey| {
+		items
+			.iter()
+			.find(|i| i.key() == key)
+			.and_then(|i| match i.value() {
+				ItemValue::Text(text) => Some(text.as_str()),
+				_ => None,
+			})
+	};
+
+	let mut ape_items = Vec::new();
+
+	for (key, current_key, total_key) in [
+		("Track", ItemKey::TrackNumber, ItemKey::TrackTotal),
+		("Disc", ItemKey::DiscNumber, ItemKey::DiscTotal),
+	] {
+		let value = match (first_text(&current_key), first_text(&total_key)) {
+			(current, Some(total)) => format!("{}/{}", current.unwrap_or("1"), total),
+			(Some(current), None) => current.to_string(),
+			(None, None) => continue,
+		};
+
+		ape_items.push((key, ItemValueRef::Text(Cow::Owned(value))));
+	}
+
+	let has_recording_date = items.iter().any(|i| i.key() == &ItemKey::RecordingDate);
+
+	for item in items {
+		let key = match item.key() {
+			ItemKey::TrackNumber
+			| ItemKey::TrackTotal
+			| ItemKey::DiscNumber
+			| ItemKey::DiscTotal => continue,
+			ItemKey::Year if has_recording_date => continue,
+			ItemKey::Year => "Year",
+			key => match key.map_key(TagType::APE, allow_unknown) {
+				Some(key) => key,
+				None => continue,
+			},
+		};
+
+		let existing = ape_items
+			.iter_mut()
+			.find(|(k, _)| k.eq_ignore_ascii_case(key));
+
+		match (existing, item.value()) {
+			(Some((_, ItemValueRef::Text(existing))), ItemValue::Text(value))
+				if is_multi_value(item.key()) =>
+			{
+				let existing = existing.to_mut();
+				existing.push('\0');
+				existing.push_str(value);
+			},
+			(Some(_), _) => {},
+			(None, value) => ape_items.push((key, value.into())),
+		}
+	}
+
+	ape_items
+}
+
 pub(crate) struct ApeTagRef<'a, I>
 where
 	I: Iterator<Item = ApeItemRef<'a>>,
@@ -371,13 +524,13 @@ where
 }
 
 pub(crate) fn tagitems_into_ape(items: &[TagItem]) -> impl Iterator<Item = ApeItemRef<'_>> {
-	items.iter().filter_map(|i| {
-		i.key().map_key(TagType::APE, true).map(|key| ApeItemRef {
+	ape_items(items, true)
+		.into_iter()
+		.map(|(key, value)| ApeItemRef {
 			read_only: false,
 			key,
-			value: (&i.item_value).into(),
+			value,
 		})
-	})
 }
 
 #[cfg(test)]
@@ -514,4 +667,84 @@ mod tests {
 		verify_key(&ape_tag, "Track", "1");
 		verify_key(&ape_tag, "Genre", "Classical");
 	}
+
+	#[test]
+	fn number_pairs_and_multiple_values() {
+		use crate::ItemKey;
+
+		let mut tag = Tag::new(TagType::APE);
+		tag.insert_text(ItemKey::TrackNumber, String::from("1"));
+		tag.insert_text(ItemKey::TrackTotal, String::from("10"));
+		tag.insert_text(ItemKey::DiscTotal, String::from("2"));
+		tag.insert_text(ItemKey::TrackTitle, String::from("Foo title"));
+		for artist in ["Foo artist", "Bar artist"] {
+			tag.push_item(crate::TagItem::new(
+				ItemKey::TrackArtist,
+				ItemValue::Text(String::from(artist)),
+			));
+		}
+
+		let ape_tag: ApeTag = tag.into();
+		for (key, value) in [
+			("Track", "1/10"),
+			("Disc", "1/2"),
+			("Title", "Foo title"),
+			("Artist", "Foo artist\0Bar artist"),
+		] {
+			assert_eq!(
+				ape_tag.get_key(key).map(ApeItem::value),
+				Some(&ItemValue::Text(String::from(value)))
+			);
+		}
+
+		let tag: Tag = ape_tag.into();
+		assert_eq!(tag.get_string(&ItemKey::TrackNumber), Some("1"));
+		assert_eq!(tag.get_string(&ItemKey::TrackTotal), Some("10"));
+		assert_eq!(tag.get_string(&ItemKey::DiscNumber), Some("1"));
+		assert_eq!(tag.get_string(&ItemKey::DiscTotal), Some("2"));
+		assert_eq!(
+			tag.get_strings(&ItemKey::TrackArtist).collect::<Vec<_>>(),
+			["Foo artist", "Bar artist"]
+		);
+	}
+
+	#[test]
+	#[cfg(feature = "id3v1")]
+	fn id3v1_year_to_ape() {
+		let mut id3v1 = crate::id3::v1::ID3v1Tag::default();
+		id3v1.year = Some(String::from("2022"));
+
+		let ape_tag: ApeTag = Tag::from(id3v1).into();
+		assert_eq!(
+			ape_tag.get_key("Year").map(ApeItem::value),
+			Some(&ItemValue::Text(String::from("2022")))
+		);
+
+		// "Year" is still read as the recording date
+		let tag: Tag = ape_tag.into();
+		assert_eq!(tag.get_string(&crate::ItemKey::RecordingDate), Some("2022"));
+	}
+
+	#[test]
+	fn year_and_recording_date_to_ape() {
+		use crate::ItemKey;
+
+		let mut tag = Tag::new(TagType::APE);
+		tag.insert_text(ItemKey::Year, String::from("2022"));
+		tag.insert_text(ItemKey::RecordingDate, String::from("2022-09-01"));
+
+		// The full date is preferred
+		let ape_tag: ApeTag = tag.into();
+		assert_eq!(
+			ape_tag.get_key("Year").map(ApeItem::value),
+			Some(&ItemValue::Text(String::from("2022-09-01")))
+		);
+
+		let tag: Tag = ape_tag.into();
+		assert_eq!(
+			tag.get_strings(&ItemKey::RecordingDate).collect::<Vec<_>>(),
+			["2022-09-01"]
+		);
+		assert_eq!(tag.get_string(&ItemKey::Year), None);
+	}
 }
@@ -5,9 +5,9 @@ use crate::ape::constants::APE_PREAMBLE;
 use crate::ape::header::read_ape_header;
 use crate::error::Result;
 use crate::file::FileType;
-use crate::id3::{find_id3v1, find_id3v2, find_lyrics3v2};
+use crate::id3::{find_id3v1, find_id3v2, find_lyrics3};
 use crate::macros::{decode_err, err};
-use crate::probe::Probe;
+use crate::probe::{ParsingMode, Probe};
 use crate::tag::item::ItemValueRef;
 
 use std::fs::File;
@@ -64,9 +64,9 @@ where
 		data.seek(SeekFrom::Current(-8))?;
 	}
 
-	// Skip over ID3v1 and Lyrics3v2 tags
+	// Skip over ID3v1 and Lyrics3 tags
 	find_id3v1(data, false)?;
-	find_lyrics3v2(data)?;
+	find_lyrics3(data, false, ParsingMode::Strict)?;
 
 	// In case there's no ape tag already, this is the spot it belongs
 	let ape_position = data.stream_position()?;
@@ -152,11 +152,11 @@ where
 	let mut item_count = 0_u32;
 
 	for item in peek {
-		let (mut flags, value) = match item.value {
+		let (mut flags, value) = match &item.value {
 			ItemValueRef::Binary(value) => {
 				tag_write.write_u32::<LittleEndian>(value.len() as u32)?;
 
-				(1_u32 << 1, value)
+				(1_u32 << 1, *value)
 			},
 			ItemValueRef::Text(value) => {
 				tag_write.write_u32::<LittleEndian>(value.len() as u32)?;
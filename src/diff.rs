@@ -0,0 +1,504 @@
+//! Tools to compare tags
+//!
+//! This allows for checking what changed between two versions of a tag, such as before
+//! and after a batch edit.
+//!
+//! * [`Tag::diff`] compares two [`Tag`]s item by item, by their [`ItemKey`]s
+//! * [`ID3v2Tag::diff`](crate::id3::v2::ID3v2Tag::diff) compares two ID3v2 tags frame by frame
+//!
+//! # Examples
+//!
+//! ```rust
+//! use lofty::diff::{Change, DiffOptions};
+//! use lofty::{Accessor, ItemKey, ItemValue, Tag, TagType};
+//!
+//! let mut old = Tag::new(TagType::VorbisComments);
+//! old.set_title(String::from("Foo title"));
+//! old.insert_text(ItemKey::TrackNumber, String::from("01/10"));
+//!
+//! let mut new = old.clone();
+//! new.set_title(String::from("Bar title"));
+//! new.insert_text(ItemKey::TrackNumber, String::from("1"));
+//! new.insert_text(ItemKey::TrackTotal, String::from("10"));
+//!
+//! let diff = old.diff(&new, DiffOptions::new().ignore_formatting(true));
+//!
+//! // Only the title actually changed
+//! assert_eq!(diff.items().len(), 1);
+//! assert_eq!(diff.items()[0].key, ItemKey::TrackTitle);
+//! assert_eq!(
+//! 	diff.items()[0].change,
+//! 	Change::Modified {
+//! 		old: vec![ItemValue::Text(String::from("Foo title"))],
+//! 		new: vec![ItemValue::Text(String::from("Bar title"))],
+//! 	}
+//! );
+//! ```
+
+use crate::picture::{Picture, PictureType};
+use crate::tag::item::{ItemKey, ItemValue};
+use crate::tag::Tag;
+
+use std::borrow::Cow;
+use std::collections::hash_map::DefaultHasher;
+use std::hash::{Hash, Hasher};
+
+/// A change between two versions of a value
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Change<T> {
+	/// The value only exists in the new version
+	Added(T),
+	/// The value only exists in the old version
+	Removed(T),
+	/// The value exists in both versions, but differs
+	Modified {
+		/// The old value
+		old: T,
+		/// The new value
+		new: T,
+	},
+}
+
+/// Options to control how tags are compared
+#[derive(Copy, Clone, Debug, Default)]
+#[non_exhaustive]
+pub struct DiffOptions {
+	pub(crate) ignore_formatting: bool,
+}
+
+impl DiffOptions {
+	/// Creates a new `DiffOptions`, alias for `Default` implementation
+	///
+	/// By default, all differences are reported.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use lofty::diff::DiffOptions;
+	///
+	/// let diff_options = DiffOptions::new();
+	/// ```
+	pub fn new() -> Self {
+		Self::default()
+	}
+
+	/// Whether or not to ignore differences that only affect formatting
+	///
+	/// When enabled, the following are considered equal:
+	///
+	/// * Values that only differ in leading, trailing, or repeated whitespace (`" Foo  bar"` and `"Foo bar"`)
+	/// * Numbers that only differ in padding (`"01"` and `"1"`)
+	/// * Typed values and their text equivalents ([`ItemValue::Integer(1)`](ItemValue::Integer) and `"1"`)
+	/// * Track and disc numbers with totals (`"1/10"`), and separate number and total items
+	///
+	/// Changes are reported with the values split into their number and total items.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use lofty::diff::DiffOptions;
+	///
+	/// let diff_options = DiffOptions::new().ignore_formatting(true);
+	/// ```
+	pub fn ignore_formatting(&mut self, ignore_formatting: bool) -> Self {
+		self.ignore_formatting = ignore_formatting;
+		*self
+	}
+}
+
+/// A change to the items of an [`ItemKey`]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ItemDiff {
+	/// The key of the items
+	pub key: ItemKey,
+	/// The change, containing all of the key's values in order
+	pub change: Change<Vec<ItemValue>>,
+}
+
+/// A change to the pictures of a [`PictureType`]
+///
+/// Pictures are identified by a hash of their MIME type and data, see [`picture_hash`].
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct PictureDiff {
+	/// The type of the pictures
+	pub pic_type: PictureType,
+	/// The change, containing the pictures' hashes
+	pub change: Change<u64>,
+}
+
+/// The differences between two [`Tag`]s
+///
+/// See [`Tag::diff`]
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct TagDiff {
+	pub(crate) items: Vec<ItemDiff>,
+	pub(crate) pictures: Vec<PictureDiff>,
+}
+
+impl TagDiff {
+	/// Returns the item changes, in the order the keys first appear in the old, then the new tag
+	pub fn items(&self) -> &[ItemDiff] {
+		&self.items
+	}
+
+	/// Returns the picture changes
+	pub fn pictures(&self) -> &[PictureDiff] {
+		&self.pictures
+	}
+
+	/// Whether the tags are equal
+	pub fn is_empty(&self) -> bool {
+		self.items.is_empty() && self.pictures.is_empty()
+	}
+}
+
+/// Hashes a picture's MIME type and data
+///
+/// This is used to identify pictures in a [`PictureDiff`]. The hash is only guaranteed to be
+/// stable within a single run of a program, and should not be stored.
+pub fn picture_hash(picture: &Picture) -> u64 {
+	let mut hasher = DefaultHasher::new();
+	picture.mime_type().hash(&mut hasher);
+	picture.data().hash(&mut hasher);
+	hasher.finish()
+}
+
+// The number keys that can hold a total in the form "1/10"
+const NUMBER_TOTAL_KEYS: [(ItemKey, ItemKey); 2] = [
+	(ItemKey::TrackNumber, ItemKey::TrackTotal),
+	(ItemKey::DiscNumber, ItemKey::DiscTotal),
+];
+
+pub(crate) fn diff_tags(old: &Tag, new: &Tag, options: DiffOptions) -> TagDiff {
+	let old_items = group_items(old, options);
+	let new_items = group_items(new, options);
+
+	let mut items = Vec::new();
+	for (key, old_values) in &old_items {
+		match new_items.iter().find(|(k, _)| k == key) {
+			Some((_, new_values)) if !values_equal(old_values, new_values, options) => {
+				items.push(ItemDiff {
+					key: key.clone(),
+					change: Change::Modified {
+						old: old_values.clone(),
+						new: new_values.clone(),
+					},
+				})
+			},
+			Some(_) => {},
+			None => items.push(ItemDiff {
+				key: key.clone(),
+				change: Change::Removed(old_values.clone()),
+			}),
+		}
+	}
+
+	for (key, new_values) in &new_items {
+		if !old_items.iter().any(|(k, _)| k == key) {
+			items.push(ItemDiff {
+				key: key.clone(),
+				change: Change::Added(new_values.clone()),
+			})
+		}
+	}
+
+	TagDiff {
+		items,
+		pictures: diff_pictures(old.pictures(), new.pictures()),
+	}
+}
+
+// Groups a tag's values by their keys, in the order they first appear
+fn group_items(tag: &Tag, options: DiffOptions) -> Vec<(ItemKey, Vec<ItemValue>)> {
+	let mut grouped: Vec<(ItemKey, Vec<ItemValue>)> = Vec::new();
+	for item in tag.items() {
+		match grouped.iter_mut().find(|(key, _)| key == item.key()) {
+			Some((_, values)) => values.push(item.value().clone()),
+			None => grouped.push((item.key().clone(), vec![item.value().clone()])),
+		}
+	}
+
+	if options.ignore_formatting {
+		for (number_key, total_key) in &NUMBER_TOTAL_KEYS {
+			split_number_total(&mut grouped, number_key, total_key);
+		}
+	}
+
+	grouped
+}
+
+// Splits a "1/10" value into separate number and total items
+//
+// If there is already a separate total that differs from the one in the number, both are kept,
+// so the conflict is reported rather than either total being considered equal.
+fn split_number_total(
+	grouped: &mut Vec<(ItemKey, Vec<ItemValue>)>,
+	number_key: &ItemKey,
+	total_key: &ItemKey,
+) {
+	let total = match grouped.iter_mut().find(|(key, _)| key == number_key) {
+		Some((_, values)) if values.len() == 1 => {
+			let (number, total) = match values[0].text().and_then(|text| text.split_once('/')) {
+				Some((number, total)) => (number.to_string(), total.to_string()),
+				None => return,
+			};
+
+			values[0] = ItemValue::Text(number);
+			total
+		},
+		_ => return,
+	};
+
+	let total = ItemValue::Text(total);
+	match grouped.iter_mut().find(|(key, _)| key == total_key) {
+		Some((_, values)) => {
+			let normalized = normalize_value(&total);
+			if !values
+				.iter()
+				.any(|value| normalize_value(value) == normalized)
+			{
+				values.push(total);
+			}
+		},
+		None => grouped.push((total_key.clone(), vec![total])),
+	}
+}
+
+fn values_equal(old: &[ItemValue], new: &[ItemValue], options: DiffOptions) -> bool {
+	if !options.ignore_formatting {
+		return old == new;
+	}
+
+	old.len() == new.len()
+		&& old.iter().zip(new).all(|(old, new)| {
+			match (normalize_value(old), normalize_value(new)) {
+				(Some(old), Some(new)) => old == new,
+				_ => old == new,
+			}
+		})
+}
+
+// Trims and collapses whitespace, and removes the padding from numbers
+fn normalize_value(value: &ItemValue) -> Option<Cow<'_, str>> {
+	let text = value.as_text()?;
+
+	let mut normalized = text.split_whitespace().collect::<Vec<_>>().join(" ");
+	if !normalized.is_empty() && normalized.bytes().all(|b| b.is_ascii_digit()) {
+		let unpadded = normalized.trim_start_matches('0');
+		normalized = if unpadded.is_empty() {
+			String::from("0")
+		} else {
+			unpadded.to_string()
+		};
+	}
+
+	if normalized == text {
+		return Some(text);
+	}
+
+	Some(Cow::Owned(normalized))
+}
+
+fn diff_pictures(old: &[Picture], new: &[Picture]) -> Vec<PictureDiff> {
+	let mut pic_types = Vec::new();
+	for picture in old.iter().chain(new) {
+		if !pic_types.contains(&picture.pic_type()) {
+			pic_types.push(picture.pic_type());
+		}
+	}
+
+	let mut diffs = Vec::new();
+	for pic_type in pic_types {
+		let hashes = |pictures: &[Picture]| {
+			pictures
+				.iter()
+				.filter(|picture| picture.pic_type() == pic_type)
+				.map(picture_hash)
+				.collect::<Vec<_>>()
+		};
+
+		let mut removed = hashes(old);
+		let mut added = hashes(new);
+
+		// Pictures in both tags are unchanged
+		removed.retain(|hash| match added.iter().position(|h| h == hash) {
+			Some(pos) => {
+				added.remove(pos);
+				false
+			},
+			None => true,
+		});
+
+		// Any remaining pictures are considered replacements of one another
+		let mut removed = removed.into_iter();
+		let mut added = added.into_iter();
+		loop {
+			let change = match (removed.next(), added.next()) {
+				(Some(old), Some(new)) => Change::Modified { old, new },
+				(Some(old), None) => Change::Removed(old),
+				(None, Some(new)) => Change::Added(new),
+				(None, None) => break,
+			};
+
+			diffs.push(PictureDiff { pic_type, change });
+		}
+	}
+
+	diffs
+}
+
+#[cfg(test)]
+mod tests {
+	use super::{picture_hash, Change, DiffOptions, ItemDiff, PictureDiff};
+	use crate::{ItemKey, ItemValue, MimeType, Picture, PictureType, Tag, TagType};
+
+	fn picture(pic_type: PictureType, data: &[u8]) -> Picture {
+		Picture::new_unchecked(pic_type, MimeType::Png, None, data.to_vec())
+	}
+
+	#[test]
+	fn item_changes() {
+		let mut old = Tag::new(TagType::ID3v2);
+		old.insert_text(ItemKey::TrackTitle, String::from("Foo title"));
+		old.insert_text(ItemKey::AlbumTitle, String::from("Bar album"));
+		old.push_item(crate::TagItem::new(
+			ItemKey::TrackArtist,
+			ItemValue::Text(String::from("Foo artist")),
+		));
+
+		let mut new = old.clone();
+		new.remove_key(&ItemKey::AlbumTitle);
+		new.insert_text(ItemKey::Genre, String::from("Classical"));
+		new.push_item(crate::TagItem::new(
+			ItemKey::TrackArtist,
+			ItemValue::Text(String::from("Bar artist")),
+		));
+
+		let diff = old.diff(&new, DiffOptions::new());
+		assert_eq!(
+			diff.items(),
+			&[
+				ItemDiff {
+					key: ItemKey::AlbumTitle,
+					change: Change::Removed(vec![ItemValue::Text(String::from("Bar album"))]),
+				},
+				ItemDiff {
+					key: ItemKey::TrackArtist,
+					change: Change::Modified {
+						old: vec![ItemValue::Text(String::from("Foo artist"))],
+						new: vec![
+							ItemValue::Text(String::from("Foo artist")),
+							ItemValue::Text(String::from("Bar artist"))
+						],
+					},
+				},
+				ItemDiff {
+					key: ItemKey::Genre,
+					change: Change::Added(vec![ItemValue::Text(String::from("Classical"))]),
+				},
+			]
+		);
+
+		assert!(old.diff(&old, DiffOptions::new()).is_empty());
+	}
+
+	#[test]
+	fn ignore_formatting() {
+		let mut old = Tag::new(TagType::ID3v2);
+		old.insert_text(ItemKey::TrackTitle, String::from(" Foo  title "));
+		old.insert_text(ItemKey::TrackNumber, String::from("03/12"));
+		old.insert_text(ItemKey::DiscNumber, String::from("1/2"));
+		old.insert_text(ItemKey::BPM, String::from("0120"));
+
+		let mut new = Tag::new(TagType::ID3v2);
+		new.insert_text(ItemKey::TrackTitle, String::from("Foo title"));
+		new.insert_text(ItemKey::TrackNumber, String::from("3"));
+		new.insert_text(ItemKey::TrackTotal, String::from("12"));
+		new.insert_text(ItemKey::DiscNumber, String::from("1"));
+		new.insert_text(ItemKey::DiscTotal, String::from("3"));
+		new.insert_item(crate::TagItem::new(ItemKey::BPM, ItemValue::Integer(120)));
+
+		let diff = old.diff(&new, DiffOptions::new().ignore_formatting(true));
+		assert_eq!(
+			diff.items(),
+			&[ItemDiff {
+				key: ItemKey::DiscTotal,
+				change: Change::Modified {
+					old: vec![ItemValue::Text(String::from("2"))],
+					new: vec![ItemValue::Text(String::from("3"))],
+				},
+			}]
+		);
+
+		// Without the option, everything is different
+		assert_eq!(old.diff(&new, DiffOptions::new()).items().len(), 6);
+	}
+
+	#[test]
+	fn conflicting_totals() {
+		let mut old = Tag::new(TagType::ID3v2);
+		old.insert_text(ItemKey::TrackNumber, String::from("3/12"));
+		old.insert_text(ItemKey::TrackTotal, String::from("10"));
+
+		let mut new = Tag::new(TagType::ID3v2);
+		new.insert_text(ItemKey::TrackNumber, String::from("3"));
+		new.insert_text(ItemKey::TrackTotal, String::from("10"));
+
+		let diff = old.diff(&new, DiffOptions::new().ignore_formatting(true));
+		assert_eq!(
+			diff.items(),
+			&[ItemDiff {
+				key: ItemKey::TrackTotal,
+				change: Change::Modified {
+					old: vec![
+						ItemValue::Text(String::from("10")),
+						ItemValue::Text(String::from("12"))
+					],
+					new: vec![ItemValue::Text(String::from("10"))],
+				},
+			}]
+		);
+
+		// Matching totals are only compared once
+		old.insert_text(ItemKey::TrackNumber, String::from("03/010"));
+		assert!(old
+			.diff(&new, DiffOptions::new().ignore_formatting(true))
+			.is_empty());
+	}
+
+	#[test]
+	fn picture_changes() {
+		let front_cover = picture(PictureType::CoverFront, &[1, 2, 3]);
+		let new_front_cover = picture(PictureType::CoverFront, &[4, 5, 6]);
+		let back_cover = picture(PictureType::CoverBack, &[7, 8, 9]);
+		let icon = picture(PictureType::Icon, &[10]);
+
+		let mut old = Tag::new(TagType::ID3v2);
+		old.push_picture(front_cover.clone());
+		old.push_picture(back_cover.clone());
+
+		let mut new = Tag::new(TagType::ID3v2);
+		new.push_picture(back_cover);
+		new.push_picture(new_front_cover.clone());
+		new.push_picture(icon.clone());
+
+		let diff = old.diff(&new, DiffOptions::new());
+		assert!(diff.items().is_empty());
+		assert_eq!(
+			diff.pictures(),
+			&[
+				PictureDiff {
+					pic_type: PictureType::CoverFront,
+					change: Change::Modified {
+						old: picture_hash(&front_cover),
+						new: picture_hash(&new_front_cover),
+					},
+				},
+				PictureDiff {
+					pic_type: PictureType::Icon,
+					change: Change::Added(picture_hash(&icon)),
+				},
+			]
+		);
+	}
+}
@@ -42,6 +42,8 @@ pub enum ErrorKind {
 	FakeTag,
 	/// Errors that arise while decoding text
 	TextDecode(&'static str),
+	/// Arises when parsing an invalid [`Timestamp`](crate::Timestamp)
+	BadTimestamp(&'static str),
 	/// Errors that arise while reading/writing ID3v2 tags
 	ID3v2(ID3v2Error),
 
@@ -80,8 +82,14 @@ pub enum ID3v2ErrorKind {
 	/// Arises when invalid data is encountered while reading an ID3v2 synchronized text frame
 	BadSyncText,
 	#[cfg(feature = "id3v2")]
+	/// Arises when a synchronized frame (`SYLT`, `ETCO`, `SYTC`) has an invalid timestamp format
+	BadTimestampFormat,
+	#[cfg(feature = "id3v2")]
 	/// Arises when attempting to write an invalid Frame (Bad `FrameID`/`FrameValue` pairing)
 	BadFrame(String, &'static str),
+	#[cfg(feature = "id3v2_restrictions")]
+	/// Arises when attempting to write a tag that doesn't satisfy its [`TagRestrictions`](crate::id3::v2::TagRestrictions)
+	RestrictionViolation(crate::id3::v2::RestrictionViolation),
 	/// A catch-all for all remaining errors
 	///
 	/// NOTE: This will likely be deprecated in the future
@@ -106,11 +114,24 @@ impl Display for ID3v2ErrorKind {
 			#[cfg(feature = "id3v2")]
 			ID3v2ErrorKind::BadSyncText => write!(f, "Encountered invalid data in SYLT frame"),
 			#[cfg(feature = "id3v2")]
+			ID3v2ErrorKind::BadTimestampFormat => {
+				write!(
+					f,
+					"Encountered an invalid timestamp format in a synchronized frame"
+				)
+			},
+			#[cfg(feature = "id3v2")]
 			ID3v2ErrorKind::BadFrame(ref frame_id, frame_value) => write!(
 				f,
 				"Attempted to write an invalid frame. ID: \"{}\", Value: \"{}\"",
 				frame_id, frame_value
 			),
+			#[cfg(feature = "id3v2_restrictions")]
+			ID3v2ErrorKind::RestrictionViolation(violation) => write!(
+				f,
+				"Frame \"{}\" violates the tag's restrictions ({:?})",
+				violation.frame_id, violation.kind
+			),
 			#[cfg(feature = "id3v2")]
 			ID3v2ErrorKind::BadPictureFormat(format) => {
 				write!(f, "Picture: Found unexpected format \"{}\"", format)
@@ -373,6 +394,7 @@ impl Display for LoftyError {
 			),
 			ErrorKind::FakeTag => write!(f, "Reading: Expected a tag, found invalid data"),
 			ErrorKind::TextDecode(message) => write!(f, "Text decoding: {}", message),
+			ErrorKind::BadTimestamp(message) => write!(f, "Timestamp: {message}"),
 			ErrorKind::ID3v2(ref id3v2_err) => write!(f, "{}", id3v2_err),
 			ErrorKind::BadAtom(message) => write!(f, "MP4 Atom: {}", message),
 
@@ -0,0 +1,487 @@
+//! A text format for tags, for editing them by hand
+//!
+//! [`TagExt::dump_to`](crate::TagExt::dump_to) writes a tag in its binary encoding. For bulk
+//! editing in a text editor (or keeping tags in version control), a tag can instead be exported
+//! to and imported from text with [`TagExt::export_text`](crate::TagExt::export_text) and
+//! [`TagExt::import_text`](crate::TagExt::import_text). This is available for [`Tag`] and all
+//! of the format-specific tags.
+//!
+//! # Format
+//!
+//! Every item is written on its own line as `KEY=value`, using the keys of the tag's format
+//! (such as `TIT2` for ID3v2 or `TITLE` for Vorbis Comments). ID3v1 has no keys, so the
+//! Vorbis Comments keys are used in its place.
+//!
+//! * Items with multiple values are written as repeated keys, in their original order
+//! * Lines are sorted by key, so the output of a tag is always the same
+//! * `\`, newlines, and carriage returns are escaped as `\\`, `\n`, and `\r`. Keys also escape `=` as `\=`
+//! * Empty lines and lines starting with `#` are ignored
+//!
+//! Pictures are written to files in a separate directory, and referenced with lines of the form
+//! `@PICTURE=<type>|<mime type>|<description>|<file>`:
+//!
+//! * `type` is the ID3v2 picture type, see [`PictureType::as_u8`]
+//! * `mime type` and `description` may be empty
+//! * `file` is relative to the picture directory, and can't refer to any other directory
+//! * Pictures are named after a hash of their data, so the pictures of many tags can share a directory
+//! * The fields escape `|` as `\|`
+//!
+//! Keys starting with `#` or `@` have it escaped as `\#` and `\@`.
+//!
+//! ```text
+//! ARTIST=Foo artist
+//! ARTIST=Bar artist
+//! COMMENT=Line one\nLine two
+//! TITLE=Foo title
+//! @PICTURE=3|image/png|Front cover|d0aa6218672cf5ab.png
+//! ```
+//!
+//! # Lossy items
+//!
+//! Items that can't be written as text, being binary items and items with no key in the tag's
+//! format, are skipped. When importing, these items are kept as-is, while all others are
+//! replaced by those in the text.
+//!
+//! # Examples
+//!
+//! ```rust
+//! # fn main() -> lofty::Result<()> {
+//! use lofty::{Accessor, Tag, TagExt, TagType};
+//!
+//! let mut tag = Tag::new(TagType::VorbisComments);
+//! tag.set_title(String::from("Foo title"));
+//! tag.set_comment(String::from("Line one\nLine two"));
+//!
+//! let mut text = Vec::new();
+//! tag.export_text(&mut text, None)?;
+//! assert_eq!(text, b"COMMENT=Line one\\nLine two\nTITLE=Foo title\n");
+//!
+//! let mut imported = Tag::new(TagType::VorbisComments);
+//! imported.import_text(&mut &b"TITLE=Bar title\nARTIST=Bar artist\n"[..], None)?;
+//! assert_eq!(imported.title(), Some("Bar title"));
+//! assert_eq!(imported.artist(), Some("Bar artist"));
+//! # Ok(()) }
+//! ```
+
+use crate::error::Result;
+use crate::macros::err;
+use crate::picture::{MimeType, Picture, PictureType};
+use crate::tag::item::{ItemKey, ItemValue, TagItem};
+use crate::tag::{Tag, TagType};
+
+use std::io::{BufRead, Write};
+use std::path::{Component, Path};
+
+const PICTURE_KEY: &str = "@PICTURE";
+
+pub(crate) fn export_tag<W>(tag: &Tag, writer: &mut W, picture_dir: Option<&Path>) -> Result<()>
+where
+	W: Write,
+{
+	let mut lines = Vec::new();
+	for item in &tag.items {
+		if let Some((key, value)) = exportable(tag.tag_type(), item) {
+			lines.push((key, value));
+		}
+	}
+
+	// Stable, to keep multiple values in order
+	lines.sort_by_key(|(key, _)| *key);
+
+	for (key, value) in lines {
+		writeln!(writer, "{}={}", escape_key(key), escape(&value, &[]))?;
+	}
+
+	if let Some(picture_dir) = picture_dir {
+		for picture in &tag.pictures {
+			let file_name = format!(
+				"{:016x}.{}",
+				hash(picture.data()),
+				extension(picture.mime_type())
+			);
+			std::fs::write(picture_dir.join(&file_name), picture.data())?;
+
+			writeln!(
+				writer,
+				"{}={}|{}|{}|{}",
+				PICTURE_KEY,
+				picture.pic_type().as_u8(),
+				escape(picture.mime_type().as_str(), &['|']),
+				escape(picture.description().unwrap_or_default(), &['|']),
+				escape(&file_name, &['|']),
+			)?;
+		}
+	}
+
+	Ok(())
+}
+
+pub(crate) fn import_tag<R>(tag: &mut Tag, reader: &mut R, picture_dir: Option<&Path>) -> Result<()>
+where
+	R: BufRead,
+{
+	let tag_type = tag.tag_type();
+
+	let mut items = Vec::new();
+	let mut pictures = Vec::new();
+	for line in reader.lines() {
+		let line = line?;
+		if line.is_empty() || line.starts_with('#') {
+			continue;
+		}
+
+		let (key, value) = match split_unescaped(&line, '=', Some(2))[..] {
+			[key, value] => (key, value),
+			_ => err!(TextDecode("Found a line with no `=`")),
+		};
+
+		if key == PICTURE_KEY {
+			if let Some(picture_dir) = picture_dir {
+				pictures.push(read_picture(value, picture_dir)?);
+			}
+
+			continue;
+		}
+
+		let key = unescape(key)?;
+		let value = unescape(value)?;
+
+		let item_key = ItemKey::from_key(key_format(tag_type), &key);
+		let value = if is_locator(tag, &item_key, &key) {
+			ItemValue::Locator(value)
+		} else {
+			ItemValue::Text(value)
+		};
+
+		items.push(TagItem::new(item_key, value));
+	}
+
+	tag.items
+		.retain(|item| exportable(tag_type, item).is_none());
+	tag.items.extend(items);
+
+	if picture_dir.is_some() {
+		tag.pictures = pictures;
+	}
+
+	Ok(())
+}
+
+// ID3v1 has no keys of its own
+fn key_format(tag_type: TagType) -> TagType {
+	match tag_type {
+		TagType::ID3v1 => TagType::VorbisComments,
+		tag_type => tag_type,
+	}
+}
+
+fn exportable(tag_type: TagType, item: &TagItem) -> Option<(&str, String)> {
+	let key = item.key().map_key(key_format(tag_type), true)?;
+	let value = item.value().as_text()?;
+
+	Some((key, value.into_owned()))
+}
+
+// Locators are written the same as text, so they are restored from the existing items and
+// ID3v2 URL frames ("WOAR", etc.)
+fn is_locator(tag: &Tag, item_key: &ItemKey, key: &str) -> bool {
+	tag.items
+		.iter()
+		.any(|item| item.key() == item_key && matches!(item.value(), ItemValue::Locator(_)))
+		|| (tag.tag_type() == TagType::ID3v2 && key.len() == 4 && key.starts_with('W'))
+}
+
+fn read_picture(value: &str, picture_dir: &Path) -> Result<Picture> {
+	let (pic_type, mime_type, description, file_name) = match split_unescaped(value, '|', None)[..]
+	{
+		[pic_type, mime_type, description, file_name] => {
+			(pic_type, mime_type, description, file_name)
+		},
+		_ => err!(TextDecode("Picture lines must have 4 fields")),
+	};
+
+	let pic_type = match pic_type.parse::<u8>() {
+		Ok(pic_type) => PictureType::from_u8(pic_type),
+		Err(_) => err!(TextDecode("Found an invalid picture type")),
+	};
+
+	// Only allow plain file names, anything else could refer to a file outside of the directory
+	let file_name = unescape(file_name)?;
+	let mut components = Path::new(&file_name).components();
+	match (components.next(), components.next()) {
+		(Some(Component::Normal(_)), None) => {},
+		_ => err!(TextDecode("Found an invalid picture file name")),
+	}
+
+	let description = unescape(description)?;
+	let data = std::fs::read(picture_dir.join(file_name))?;
+
+	Ok(Picture::new_unchecked(
+		pic_type,
+		MimeType::from_str(&unescape(mime_type)?),
+		(!description.is_empty()).then(|| description),
+		data,
+	))
+}
+
+// 64-bit FNV-1a, which unlike `DefaultHasher` is stable across Rust versions
+fn hash(data: &[u8]) -> u64 {
+	const OFFSET_BASIS: u64 = 0xcbf2_9ce4_8422_2325;
+	const PRIME: u64 = 0x0100_0000_01b3;
+
+	data.iter().fold(OFFSET_BASIS, |hash, byte| {
+		(hash ^ u64::from(*byte)).wrapping_mul(PRIME)
+	})
+}
+
+fn extension(mime_type: &MimeType) -> &'static str {
+	match mime_type {
+		MimeType::Png => "png",
+		MimeType::Jpeg => "jpg",
+		MimeType::Tiff => "tiff",
+		MimeType::Bmp => "bmp",
+		MimeType::Gif => "gif",
+		_ => "bin",
+	}
+}
+
+fn escape(text: &str, special: &[char]) -> String {
+	let mut escaped = String::with_capacity(text.len());
+	for c in text.chars() {
+		match c {
+			'\\' => escaped.push_str("\\\\"),
+			'\n' => escaped.push_str("\\n"),
+			'\r' => escaped.push_str("\\r"),
+			c if special.contains(&c) => {
+				escaped.push('\\');
+				escaped.push(c);
+			},
+			c => escaped.push(c),
+		}
+	}
+
+	escaped
+}
+
+fn escape_key(key: &str) -> String {
+	let escaped = escape(key, &['=']);
+	if escaped.starts_with('#') || escaped.starts_with('@') {
+		return format!("\\{escaped}");
+	}
+
+	escaped
+}
+
+fn unescape(text: &str) -> Result<String> {
+	let mut unescaped = String::with_capacity(text.len());
+
+	let mut chars = text.chars();
+	while let Some(c) = chars.next() {
+		if c != '\\' {
+			unescaped.push(c);
+			continue;
+		}
+
+		match chars.next() {
+			Some('n') => unescaped.push('\n'),
+			Some('r') => unescaped.push('\r'),
+			Some(c @ ('\\' | '=' | '|' | '#' | '@')) => unescaped.push(c),
+			_ => err!(TextDecode("Found an invalid escape sequence")),
+		}
+	}
+
+	Ok(unescaped)
+}
+
+// Splits on `separator`, ignoring any that are escaped
+fn split_unescaped(text: &str, separator: char, limit: Option<usize>) -> Vec<&str> {
+	let mut parts = Vec::new();
+
+	let mut start = 0;
+	let mut escaped = false;
+	for (idx, c) in text.char_indices() {
+		if escaped {
+			escaped = false;
+			continue;
+		}
+
+		match c {
+			'\\' => escaped = true,
+			c if c == separator && limit != Some(parts.len() + 1) => {
+				parts.push(&text[start..idx]);
+				start = idx + c.len_utf8();
+			},
+			_ => {},
+		}
+	}
+
+	parts.push(&text[start..]);
+	parts
+}
+
+#[cfg(test)]
+mod tests {
+	use crate::id3::v2::{Frame, FrameFlags, FrameValue, ID3v2Tag, LanguageFrame};
+	use crate::ogg::VorbisComments;
+	use crate::util::text::TextEncoding;
+	use crate::{
+		Accessor, ItemKey, ItemValue, MimeType, Picture, PictureType, Tag, TagExt, TagType,
+	};
+
+	fn export<T: TagExt + Clone>(tag: &T) -> String {
+		let mut text = Vec::new();
+		tag.export_text(&mut text, None).unwrap();
+		String::from_utf8(text).unwrap()
+	}
+
+	#[test]
+	fn escaping() {
+		let mut tag = Tag::new(TagType::VorbisComments);
+		tag.set_comment(String::from("C:\\foo\r\nbar=baz"));
+		tag.insert_item_unchecked(crate::TagItem::new(
+			ItemKey::Unknown(String::from("#MY=KEY")),
+			ItemValue::Text(String::from("Foo")),
+		));
+
+		let text = export(&tag);
+		assert_eq!(text, "\\#MY\\=KEY=Foo\nCOMMENT=C:\\\\foo\\r\\nbar=baz\n");
+
+		let mut imported = Tag::new(TagType::VorbisComments);
+		imported.import_text(&mut text.as_bytes(), None).unwrap();
+		assert_eq!(imported.comment().as_deref(), tag.comment().as_deref());
+		assert_eq!(export(&imported), text);
+	}
+
+	#[test]
+	fn multiple_values() {
+		let text = "# Comment\n\nTITLE=Foo title\nARTIST=Foo artist\nARTIST=Bar artist\nENCODER=Foo vendor\n";
+
+		let mut vorbis_comments = VorbisComments::default();
+		vorbis_comments
+			.import_text(&mut text.as_bytes(), None)
+			.unwrap();
+
+		let artists = vorbis_comments.get_all("ARTIST").collect::<Vec<_>>();
+		assert_eq!(artists, ["Foo artist", "Bar artist"]);
+		assert_eq!(vorbis_comments.vendor(), "Foo vendor");
+
+		// Sorted by key, with the values in their original order
+		assert_eq!(
+			export(&vorbis_comments),
+			"ARTIST=Foo artist\nARTIST=Bar artist\nENCODER=Foo vendor\nTITLE=Foo title\n"
+		);
+	}
+
+	#[test]
+	fn id3v2_native_keys() {
+		let mut id3v2 = ID3v2Tag::default();
+		id3v2.set_title(String::from("Foo title"));
+		id3v2.insert(
+			Frame::new(
+				"USLT",
+				FrameValue::UnSyncText(LanguageFrame {
+					encoding: TextEncoding::UTF8,
+					language: *b"eng",
+					description: String::new(),
+					content: String::from("Foo\nlyrics"),
+				}),
+				FrameFlags::default(),
+			)
+			.unwrap(),
+		);
+
+		let text = export(&id3v2);
+		assert_eq!(text, "TIT2=Foo title\nUSLT=Foo\\nlyrics\n");
+
+		// Untouched frames are kept as-is
+		let edited = text.replace("Foo title", "Bar title") + "WOAR=https://example.com\n";
+		let mut imported = id3v2.clone();
+		imported.import_text(&mut edited.as_bytes(), None).unwrap();
+
+		assert_eq!(imported.title().as_deref(), Some("Bar title"));
+		assert_eq!(imported.get("USLT"), id3v2.get("USLT"));
+		assert_eq!(
+			imported.get("WOAR").unwrap().content(),
+			&FrameValue::URL(String::from("https://example.com"))
+		);
+	}
+
+	#[test]
+	fn pictures() {
+		let dir = std::env::temp_dir().join(format!("lofty-export-{}", std::process::id()));
+		std::fs::create_dir_all(&dir).unwrap();
+
+		let mut tag = Tag::new(TagType::APE);
+		tag.set_title(String::from("Foo title"));
+		tag.push_picture(Picture::new_unchecked(
+			PictureType::CoverFront,
+			MimeType::Png,
+			Some(String::from("Foo|description")),
+			vec![1, 2, 3],
+		));
+		tag.push_picture(Picture::new_unchecked(
+			PictureType::CoverBack,
+			MimeType::None,
+			None,
+			vec![4, 5, 6],
+		));
+
+		let mut text = Vec::new();
+		tag.export_text(&mut text, Some(&dir)).unwrap();
+		assert_eq!(
+			String::from_utf8(text.clone()).unwrap(),
+			"Title=Foo title\n@PICTURE=3|image/png|Foo\\|description|d0aa6218672cf5ab.png\n@PICTURE=4|||b6b15a185877e1ec.bin\n"
+		);
+		assert_eq!(
+			std::fs::read(dir.join("d0aa6218672cf5ab.png")).unwrap(),
+			[1, 2, 3]
+		);
+
+		let mut imported = Tag::new(TagType::APE);
+		imported.import_text(&mut &text[..], Some(&dir)).unwrap();
+		assert_eq!(imported.pictures(), tag.pictures());
+
+		// Pictures are left alone without a directory
+		let mut without_pictures = tag.clone();
+		without_pictures
+			.import_text(&mut &b"Title=Bar title\n"[..], None)
+			.unwrap();
+		assert_eq!(without_pictures.pictures(), tag.pictures());
+
+		// Only plain file names are allowed
+		for file_name in ["../d0aa6218672cf5ab.png", "/d0aa6218672cf5ab.png", "", "."] {
+			let line = format!("@PICTURE=3|||{}\n", file_name);
+			assert!(imported
+				.import_text(&mut line.as_bytes(), Some(&dir))
+				.is_err());
+		}
+
+		std::fs::remove_dir_all(&dir).unwrap();
+	}
+
+	#[test]
+	fn binary_items_are_kept() {
+		let mut tag = Tag::new(TagType::ID3v2);
+		tag.set_title(String::from("Foo title"));
+		tag.insert_item_unchecked(crate::TagItem::new(
+			ItemKey::Unknown(String::from("SYLT")),
+			ItemValue::Binary(vec![1, 2, 3]),
+		));
+
+		let text = export(&tag);
+		assert_eq!(text, "TIT2=Foo title\n");
+
+		tag.import_text(&mut &b""[..], None).unwrap();
+		assert_eq!(tag.title(), None);
+		assert_eq!(tag.item_count(), 1);
+	}
+
+	#[test]
+	fn invalid_lines() {
+		let mut tag = Tag::new(TagType::VorbisComments);
+		assert!(tag.import_text(&mut &b"TITLE\n"[..], None).is_err());
+		assert!(tag
+			.import_text(&mut &b"TITLE=Foo\\ttitle\n"[..], None)
+			.is_err());
+	}
+}
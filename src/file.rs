@@ -575,6 +575,8 @@ impl FileType {
 			FileType::AIFF if tag_type == TagType::AIFFText => true,
 			#[cfg(feature = "id3v1")]
 			FileType::APE | FileType::MPEG | FileType::WavPack if tag_type == TagType::ID3v1 => true,
+			#[cfg(feature = "lyrics3")]
+			FileType::MPEG if tag_type == TagType::Lyrics3 => true,
 			#[cfg(feature = "ape")]
 			FileType::APE | FileType::MPEG | FileType::WavPack if tag_type == TagType::APE => true,
 			#[cfg(feature = "vorbis_comments")]
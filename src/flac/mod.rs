@@ -10,12 +10,12 @@ mod read;
 #[cfg(feature = "vorbis_comments")]
 pub(crate) mod write;
 
+pub use properties::FlacProperties;
+
 #[cfg(feature = "id3v2")]
 use crate::id3::v2::tag::ID3v2Tag;
 #[cfg(feature = "vorbis_comments")]
 use crate::ogg::VorbisComments;
-use crate::properties::FileProperties;
-
 use lofty_attr::LoftyFile;
 
 /// A FLAC file
@@ -41,5 +41,5 @@ pub struct FlacFile {
 	#[lofty(tag_type = "VorbisComments")]
 	pub(crate) vorbis_comments_tag: Option<VorbisComments>,
 	/// The file's audio properties
-	pub(crate) properties: FileProperties,
+	pub(crate) properties: FlacProperties,
 }
@@ -6,23 +6,120 @@ use std::time::Duration;
 
 use byteorder::{BigEndian, ReadBytesExt};
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[non_exhaustive]
+/// A FLAC file's audio properties
+pub struct FlacProperties {
+	pub(crate) duration: Duration,
+	pub(crate) overall_bitrate: u32,
+	pub(crate) audio_bitrate: u32,
+	pub(crate) sample_rate: u32,
+	pub(crate) bit_depth: u8,
+	pub(crate) channels: u8,
+	pub(crate) min_block_size: u16,
+	pub(crate) max_block_size: u16,
+	pub(crate) min_frame_size: u32,
+	pub(crate) max_frame_size: u32,
+	pub(crate) total_samples: u64,
+	pub(crate) signature: u128,
+}
+
+impl From<FlacProperties> for FileProperties {
+	fn from(input: FlacProperties) -> Self {
+		Self {
+			duration: input.duration,
+			overall_bitrate: Some(input.overall_bitrate),
+			audio_bitrate: Some(input.audio_bitrate),
+			sample_rate: Some(input.sample_rate),
+			bit_depth: Some(input.bit_depth),
+			channels: Some(input.channels),
+		}
+	}
+}
+
+impl FlacProperties {
+	/// Duration
+	pub fn duration(&self) -> Duration {
+		self.duration
+	}
+
+	/// Overall bitrate (kbps)
+	pub fn overall_bitrate(&self) -> u32 {
+		self.overall_bitrate
+	}
+
+	/// Audio bitrate (kbps)
+	pub fn audio_bitrate(&self) -> u32 {
+		self.audio_bitrate
+	}
+
+	/// Sample rate (Hz)
+	pub fn sample_rate(&self) -> u32 {
+		self.sample_rate
+	}
+
+	/// Bits per sample (usually 16 or 24 bit)
+	pub fn bit_depth(&self) -> u8 {
+		self.bit_depth
+	}
+
+	/// Channel count
+	pub fn channels(&self) -> u8 {
+		self.channels
+	}
+
+	/// The minimum block size (in samples) used in the stream
+	pub fn min_block_size(&self) -> u16 {
+		self.min_block_size
+	}
+
+	/// The maximum block size (in samples) used in the stream
+	pub fn max_block_size(&self) -> u16 {
+		self.max_block_size
+	}
+
+	/// The minimum frame size (in bytes) used in the stream
+	///
+	/// NOTE: This will be `0` if the value is unknown
+	pub fn min_frame_size(&self) -> u32 {
+		self.min_frame_size
+	}
+
+	/// The maximum frame size (in bytes) used in the stream
+	///
+	/// NOTE: This will be `0` if the value is unknown
+	pub fn max_frame_size(&self) -> u32 {
+		self.max_frame_size
+	}
+
+	/// The total number of samples in the stream, per channel
+	///
+	/// NOTE: This will be `0` if the value is unknown
+	pub fn total_samples(&self) -> u64 {
+		self.total_samples
+	}
+
+	/// The MD5 signature of the unencoded audio data
+	///
+	/// NOTE: This will be `0` if the signature was not calculated by the encoder
+	pub fn signature(&self) -> u128 {
+		self.signature
+	}
+}
+
 pub(crate) fn read_properties<R>(
 	stream_info: &mut R,
 	stream_length: u64,
 	file_length: u64,
-) -> Result<FileProperties>
+) -> Result<FlacProperties>
 where
 	R: Read,
 {
-	// Skip 4 bytes
-	// Minimum block size (2)
-	// Maximum block size (2)
-	stream_info.read_u32::<BigEndian>()?;
-
-	// Skip 6 bytes
-	// Minimum frame size (3)
-	// Maximum frame size (3)
-	stream_info.read_uint::<BigEndian>(6)?;
+	let min_block_size = stream_info.read_u16::<BigEndian>()?;
+	let max_block_size = stream_info.read_u16::<BigEndian>()?;
+	let min_frame_size = stream_info.read_u24::<BigEndian>()?;
+	let max_frame_size = stream_info.read_u24::<BigEndian>()?;
 
 	// Read 4 bytes
 	// Sample rate (20 bits)
@@ -36,22 +133,31 @@ where
 	let channels = ((info >> 9) & 7) + 1;
 
 	// Read the remaining 32 bits of the total samples
-	let total_samples = stream_info.read_u32::<BigEndian>()? | (info << 28);
+	let total_samples =
+		u64::from(stream_info.read_u32::<BigEndian>()?) | (u64::from(info & 0xF) << 32);
+
+	let signature = stream_info.read_u128::<BigEndian>()?;
 
-	let mut properties = FileProperties {
-		sample_rate: Some(sample_rate),
-		bit_depth: Some(bits_per_sample as u8),
-		channels: Some(channels as u8),
-		..FileProperties::default()
+	let mut properties = FlacProperties {
+		sample_rate,
+		bit_depth: bits_per_sample as u8,
+		channels: channels as u8,
+		min_block_size,
+		max_block_size,
+		min_frame_size,
+		max_frame_size,
+		total_samples,
+		signature,
+		..FlacProperties::default()
 	};
 
 	if sample_rate > 0 && total_samples > 0 {
-		let length = (u64::from(total_samples) * 1000) / u64::from(sample_rate);
+		let length = (total_samples * 1000) / u64::from(sample_rate);
 		properties.duration = Duration::from_millis(length);
 
 		if length > 0 && file_length > 0 && stream_length > 0 {
-			properties.overall_bitrate = Some(((file_length * 8) / length) as u32);
-			properties.audio_bitrate = Some(((stream_length * 8) / length) as u32);
+			properties.overall_bitrate = ((file_length * 8) / length) as u32;
+			properties.audio_bitrate = ((stream_length * 8) / length) as u32;
 		}
 	}
 
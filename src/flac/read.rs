@@ -1,4 +1,5 @@
 use super::block::Block;
+use super::properties::FlacProperties;
 use super::FlacFile;
 use crate::error::Result;
 #[cfg(feature = "id3v2")]
@@ -6,7 +7,6 @@ use crate::id3::v2::read::parse_id3v2;
 use crate::id3::{find_id3v2, ID3FindResults};
 use crate::macros::decode_err;
 use crate::probe::ParseOptions;
-use crate::properties::FileProperties;
 #[cfg(feature = "vorbis_comments")]
 use crate::{
 	ogg::{read::read_comments, tag::VorbisComments},
@@ -44,7 +44,7 @@ where
 		id3v2_tag: None,
 		#[cfg(feature = "vorbis_comments")]
 		vorbis_comments_tag: None,
-		properties: FileProperties::default(),
+		properties: FlacProperties::default(),
 	};
 
 	// It is possible for a FLAC file to contain an ID3v2 tag
@@ -53,7 +53,7 @@ where
 		{
 			let reader = &mut &*content;
 
-			let id3v2 = parse_id3v2(reader, header)?;
+			let id3v2 = parse_id3v2(reader, header, parse_options.parsing_mode)?;
 			flac_file.id3v2_tag = Some(id3v2)
 		}
 	}
@@ -61,8 +61,8 @@ where
 	let stream_info = verify_flac(data)?;
 	let stream_info_len = (stream_info.end - stream_info.start) as u32;
 
-	if stream_info_len < 18 {
-		decode_err!(@BAIL FLAC, "File has an invalid STREAMINFO block size (< 18)");
+	if stream_info_len < 34 {
+		decode_err!(@BAIL FLAC, "File has an invalid STREAMINFO block size (< 34)");
 	}
 
 	let mut last_block = stream_info.last;
@@ -109,7 +109,7 @@ where
 	flac_file.properties = if parse_options.read_properties {
 		super::properties::read_properties(&mut &*stream_info.content, stream_length, file_length)?
 	} else {
-		FileProperties::default()
+		FlacProperties::default()
 	};
 
 	Ok(flac_file)
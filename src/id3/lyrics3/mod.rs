@@ -0,0 +1,42 @@
+//! Lyrics3 items
+//!
+//! # Lyrics3 notes
+//!
+//! See also: [`Lyrics3Tag`]
+//!
+//! ## Location
+//!
+//! Lyrics3 tags are stored directly before an ID3v1 tag, which the format depends on.
+//! When writing, an empty ID3v1 tag will be created if the file doesn't already have one.
+//!
+//! ## Versions
+//!
+//! Lyrics3v1 tags can only hold lyrics, and have no size information. Lyrics3v2 tags
+//! are made up of fields, which extend the ID3v1 title, artist, and album fields among others.
+//!
+//! **Lofty** will *always* write a Lyrics3v2 tag.
+
+cfg_if::cfg_if! {
+	if #[cfg(feature = "lyrics3")] {
+		pub(crate) mod tag;
+		pub use tag::Lyrics3Tag;
+
+		pub(crate) mod read;
+		pub(crate) mod write;
+	}
+}
+
+/// The Lyrics3 version
+#[derive(PartialEq, Eq, Debug, Clone, Copy)]
+pub enum Lyrics3Version {
+	/// Lyrics3v1
+	V1,
+	/// Lyrics3v2
+	V2,
+}
+
+#[derive(Copy, Clone)]
+pub(crate) struct Lyrics3Header {
+	/// The size of the entire tag, including the "LYRICSBEGIN" marker and the footer
+	pub size: u32,
+}
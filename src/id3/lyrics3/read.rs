@@ -0,0 +1,67 @@
+use super::tag::Lyrics3Tag;
+use super::Lyrics3Version;
+use crate::error::Result;
+use crate::macros::decode_err;
+use crate::util::text::{decode_text, TextEncoding};
+
+// Parses an entire Lyrics3 tag, from "LYRICSBEGIN" to the end of its footer
+pub(crate) fn parse_lyrics3(data: &[u8], version: Lyrics3Version) -> Result<Lyrics3Tag> {
+	let mut tag = Lyrics3Tag {
+		original_version: version,
+		..Lyrics3Tag::default()
+	};
+
+	// "LYRICSBEGIN" (11) + "LYRICSEND" (9) or size (6) + "LYRICS200" (9)
+	let footer_len = match version {
+		Lyrics3Version::V1 => 9,
+		Lyrics3Version::V2 => 15,
+	};
+
+	if data.len() < 11 + footer_len {
+		decode_err!(@BAIL "Lyrics3 tag is too small");
+	}
+
+	let mut content = &data[11..data.len() - footer_len];
+
+	if version == Lyrics3Version::V1 {
+		tag.lyrics = decode_text(&mut content, TextEncoding::Latin1, false)?;
+		return Ok(tag);
+	}
+
+	// Fields are stored as [id (3 bytes), size (5 digits), content]
+	while !content.is_empty() {
+		if content.len() < 8 {
+			decode_err!(@BAIL "Lyrics3v2 field has an incomplete header");
+		}
+
+		let (id, size) = content.split_at(3);
+
+		let size = std::str::from_utf8(&size[..5])
+			.ok()
+			.and_then(|size| size.parse::<usize>().ok());
+
+		let size = match size {
+			Some(size) if size <= content.len() - 8 => size,
+			_ => decode_err!(@BAIL "Lyrics3v2 field has an invalid size"),
+		};
+
+		let mut value = &content[8..8 + size];
+		content = &content[8 + size..];
+
+		let field = match id {
+			b"LYR" => &mut tag.lyrics,
+			b"ETT" => &mut tag.title,
+			b"EAR" => &mut tag.artist,
+			b"EAL" => &mut tag.album,
+			b"AUT" => &mut tag.author,
+			b"INF" => &mut tag.information,
+			b"IMG" => &mut tag.images,
+			// The indications field (`IND`) is recreated when writing, and any unknown fields are skipped
+			_ => continue,
+		};
+
+		*field = decode_text(&mut value, TextEncoding::Latin1, false)?;
+	}
+
+	Ok(tag)
+}
@@ -0,0 +1,333 @@
+use super::Lyrics3Version;
+use crate::error::{LoftyError, Result};
+use crate::tag::item::{ItemKey, ItemValue, TagItem};
+use crate::tag::{Tag, TagType};
+use crate::traits::{Accessor, TagExt};
+
+use std::fs::{File, OpenOptions};
+use std::io::Write;
+use std::path::Path;
+
+macro_rules! impl_accessor {
+	($($name:ident => $field:ident,)+) => {
+		paste::paste! {
+			$(
+				fn $name(&self) -> Option<&str> {
+					self.$field.as_deref()
+				}
+
+				fn [<set_ $name>](&mut self, value: String) {
+					self.$field = Some(value)
+				}
+
+				fn [<remove_ $name>](&mut self) {
+					self.$field = None
+				}
+			)+
+		}
+	}
+}
+
+/// A Lyrics3 tag
+///
+/// Lyrics3 tags are mostly used to store lyrics, but Lyrics3v2 also allows
+/// for extending the small ID3v1 title, artist, and album fields.
+///
+/// All text is stored as ISO-8859-1. Characters outside of that range will be replaced
+/// with `?` when writing.
+///
+/// ## Supported file types
+///
+/// * [`FileType::MP3`](crate::FileType::MPEG)
+///
+/// ## Conversions
+///
+/// The fields map to the following [`ItemKey`]s:
+///
+/// | Field         | Lyrics3v2 field | [`ItemKey`]              |
+/// |---------------|-----------------|--------------------------|
+/// | `lyrics`      | `LYR`           | [`ItemKey::Lyrics`]      |
+/// | `title`       | `ETT`           | [`ItemKey::TrackTitle`]  |
+/// | `artist`      | `EAR`           | [`ItemKey::TrackArtist`] |
+/// | `album`       | `EAL`           | [`ItemKey::AlbumTitle`]  |
+/// | `author`      | `AUT`           | [`ItemKey::Lyricist`]    |
+/// | `information` | `INF`           | [`ItemKey::Comment`]     |
+///
+/// The `images` field has no equivalent, and will be discarded.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct Lyrics3Tag {
+	pub(super) original_version: Lyrics3Version,
+	/// The lyrics (`LYR`)
+	///
+	/// Lines are separated by `\r\n`, and may be prefixed with `[mm:ss]` timestamps.
+	pub lyrics: Option<String>,
+	/// The extended track title (`ETT`), 250 bytes max
+	pub title: Option<String>,
+	/// The extended track artist (`EAR`), 250 bytes max
+	pub artist: Option<String>,
+	/// The extended album title (`EAL`), 250 bytes max
+	pub album: Option<String>,
+	/// The author of the lyrics (`AUT`)
+	pub author: Option<String>,
+	/// Additional information (`INF`)
+	pub information: Option<String>,
+	/// Links to image files (`IMG`)
+	///
+	/// This is stored as-is, see the Lyrics3v2 specification for its format.
+	pub images: Option<String>,
+}
+
+impl Default for Lyrics3Tag {
+	fn default() -> Self {
+		Self {
+			original_version: Lyrics3Version::V2,
+			lyrics: None,
+			title: None,
+			artist: None,
+			album: None,
+			author: None,
+			information: None,
+			images: None,
+		}
+	}
+}
+
+impl Lyrics3Tag {
+	/// The original version of the tag
+	///
+	/// This is here, since a `Lyrics3v1` tag may have been read, but the tag
+	/// will always be written as `Lyrics3v2`.
+	pub fn original_version(&self) -> Lyrics3Version {
+		self.original_version
+	}
+}
+
+impl Accessor for Lyrics3Tag {
+	impl_accessor!(
+		title => title,
+		artist => artist,
+		album => album,
+		comment => information,
+		lyrics => lyrics,
+	);
+}
+
+impl TagExt for Lyrics3Tag {
+	type Err = LoftyError;
+
+	fn is_empty(&self) -> bool {
+		self.lyrics.is_none()
+			&& self.title.is_none()
+			&& self.artist.is_none()
+			&& self.album.is_none()
+			&& self.author.is_none()
+			&& self.information.is_none()
+			&& self.images.is_none()
+	}
+
+	fn save_to_path<P: AsRef<Path>>(&self, path: P) -> std::result::Result<(), Self::Err> {
+		self.save_to(&mut OpenOptions::new().read(true).write(true).open(path)?)
+	}
+
+	/// Writes the tag to a file
+	///
+	/// If the file has no ID3v1 tag, an empty one will be created to follow the Lyrics3 tag.
+	///
+	/// # Errors
+	///
+	/// * Attempting to write the tag to a format that does not support it
+	/// * A field is too large to be written
+	/// * [`std::io::Error`]
+	fn save_to(&self, file: &mut File) -> std::result::Result<(), Self::Err> {
+		Into::<Lyrics3TagRef<'_>>::into(self).write_to(file)
+	}
+
+	/// Dumps the tag to a writer
+	///
+	/// # Errors
+	///
+	/// * A field is too large to be written
+	/// * [`std::io::Error`]
+	fn dump_to<W: Write>(&self, writer: &mut W) -> std::result::Result<(), Self::Err> {
+		Into::<Lyrics3TagRef<'_>>::into(self).dump_to(writer)
+	}
+
+	fn remove_from_path<P: AsRef<Path>>(&self, path: P) -> std::result::Result<(), Self::Err> {
+		TagType::Lyrics3.remove_from_path(path)
+	}
+
+	fn remove_from(&self, file: &mut File) -> std::result::Result<(), Self::Err> {
+		TagType::Lyrics3.remove_from(file)
+	}
+
+	fn clear(&mut self) {
+		*self = Self::default();
+	}
+}
+
+impl From<Lyrics3Tag> for Tag {
+	fn from(input: Lyrics3Tag) -> Self {
+		let mut tag = Self::new(TagType::Lyrics3);
+
+		let fields = [
+			(input.title, ItemKey::TrackTitle),
+			(input.artist, ItemKey::TrackArtist),
+			(input.album, ItemKey::AlbumTitle),
+			(input.lyrics, ItemKey::Lyrics),
+			(input.author, ItemKey::Lyricist),
+			(input.information, ItemKey::Comment),
+		];
+
+		for (field, item_key) in fields {
+			if let Some(text) = field {
+				tag.items
+					.push(TagItem::new(item_key, ItemValue::Text(text)));
+			}
+		}
+
+		tag
+	}
+}
+
+impl From<Tag> for Lyrics3Tag {
+	fn from(mut input: Tag) -> Self {
+		input.convert_typed_items_to_text();
+
+		Self {
+			original_version: Lyrics3Version::V2,
+			lyrics: input.get_string(&ItemKey::Lyrics).map(str::to_owned),
+			title: input.get_string(&ItemKey::TrackTitle).map(str::to_owned),
+			artist: input.get_string(&ItemKey::TrackArtist).map(str::to_owned),
+			album: input.get_string(&ItemKey::AlbumTitle).map(str::to_owned),
+			author: input.get_string(&ItemKey::Lyricist).map(str::to_owned),
+			information: input.get_string(&ItemKey::Comment).map(str::to_owned),
+			images: None,
+		}
+	}
+}
+
+pub(crate) struct Lyrics3TagRef<'a> {
+	pub lyrics: Option<&'a str>,
+	pub title: Option<&'a str>,
+	pub artist: Option<&'a str>,
+	pub album: Option<&'a str>,
+	pub author: Option<&'a str>,
+	pub information: Option<&'a str>,
+	pub images: Option<&'a str>,
+}
+
+impl<'a> Into<Lyrics3TagRef<'a>> for &'a Lyrics3Tag {
+	fn into(self) -> Lyrics3TagRef<'a> {
+		Lyrics3TagRef {
+			lyrics: self.lyrics.as_deref(),
+			title: self.title.as_deref(),
+			artist: self.artist.as_deref(),
+			album: self.album.as_deref(),
+			author: self.author.as_deref(),
+			information: self.information.as_deref(),
+			images: self.images.as_deref(),
+		}
+	}
+}
+
+impl<'a> Into<Lyrics3TagRef<'a>> for &'a Tag {
+	fn into(self) -> Lyrics3TagRef<'a> {
+		Lyrics3TagRef {
+			lyrics: self.get_string(&ItemKey::Lyrics),
+			title: self.get_string(&ItemKey::TrackTitle),
+			artist: self.get_string(&ItemKey::TrackArtist),
+			album: self.get_string(&ItemKey::AlbumTitle),
+			author: self.get_string(&ItemKey::Lyricist),
+			information: self.get_string(&ItemKey::Comment),
+			images: None,
+		}
+	}
+}
+
+impl Lyrics3TagRef<'_> {
+	pub(super) fn is_empty(&self) -> bool {
+		self.lyrics.is_none()
+			&& self.title.is_none()
+			&& self.artist.is_none()
+			&& self.album.is_none()
+			&& self.author.is_none()
+			&& self.information.is_none()
+			&& self.images.is_none()
+	}
+
+	pub(crate) fn write_to(&self, file: &mut File) -> Result<()> {
+		super::write::write_lyrics3(file, self)
+	}
+
+	pub(crate) fn dump_to<W: Write>(&mut self, writer: &mut W) -> Result<()> {
+		let temp = super::write::encode(self)?;
+		writer.write_all(&temp)?;
+
+		Ok(())
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use crate::id3::lyrics3::{Lyrics3Tag, Lyrics3Version};
+	use crate::{Accessor, ItemKey, Tag, TagExt, TagType};
+
+	fn expected_tag() -> Lyrics3Tag {
+		Lyrics3Tag {
+			lyrics: Some(String::from("[00:01]Foo lyrics\r\n[00:02]Bar lyrics")),
+			title: Some(String::from("Foo title that is longer than thirty bytes")),
+			artist: Some(String::from("Bar artist")),
+			album: Some(String::from("Baz album")),
+			author: Some(String::from("Qux author")),
+			..Lyrics3Tag::default()
+		}
+	}
+
+	#[test]
+	fn parse_lyrics3v1() {
+		let tag = b"LYRICSBEGIN[00:01]Foo lyricsLYRICSEND";
+		let parsed_tag = crate::id3::lyrics3::read::parse_lyrics3(tag, Lyrics3Version::V1).unwrap();
+
+		assert_eq!(parsed_tag.original_version(), Lyrics3Version::V1);
+		assert_eq!(parsed_tag.lyrics.as_deref(), Some("[00:01]Foo lyrics"));
+		assert!(parsed_tag.title.is_none());
+	}
+
+	#[test]
+	fn lyrics3v2_re_read() {
+		let tag = expected_tag();
+
+		let mut writer = Vec::new();
+		tag.dump_to(&mut writer).unwrap();
+
+		assert!(writer.starts_with(b"LYRICSBEGININD0000211"));
+		assert!(writer.ends_with(b"LYRICS200"));
+
+		let size = std::str::from_utf8(&writer[writer.len() - 15..writer.len() - 9]).unwrap();
+		assert_eq!(size.parse::<usize>().unwrap(), writer.len() - 15);
+
+		let parsed_tag =
+			crate::id3::lyrics3::read::parse_lyrics3(&writer, Lyrics3Version::V2).unwrap();
+
+		assert_eq!(tag, parsed_tag);
+	}
+
+	#[test]
+	fn lyrics3v2_to_tag() {
+		let tag: Tag = expected_tag().into();
+
+		assert_eq!(tag.tag_type(), TagType::Lyrics3);
+		assert_eq!(
+			tag.title(),
+			Some("Foo title that is longer than thirty bytes")
+		);
+		assert_eq!(tag.get_string(&ItemKey::Lyricist), Some("Qux author"));
+		assert_eq!(
+			tag.get_string(&ItemKey::Lyrics),
+			Some("[00:01]Foo lyrics\r\n[00:02]Bar lyrics")
+		);
+
+		let lyrics3: Lyrics3Tag = tag.into();
+		assert_eq!(lyrics3, expected_tag());
+	}
+}
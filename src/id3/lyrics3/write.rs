@@ -0,0 +1,147 @@
+use super::tag::Lyrics3TagRef;
+use crate::error::Result;
+use crate::file::FileType;
+use crate::id3::{find_id3v1, find_lyrics3, ID3FindResults};
+use crate::macros::err;
+use crate::probe::{ParsingMode, Probe};
+
+use std::fs::File;
+use std::io::{Read, Seek, SeekFrom, Write};
+
+// The maximum size of the extended title, artist, and album fields
+const EXTENDED_FIELD_MAX_LEN: usize = 250;
+
+// An ID3v1 tag with every field empty, used when a file doesn't already have one
+const EMPTY_ID3V1: [u8; 128] = {
+	let mut tag = [0; 128];
+	tag[0] = b'T';
+	tag[1] = b'A';
+	tag[2] = b'G';
+	tag[127] = 255;
+	tag
+};
+
+#[allow(clippy::shadow_unrelated)]
+pub(crate) fn write_lyrics3(file: &mut File, tag: &Lyrics3TagRef<'_>) -> Result<()> {
+	let probe = Probe::new(file).guess_file_type()?;
+
+	match probe.file_type() {
+		Some(FileType::MPEG) => {},
+		_ => err!(UnsupportedTag),
+	}
+
+	let file = probe.into_inner();
+
+	// The Lyrics3 tag is located directly before the ID3v1 tag
+	let ID3FindResults(id3v1_header, _) = find_id3v1(file, false)?;
+	let tag_end = file.stream_position()?;
+
+	let ID3FindResults(lyrics3_header, _) = find_lyrics3(file, false, ParsingMode::Strict)?;
+	let tag_start = file.stream_position()?;
+
+	if tag.is_empty() && lyrics3_header.is_none() {
+		return Ok(());
+	}
+
+	let mut trailing = Vec::new();
+	file.seek(SeekFrom::Start(tag_end))?;
+	file.read_to_end(&mut trailing)?;
+
+	let encoded = if tag.is_empty() {
+		Vec::new()
+	} else {
+		// Lyrics3 depends on the ID3v1 tag to be found
+		if id3v1_header.is_none() {
+			trailing = EMPTY_ID3V1.to_vec();
+		}
+
+		encode(tag)?
+	};
+
+	file.seek(SeekFrom::Start(tag_start))?;
+	file.set_len(tag_start)?;
+
+	file.write_all(&encoded)?;
+	file.write_all(&trailing)?;
+
+	Ok(())
+}
+
+pub(super) fn encode(tag: &Lyrics3TagRef<'_>) -> Result<Vec<u8>> {
+	fn write_field(writer: &mut Vec<u8>, id: [u8; 3], value: &[u8]) -> Result<()> {
+		// Field sizes are stored as 5 digits
+		if value.len() > 99_999 {
+			err!(TooMuchData);
+		}
+
+		writer.extend(id);
+		writer.extend(format!("{:05}", value.len()).as_bytes());
+		writer.extend(value);
+
+		Ok(())
+	}
+
+	fn encode_text(value: &str, max_len: Option<usize>) -> Vec<u8> {
+		value
+			.chars()
+			.take(max_len.unwrap_or(usize::MAX))
+			.map(|c| u8::try_from(u32::from(c)).unwrap_or(b'?'))
+			.collect()
+	}
+
+	let mut writer = Vec::new();
+	writer.extend(b"LYRICSBEGIN");
+
+	// The indications field holds two flags, for whether lyrics are present,
+	// and whether they contain timestamps
+	let (has_lyrics, has_timestamps) = match tag.lyrics {
+		Some(lyrics) => (true, contains_timestamp(lyrics)),
+		None => (false, false),
+	};
+
+	write_field(
+		&mut writer,
+		*b"IND",
+		&[b'0' + u8::from(has_lyrics), b'0' + u8::from(has_timestamps)],
+	)?;
+
+	let fields = [
+		(b"LYR", tag.lyrics, None),
+		(b"INF", tag.information, None),
+		(b"AUT", tag.author, None),
+		(b"EAL", tag.album, Some(EXTENDED_FIELD_MAX_LEN)),
+		(b"EAR", tag.artist, Some(EXTENDED_FIELD_MAX_LEN)),
+		(b"ETT", tag.title, Some(EXTENDED_FIELD_MAX_LEN)),
+		(b"IMG", tag.images, None),
+	];
+
+	for (id, value, max_len) in fields {
+		if let Some(value) = value {
+			write_field(&mut writer, *id, &encode_text(value, max_len))?;
+		}
+	}
+
+	// The size includes "LYRICSBEGIN", and is stored as 6 digits
+	let size = writer.len();
+	if size > 999_999 {
+		err!(TooMuchData);
+	}
+
+	writer.extend(format!("{size:06}").as_bytes());
+	writer.extend(b"LYRICS200");
+
+	Ok(writer)
+}
+
+// Checks for a "[mm:ss]" timestamp
+fn contains_timestamp(lyrics: &str) -> bool {
+	lyrics.as_bytes().windows(7).any(|window| {
+		window[0] == b'['
+			&& window[1].is_ascii_digit()
+			&& window[2].is_ascii_digit()
+			&& window[3] == b':'
+			&& window[4].is_ascii_digit()
+			&& window[5].is_ascii_digit()
+			&& window[6] == b']'
+	})
+}
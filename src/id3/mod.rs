@@ -3,46 +3,131 @@
 //! ID3 does things differently than other tags, making working with them a little more effort than other formats.
 //! Check the other modules for important notes and/or warnings.
 
+pub mod lyrics3;
 pub mod v1;
 pub mod v2;
 
-use crate::error::{ErrorKind, LoftyError, Result};
-use crate::macros::try_vec;
+use crate::ape::constants::APE_PREAMBLE;
+use crate::ape::header::read_ape_header;
+use crate::error::Result;
+use crate::macros::{err, parse_mode_choice, try_vec};
+use crate::probe::ParsingMode;
+use lyrics3::{Lyrics3Header, Lyrics3Version};
+use v2::util::unsynch_u32;
 use v2::{read_id3v2_header, ID3v2Header};
 
 use std::io::{Read, Seek, SeekFrom};
-use std::ops::Neg;
+
+use byteorder::{BigEndian, ByteOrder};
 
 pub(crate) struct ID3FindResults<Header, Content>(pub Option<Header>, pub Content);
 
-pub(crate) fn find_lyrics3v2<R>(data: &mut R) -> Result<ID3FindResults<(), u32>>
+#[cfg(feature = "lyrics3")]
+pub(crate) type FindLyrics3Content = Option<lyrics3::tag::Lyrics3Tag>;
+#[cfg(not(feature = "lyrics3"))]
+pub(crate) type FindLyrics3Content = Option<()>;
+
+// Searches for a Lyrics3v1 or Lyrics3v2 tag ending at the current position
+//
+// If one is found, the reader is left at the start of the tag. Otherwise, the reader's position is unchanged.
+#[allow(unused_variables)]
+// `parsing_mode` is only used when `read` is true. Outside of `ParsingMode::Strict`, a tag that fails
+// to parse is skipped.
+pub(crate) fn find_lyrics3<R>(
+	data: &mut R,
+	read: bool,
+	parsing_mode: ParsingMode,
+) -> Result<ID3FindResults<Lyrics3Header, FindLyrics3Content>>
 where
 	R: Read + Seek,
 {
-	let mut header = None;
-	let mut size = 0_u32;
+	let tag_end = data.stream_position()?;
+
+	// "LYRICSBEGIN" (11) + "LYRICSEND" (9)
+	if tag_end < 20 {
+		return Ok(ID3FindResults(None, None));
+	}
 
 	data.seek(SeekFrom::Current(-15))?;
 
-	let mut lyrics3v2 = [0; 15];
-	data.read_exact(&mut lyrics3v2)?;
+	let mut footer = [0; 15];
+	data.read_exact(&mut footer)?;
 
-	if &lyrics3v2[7..] == b"LYRICS200" {
-		header = Some(());
+	let (version, tag_start) = match &footer[6..] {
+		b"LYRICS200" => {
+			let lyrics_size = std::str::from_utf8(&footer[..6])
+				.ok()
+				.and_then(|size| size.parse::<u64>().ok());
+			let lyrics_size = match lyrics_size {
+				Some(lyrics_size) => lyrics_size,
+				None => parse_mode_choice!(
+					parsing_mode,
+					STRICT: err!(TextDecode("Lyrics3v2 tag has an invalid size string")),
+					DEFAULT: {
+						data.seek(SeekFrom::Start(tag_end))?;
+						return Ok(ID3FindResults(None, None));
+					}
+				),
+			};
+
+			// The size doesn't include the footer
+			match tag_end.checked_sub(lyrics_size + 15) {
+				Some(tag_start) => (Lyrics3Version::V2, tag_start),
+				None => return Ok(ID3FindResults(None, None)),
+			}
+		},
+		b"LYRICSEND" => {
+			// Lyrics3v1 tags have no size, so "LYRICSBEGIN" has to be searched for.
+			// The lyrics can be at most 5100 bytes.
+			let search_len = std::cmp::min(tag_end, 5100 + 20);
+			let search_start = tag_end - search_len;
+
+			data.seek(SeekFrom::Start(search_start))?;
+
+			let mut content = try_vec![0; search_len as usize];
+			data.read_exact(&mut content)?;
+
+			match content
+				.windows(11)
+				.rposition(|window| window == b"LYRICSBEGIN")
+			{
+				Some(pos) => (Lyrics3Version::V1, search_start + pos as u64),
+				None => {
+					data.seek(SeekFrom::Start(tag_end))?;
+					return Ok(ID3FindResults(None, None));
+				},
+			}
+		},
+		_ => return Ok(ID3FindResults(None, None)),
+	};
 
-		let lyrics_size = std::str::from_utf8(&lyrics3v2[..7])?;
-		let lyrics_size = lyrics_size.parse::<u32>().map_err(|_| {
-			LoftyError::new(ErrorKind::TextDecode(
-				"Lyrics3v2 tag has an invalid size string",
-			))
-		})?;
+	data.seek(SeekFrom::Start(tag_start))?;
 
-		size += lyrics_size;
+	let mut content = try_vec![0; (tag_end - tag_start) as usize];
+	data.read_exact(&mut content)?;
 
-		data.seek(SeekFrom::Current(i64::from(lyrics_size + 15).neg()))?;
+	if !content.starts_with(b"LYRICSBEGIN") {
+		data.seek(SeekFrom::Start(tag_end))?;
+		return Ok(ID3FindResults(None, None));
 	}
 
-	Ok(ID3FindResults(header, size))
+	let header = Lyrics3Header {
+		size: content.len() as u32,
+	};
+
+	#[allow(unused_mut)]
+	let mut lyrics3 = None;
+
+	#[cfg(feature = "lyrics3")]
+	if read {
+		match lyrics3::read::parse_lyrics3(&content, version) {
+			Ok(tag) => lyrics3 = Some(tag),
+			Err(e) => parse_mode_choice!(parsing_mode, STRICT: return Err(e), DEFAULT: {}),
+		}
+	}
+
+	data.seek(SeekFrom::Start(tag_start))?;
+	Ok(ID3FindResults(Some(header), lyrics3))
 }
 
 #[cfg(feature = "id3v1")]
@@ -119,3 +204,87 @@ where
 
 	Ok(ID3FindResults(header, id3v2))
 }
+
+// Searches for an ID3v2 footer ending at the current position
+//
+// If one is found, the reader is left at the start of the tag's header, and its offset is returned.
+// Otherwise, the reader's position is unchanged.
+pub(crate) fn find_id3v2_footer<R>(data: &mut R) -> Result<ID3FindResults<ID3v2Header, u64>>
+where
+	R: Read + Seek,
+{
+	let footer_end = data.stream_position()?;
+
+	// Header (10) + Footer (10)
+	if footer_end < 20 {
+		return Ok(ID3FindResults(None, 0));
+	}
+
+	data.seek(SeekFrom::Current(-10))?;
+
+	let mut footer = [0; 10];
+	data.read_exact(&mut footer)?;
+
+	// Footers were introduced in ID3v2.4
+	if &footer[..3] != b"3DI" || footer[3] != 4 {
+		return Ok(ID3FindResults(None, 0));
+	}
+
+	let size = unsynch_u32(BigEndian::read_u32(&footer[6..]));
+	let tag_len = u64::from(size) + 20;
+
+	if tag_len > footer_end {
+		return Ok(ID3FindResults(None, 0));
+	}
+
+	let tag_start = footer_end - tag_len;
+	data.seek(SeekFrom::Start(tag_start))?;
+
+	// The footer is a copy of the header, so the two must agree
+	match read_id3v2_header(data) {
+		Ok(header) if header.flags.footer && header.size == size => {
+			data.seek(SeekFrom::Start(tag_start))?;
+			Ok(ID3FindResults(Some(header), tag_start))
+		},
+		_ => {
+			data.seek(SeekFrom::Start(footer_end))?;
+			Ok(ID3FindResults(None, 0))
+		},
+	}
+}
+
+// Searches for an ID3v2 tag appended to the end of the stream
+//
+// The tag is expected to come directly before an ID3v1 tag, if one exists. Since APE tags are
+// written in the same position, the area before an APE tag is also checked.
+//
+// The offset of the tag's header is returned, and the reader's position is unchanged.
+pub(crate) fn find_appended_id3v2<R>(data: &mut R) -> Result<ID3FindResults<ID3v2Header, u64>>
+where
+	R: Read + Seek,
+{
+	let start = data.stream_position()?;
+
+	let _ = find_id3v1(data, false)?;
+
+	let mut results = find_id3v2_footer(data)?;
+
+	if results.0.is_none() && data.stream_position()? >= 32 {
+		data.seek(SeekFrom::Current(-32))?;
+
+		let mut ape_preamble = [0; 8];
+		data.read_exact(&mut ape_preamble)?;
+
+		if &ape_preamble == APE_PREAMBLE {
+			// Skip to the end of the APE footer, to find its start
+			let ape_end = data.stream_position()? + 24;
+			let ape_header = read_ape_header(data, true)?;
+
+			data.seek(SeekFrom::Start(ape_end - u64::from(ape_header.size)))?;
+			results = find_id3v2_footer(data)?;
+		}
+	}
+
+	data.seek(SeekFrom::Start(start))?;
+	Ok(results)
+}
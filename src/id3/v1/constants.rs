@@ -1,4 +1,4 @@
-#[cfg(any(feature = "id3v1", feature = "mp4_ilst"))]
+#[cfg(any(feature = "id3v1", feature = "id3v2", feature = "mp4_ilst"))]
 /// All possible genres for ID3v1
 pub const GENRES: [&str; 192] = [
 	"Blues",
@@ -195,6 +195,27 @@ pub const GENRES: [&str; 192] = [
 	"Psybient",
 ];
 
+/// Find the index of a genre in [`GENRES`]
+///
+/// This accepts either a genre name (case-insensitive), or a genre number in the ID3v2.4 (`"17"`)
+/// or ID3v2.3 (`"(17)"`) form.
+#[cfg(any(feature = "id3v1", feature = "id3v2"))]
+pub(crate) fn genre_index(genre: &str) -> Option<u8> {
+	if let Some(index) = GENRES.iter().position(|g| g.eq_ignore_ascii_case(genre)) {
+		return Some(index as u8);
+	}
+
+	let number = genre
+		.strip_prefix('(')
+		.and_then(|genre| genre.strip_suffix(')'))
+		.unwrap_or(genre);
+
+	match number.parse::<u8>() {
+		Ok(index) if (index as usize) < GENRES.len() => Some(index),
+		_ => None,
+	}
+}
+
 cfg_if::cfg_if! {
 	if #[cfg(feature = "id3v1")] {
 		use crate::tag::item::ItemKey;
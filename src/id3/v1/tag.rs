@@ -1,6 +1,7 @@
 use crate::error::{LoftyError, Result};
-use crate::id3::v1::constants::GENRES;
+use crate::id3::v1::constants::{genre_index, GENRES};
 use crate::tag::item::{ItemKey, ItemValue, TagItem};
+use crate::tag::timestamp::year_from_date;
 use crate::tag::{Tag, TagType};
 use crate::traits::{Accessor, TagExt};
 
@@ -50,8 +51,8 @@ macro_rules! impl_accessor {
 ///
 /// Two checks are performed when converting a genre:
 ///
-/// * [`GENRES`] contains the string
-/// * The [`ItemValue`](crate::ItemValue) can be parsed into a `u8`
+/// * [`GENRES`] contains the string (case-insensitive)
+/// * The [`ItemValue`](crate::ItemValue) is a genre number, either on its own (`"17"`) or in the ID3v2.3 form (`"(17)"`)
 #[derive(Default, Debug, PartialEq, Eq, Clone)]
 pub struct ID3v1Tag {
 	/// Track title, 30 bytes max
@@ -102,13 +103,8 @@ impl Accessor for ID3v1Tag {
 	}
 
 	fn set_genre(&mut self, genre: String) {
-		let g_str = genre.as_str();
-
-		for (i, g) in GENRES.iter().enumerate() {
-			if g.eq_ignore_ascii_case(g_str) {
-				self.genre = Some(i as u8);
-				break;
-			}
+		if let Some(index) = genre_index(&genre) {
+			self.genre = Some(index);
 		}
 	}
 
@@ -241,26 +237,28 @@ impl From<ID3v1Tag> for Tag {
 }
 
 impl From<Tag> for ID3v1Tag {
-	fn from(input: Tag) -> Self {
+	fn from(mut input: Tag) -> Self {
+		input.convert_typed_items_to_text();
+
 		Self {
 			title: input.get_string(&ItemKey::TrackTitle).map(str::to_owned),
 			artist: input.get_string(&ItemKey::TrackArtist).map(str::to_owned),
 			album: input.get_string(&ItemKey::AlbumTitle).map(str::to_owned),
-			year: input.get_string(&ItemKey::Year).map(str::to_owned),
+			year: input.get_string(&ItemKey::Year).map_or_else(
+				|| {
+					input
+						.get_string(&ItemKey::RecordingDate)
+						.and_then(year_from_date)
+						.map(|year| year.to_string())
+				},
+				|year| Some(year.to_owned()),
+			),
 			comment: input.get_string(&ItemKey::Comment).map(str::to_owned),
 			track_number: input
 				.get_string(&ItemKey::TrackNumber)
 				.map(|g| g.parse::<u8>().ok())
 				.and_then(|g| g),
-			genre: input
-				.get_string(&ItemKey::Genre)
-				.map(|g| {
-					GENRES
-						.iter()
-						.position(|v| v == &g)
-						.map_or_else(|| g.parse::<u8>().ok(), |p| Some(p as u8))
-				})
-				.and_then(|g| g),
+			genre: input.get_string(&ItemKey::Genre).and_then(genre_index),
 		}
 	}
 }
@@ -295,21 +293,16 @@ impl<'a> Into<Id3v1TagRef<'a>> for &'a Tag {
 			title: self.get_string(&ItemKey::TrackTitle),
 			artist: self.get_string(&ItemKey::TrackArtist),
 			album: self.get_string(&ItemKey::AlbumTitle),
-			year: self.get_string(&ItemKey::Year),
+			// Only the first 4 characters are written, which will be the year of a full date
+			year: self
+				.get_string(&ItemKey::Year)
+				.map_or_else(|| self.get_string(&ItemKey::RecordingDate), Some),
 			comment: self.get_string(&ItemKey::Comment),
 			track_number: self
 				.get_string(&ItemKey::TrackNumber)
 				.map(|g| g.parse::<u8>().ok())
 				.and_then(|g| g),
-			genre: self
-				.get_string(&ItemKey::Genre)
-				.map(|g| {
-					GENRES
-						.iter()
-						.position(|v| v == &g)
-						.map_or_else(|| g.parse::<u8>().ok(), |p| Some(p as u8))
-				})
-				.and_then(|g| g),
+			genre: self.get_string(&ItemKey::Genre).and_then(genre_index),
 		}
 	}
 }
@@ -2,6 +2,7 @@
 use super::restrictions::TagRestrictions;
 
 #[derive(Default, Copy, Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[allow(clippy::struct_excessive_bools)]
 /// Flags that apply to the entire tag
 pub struct ID3v2TagFlags {
@@ -1,11 +1,17 @@
 use crate::error::{ID3v2Error, ID3v2ErrorKind, LoftyError, Result};
 use crate::id3::v2::frame::FrameValue;
 use crate::id3::v2::items::encoded_text_frame::EncodedTextFrame;
+use crate::id3::v2::items::event_timing_codes::EventTimingCodes;
+use crate::id3::v2::items::key_value_frame::KeyValueFrame;
 use crate::id3::v2::items::language_frame::LanguageFrame;
 use crate::id3::v2::items::popularimeter::Popularimeter;
+use crate::id3::v2::items::sync_tempo::SynchronizedTempo;
+use crate::id3::v2::items::sync_text::SynchronizedText;
+use crate::id3::v2::items::timestamp_frame::TimestampFrame;
 use crate::id3::v2::ID3v2Version;
-use crate::macros::err;
+use crate::macros::{err, parse_mode_choice};
 use crate::picture::Picture;
+use crate::probe::ParsingMode;
 use crate::util::text::{decode_text, read_to_terminator, utf16_decode, TextEncoding};
 
 use std::io::{Cursor, Read};
@@ -16,6 +22,7 @@ pub(super) fn parse_content(
 	content: &mut &[u8],
 	id: &str,
 	version: ID3v2Version,
+	parse_mode: ParsingMode,
 ) -> Result<Option<FrameValue>> {
 	Ok(match id {
 		// The ID was previously upgraded, but the content remains unchanged, so version is necessary
@@ -27,17 +34,41 @@ pub(super) fn parse_content(
 		"TXXX" => parse_user_defined(content, false, version)?,
 		"WXXX" => parse_user_defined(content, true, version)?,
 		"COMM" | "USLT" => parse_text_language(content, id, version)?,
+		_ if TimestampFrame::FRAME_IDS.contains(&id) => parse_timestamp(content, version)?,
+		_ if KeyValueFrame::FRAME_IDS.contains(&id) => {
+			KeyValueFrame::parse(content, version)?.map(FrameValue::KeyValue)
+		},
+		"TCON" => parse_genre(content, version)?,
 		_ if id.starts_with('T') => parse_text(content, version)?,
 		// Apple proprietary frames
 		// WFED (Podcast URL), GRP1 (Grouping), MVNM (Movement Name), MVIN (Movement Number)
 		"WFED" | "GRP1" | "MVNM" | "MVIN" => parse_text(content, version)?,
 		_ if id.starts_with('W') => parse_link(content)?,
 		"POPM" => Some(parse_popularimeter(content)?),
-		// SYLT, GEOB, and any unknown frames
+		"SYLT" | "ETCO" | "SYTC" => Some(parse_synchronized(content, id, parse_mode)?),
+		// GEOB, and any unknown frames
 		_ => Some(FrameValue::Binary(content.to_vec())),
 	})
 }
 
+// These frames were previously kept as binary, which they fall back to if they can't be parsed
+fn parse_synchronized(content: &[u8], id: &str, parse_mode: ParsingMode) -> Result<FrameValue> {
+	let value = match id {
+		"SYLT" => SynchronizedText::parse(content).map(FrameValue::SynchronizedText),
+		"ETCO" => EventTimingCodes::parse(content).map(FrameValue::EventTimingCodes),
+		_ => SynchronizedTempo::parse(content).map(FrameValue::SynchronizedTempo),
+	};
+
+	match value {
+		Ok(value) => Ok(value),
+		Err(e) => parse_mode_choice!(
+			parse_mode,
+			STRICT: Err(e),
+			DEFAULT: Ok(FrameValue::Binary(content.to_vec()))
+		),
+	}
+}
+
 // There are 2 possibilities for the frame's content: text or link.
 fn parse_user_defined(
 	mut content: &mut &[u8],
@@ -150,6 +181,38 @@ fn parse_text(content: &mut &[u8], version: ID3v2Version) -> Result<Option<Frame
 	}))
 }
 
+fn parse_genre(content: &mut &[u8], version: ID3v2Version) -> Result<Option<FrameValue>> {
+	if content.len() < 2 {
+		return Ok(None);
+	}
+
+	let encoding = verify_encoding(content.read_u8()?, version)?;
+
+	// ID3v2.4 allows for multiple null separated genres, so the entire frame needs to be read.
+	// With UTF-16, each of these values will have its own BOM.
+	let text = decode_text(content, encoding, false)?.unwrap_or_default();
+	let text = text.trim_end_matches('\0').replace('\u{feff}', "");
+
+	Ok(Some(FrameValue::Text {
+		encoding,
+		value: text,
+	}))
+}
+
+fn parse_timestamp(content: &mut &[u8], version: ID3v2Version) -> Result<Option<FrameValue>> {
+	Ok(match parse_text(content, version)? {
+		Some(FrameValue::Text { encoding, value }) => match value.parse() {
+			Ok(timestamp) => Some(FrameValue::Timestamp(TimestampFrame {
+				encoding,
+				timestamp,
+			})),
+			// Anything that isn't a valid timestamp is kept as text
+			Err(_) => Some(FrameValue::Text { encoding, value }),
+		},
+		value => value,
+	})
+}
+
 fn parse_link(content: &mut &[u8]) -> Result<Option<FrameValue>> {
 	if content.is_empty() {
 		return Ok(None);
@@ -183,7 +246,10 @@ fn parse_popularimeter(content: &mut &[u8]) -> Result<FrameValue> {
 	}))
 }
 
-fn verify_encoding(encoding: u8, version: ID3v2Version) -> Result<TextEncoding> {
+pub(in crate::id3::v2) fn verify_encoding(
+	encoding: u8,
+	version: ID3v2Version,
+) -> Result<TextEncoding> {
 	if let ID3v2Version::V2 = version {
 		if encoding != 0 && encoding != 1 {
 			return Err(ID3v2Error::new(ID3v2ErrorKind::Other(
@@ -4,6 +4,7 @@ use crate::tag::TagType;
 
 /// An `ID3v2` frame ID
 #[derive(PartialEq, Clone, Debug, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub enum FrameID {
 	/// A valid `ID3v2.3/4` frame
 	Valid(String),
@@ -50,6 +51,35 @@ impl FrameID {
 	}
 }
 
+// IDs are deserialized through `FrameID::new`, and must end up as the same variant
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for FrameID {
+	fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+	where
+		D: serde::Deserializer<'de>,
+	{
+		#[derive(serde::Deserialize)]
+		#[serde(rename = "FrameID")]
+		enum FrameIDFields {
+			Valid(String),
+			Outdated(String),
+		}
+
+		let (id, outdated) = match FrameIDFields::deserialize(deserializer)? {
+			FrameIDFields::Valid(id) => (id, false),
+			FrameIDFields::Outdated(id) => (id, true),
+		};
+
+		match Self::new(&id).map_err(serde::de::Error::custom)? {
+			frame_id @ FrameID::Valid(_) if !outdated => Ok(frame_id),
+			frame_id @ FrameID::Outdated(_) if outdated => Ok(frame_id),
+			_ => Err(serde::de::Error::custom(LoftyError::from(ID3v2Error::new(
+				ID3v2ErrorKind::BadFrameID,
+			)))),
+		}
+	}
+}
+
 impl TryFrom<&ItemKey> for FrameID {
 	type Error = LoftyError;
 
@@ -63,10 +93,13 @@ impl TryFrom<&ItemKey> for FrameID {
 			{
 				Ok(Self::Valid(unknown.clone()))
 			},
-			k => k.map_key(TagType::ID3v2, false).map_or(
-				Err(ID3v2Error::new(ID3v2ErrorKind::BadFrameID).into()),
-				|id| Ok(Self::Valid(id.to_string())),
-			),
+			// Keys such as "REPLAYGAIN_TRACK_GAIN" are TXXX descriptions, not frame IDs
+			k => match k.map_key(TagType::ID3v2, false) {
+				Some(id) if id.len() == 4 && Self::verify_id(id).is_ok() => {
+					Ok(Self::Valid(id.to_string()))
+				},
+				_ => Err(ID3v2Error::new(ID3v2ErrorKind::BadFrameID).into()),
+			},
 		}
 	}
 }
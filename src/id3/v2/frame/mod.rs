@@ -5,18 +5,24 @@ pub(super) mod read;
 
 use crate::error::{ID3v2Error, ID3v2ErrorKind, LoftyError, Result};
 use crate::id3::v2::items::encoded_text_frame::EncodedTextFrame;
+use crate::id3::v2::items::event_timing_codes::EventTimingCodes;
+use crate::id3::v2::items::key_value_frame::KeyValueFrame;
 use crate::id3::v2::items::language_frame::LanguageFrame;
+use crate::id3::v2::items::timestamp_frame::TimestampFrame;
 use crate::id3::v2::util::upgrade::{upgrade_v2, upgrade_v3};
 use crate::id3::v2::ID3v2Version;
 use crate::picture::Picture;
 use crate::tag::item::{ItemKey, ItemValue, TagItem};
-use crate::tag::TagType;
+use crate::tag::timestamp::Timestamp;
+use crate::tag::{utils, TagType};
 use crate::util::text::{encode_text, TextEncoding};
 use id::FrameID;
 
 use std::borrow::Cow;
 
 use crate::id3::v2::items::popularimeter::Popularimeter;
+use crate::id3::v2::items::sync_tempo::SynchronizedTempo;
+use crate::id3::v2::items::sync_text::SynchronizedText;
 use std::convert::{TryFrom, TryInto};
 use std::hash::{Hash, Hasher};
 
@@ -37,16 +43,50 @@ use std::hash::{Hash, Hasher};
 /// `ID3v2.3`, unlike `ID3v2.2`, stores frame IDs in 4 characters like `ID3v2.4`. There are some IDs that need upgrading (See [`upgrade_v3`]),
 /// but anything that fails to be upgraded **will not** be stored as [`FrameID::Outdated`], as it is likely not an issue to write.
 #[derive(Clone, Debug, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub struct Frame {
 	pub(super) id: FrameID,
 	pub(super) value: FrameValue,
 	pub(super) flags: FrameFlags,
 }
 
+// Frames are deserialized through `Frame::new`, and the value must be valid for the ID
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for Frame {
+	fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+	where
+		D: serde::Deserializer<'de>,
+	{
+		#[derive(serde::Deserialize)]
+		#[serde(rename = "Frame")]
+		struct FrameFields {
+			id: FrameID,
+			value: FrameValue,
+			flags: FrameFlags,
+		}
+
+		let fields = FrameFields::deserialize(deserializer)?;
+
+		let frame = Self::new(fields.id.as_str(), fields.value, fields.flags)
+			.map_err(serde::de::Error::custom)?;
+
+		if let Some(frame_ref) = frame.as_opt_ref() {
+			crate::id3::v2::write::frame::verify_frame(&frame_ref)
+				.map_err(serde::de::Error::custom)?;
+		}
+
+		Ok(frame)
+	}
+}
+
 impl PartialEq for Frame {
 	fn eq(&self, other: &Self) -> bool {
 		match self.value {
-			FrameValue::Text { .. } => self.id == other.id,
+			FrameValue::Text { .. }
+			| FrameValue::Timestamp(_)
+			| FrameValue::KeyValue(_)
+			| FrameValue::EventTimingCodes(_)
+			| FrameValue::SynchronizedTempo(_) => self.id == other.id,
 			_ => self.id == other.id && self.value == other.value,
 		}
 	}
@@ -55,7 +95,11 @@ impl PartialEq for Frame {
 impl Hash for Frame {
 	fn hash<H: Hasher>(&self, state: &mut H) {
 		match self.value {
-			FrameValue::Text { .. } => self.id.hash(state),
+			FrameValue::Text { .. }
+			| FrameValue::Timestamp(_)
+			| FrameValue::KeyValue(_)
+			| FrameValue::EventTimingCodes(_)
+			| FrameValue::SynchronizedTempo(_) => self.id.hash(state),
 			_ => {
 				self.id.hash(state);
 				self.content().hash(state);
@@ -128,6 +172,7 @@ impl Frame {
 /// The value of an `ID3v2` frame
 #[non_exhaustive]
 #[derive(PartialEq, Clone, Debug, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum FrameValue {
 	/// Represents a "COMM" frame
 	///
@@ -169,12 +214,25 @@ pub enum FrameValue {
 	},
 	/// Represents a "POPM" frame
 	Popularimeter(Popularimeter),
+	/// Represents a "TDEN", "TDOR", "TDRC", "TDRL", or "TDTG" frame
+	///
+	/// See [`TimestampFrame`]
+	Timestamp(TimestampFrame),
+	/// Represents a "TIPL" or "TMCL" frame
+	///
+	/// See [`KeyValueFrame`]
+	KeyValue(KeyValueFrame),
+	/// Represents a "SYLT" frame
+	SynchronizedText(SynchronizedText),
+	/// Represents an "ETCO" frame
+	EventTimingCodes(EventTimingCodes),
+	/// Represents a "SYTC" frame
+	SynchronizedTempo(SynchronizedTempo),
 	/// Binary data
 	///
 	/// NOTES:
 	///
-	/// * This is used for "GEOB" and "SYLT" frames, see
-	/// [`GeneralEncapsulatedObject::parse`](crate::id3::v2::GeneralEncapsulatedObject::parse) and [`SynchronizedText::parse`](crate::id3::v2::SynchronizedText::parse) respectively
+	/// * This is used for "GEOB" frames, see [`GeneralEncapsulatedObject::parse`](crate::id3::v2::GeneralEncapsulatedObject::parse)
 	/// * This is used for **all** frames with an ID of [`FrameID::Outdated`]
 	/// * This is used for unknown frames
 	Binary(Vec<u8>),
@@ -189,11 +247,26 @@ impl From<ItemValue> for FrameValue {
 			},
 			ItemValue::Locator(locator) => FrameValue::URL(locator),
 			ItemValue::Binary(binary) => FrameValue::Binary(binary),
+			typed => typed.into_text_value().into(),
 		}
 	}
 }
 
 impl FrameValue {
+	// Timestamp frames that aren't valid timestamps are stored as text
+	pub(super) fn timestamp_or_text(text: String) -> Self {
+		match text.parse::<Timestamp>() {
+			Ok(timestamp) => FrameValue::Timestamp(TimestampFrame {
+				encoding: TextEncoding::Latin1,
+				timestamp,
+			}),
+			Err(_) => FrameValue::Text {
+				encoding: TextEncoding::UTF8,
+				value: text,
+			},
+		}
+	}
+
 	pub(super) fn as_bytes(&self) -> Result<Vec<u8>> {
 		Ok(match self {
 			FrameValue::Comment(lf) | FrameValue::UnSyncText(lf) => lf.as_bytes()?,
@@ -209,6 +282,11 @@ impl FrameValue {
 				picture.as_apic_bytes(ID3v2Version::V4, *encoding)?
 			},
 			FrameValue::Popularimeter(popularimeter) => popularimeter.as_bytes(),
+			FrameValue::Timestamp(timestamp_frame) => timestamp_frame.as_bytes(),
+			FrameValue::KeyValue(key_value_frame) => key_value_frame.as_bytes(),
+			FrameValue::SynchronizedText(sync_text) => sync_text.as_bytes()?,
+			FrameValue::EventTimingCodes(event_timing_codes) => event_timing_codes.as_bytes(),
+			FrameValue::SynchronizedTempo(sync_tempo) => sync_tempo.as_bytes(),
 			FrameValue::Binary(binary) => binary.clone(),
 		})
 	}
@@ -216,6 +294,7 @@ impl FrameValue {
 
 /// Various flags to describe the content of an item
 #[derive(Copy, Clone, Debug, PartialEq, Eq, Hash, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[allow(clippy::struct_excessive_bools)]
 pub struct FrameFlags {
 	/// Preserve frame on tag edit
@@ -263,7 +342,7 @@ impl From<TagItem> for Option<Frame> {
 		match input.key().try_into() {
 			Ok(id) => {
 				// We make the VERY bold assumption the language is English
-				value = match (&id, input.item_value) {
+				value = match (&id, input.item_value.into_text_value()) {
 					(FrameID::Valid(ref s), ItemValue::Text(text)) if s == "COMM" => {
 						FrameValue::Comment(LanguageFrame {
 							encoding: TextEncoding::UTF8,
@@ -296,13 +375,29 @@ impl From<TagItem> for Option<Frame> {
 							content: text,
 						})
 					},
+					(FrameID::Valid(ref s), ItemValue::Text(text)) if s == "UFID" => {
+						FrameValue::Binary(musicbrainz_ufid(&text))
+					},
+					(FrameID::Valid(ref s), ItemValue::Text(text)) if s == "POPM" => {
+						match utils::parse_rating(&text) {
+							Some(rating) => {
+								FrameValue::Popularimeter(Popularimeter::from_rating(rating))
+							},
+							None => return None,
+						}
+					},
+					(FrameID::Valid(ref s), ItemValue::Text(text))
+						if TimestampFrame::FRAME_IDS.contains(&s.as_str()) =>
+					{
+						FrameValue::timestamp_or_text(text)
+					},
 					(_, value) => value.into(),
 				};
 
 				frame_id = id;
 			},
 			Err(_) => match input.item_key.map_key(TagType::ID3v2, true) {
-				Some(desc) => match input.item_value {
+				Some(desc) => match input.item_value.into_text_value() {
 					ItemValue::Text(text) => {
 						frame_id = FrameID::Valid(String::from("TXXX"));
 						value = FrameValue::UserText(EncodedTextFrame {
@@ -319,7 +414,7 @@ impl From<TagItem> for Option<Frame> {
 							content: locator,
 						})
 					},
-					ItemValue::Binary(_) => return None,
+					_ => return None,
 				},
 				None => return None,
 			},
@@ -358,18 +453,51 @@ impl<'a> TryFrom<&'a TagItem> for FrameRef<'a> {
 
 	fn try_from(tag_item: &'a TagItem) -> std::result::Result<Self, Self::Error> {
 		let id = match tag_item.key() {
-			ItemKey::Unknown(unknown) if unknown.len() == 4 => {
-				id::FrameID::verify_id(unknown)?;
-				Ok(unknown.as_str())
-			},
+			// Unknown keys that aren't frame IDs are stored in TXXX/WXXX frames below
+			ItemKey::Unknown(unknown) => unknown.as_str(),
 			k => k
 				.map_key(TagType::ID3v2, false)
-				.ok_or_else(|| ID3v2Error::new(ID3v2ErrorKind::BadFrameID)),
-		}?;
+				.ok_or_else(|| ID3v2Error::new(ID3v2ErrorKind::BadFrameID))?,
+		};
+
+		// Typed values are written as text, which is then parsed back into timestamp frames where necessary
+		let text_value;
+		let item_value = match tag_item.value() {
+			value @ (ItemValue::Integer(_) | ItemValue::Bool(_) | ItemValue::Date(_)) => {
+				text_value = value.clone().into_text_value();
+				&text_value
+			},
+			value => value,
+		};
+
+		// Keys that aren't frame IDs are stored in TXXX/WXXX frames, identified by their descriptions
+		if id.len() != 4 || id::FrameID::verify_id(id).is_err() {
+			return match item_value {
+				ItemValue::Text(text) => Ok(FrameRef {
+					id: "TXXX",
+					value: Cow::Owned(FrameValue::UserText(EncodedTextFrame {
+						encoding: TextEncoding::UTF8,
+						description: String::from(id),
+						content: text.clone(),
+					})),
+					flags: FrameFlags::default(),
+				}),
+				ItemValue::Locator(locator) => Ok(FrameRef {
+					id: "WXXX",
+					value: Cow::Owned(FrameValue::UserURL(EncodedTextFrame {
+						encoding: TextEncoding::UTF8,
+						description: String::from(id),
+						content: locator.clone(),
+					})),
+					flags: FrameFlags::default(),
+				}),
+				_ => Err(ID3v2Error::new(ID3v2ErrorKind::BadFrameID).into()),
+			};
+		}
 
 		Ok(FrameRef {
 			id,
-			value: Cow::Owned(match (id, tag_item.value()) {
+			value: Cow::Owned(match (id, item_value) {
 				("COMM", ItemValue::Text(text)) => FrameValue::Comment(LanguageFrame {
 					encoding: TextEncoding::UTF8,
 					language: *b"eng",
@@ -394,6 +522,20 @@ impl<'a> TryFrom<&'a TagItem> for FrameRef<'a> {
 					description: String::new(),
 					content: text.clone(),
 				}),
+				("UFID", ItemValue::Text(text)) => FrameValue::Binary(musicbrainz_ufid(text)),
+				("POPM", ItemValue::Text(text)) => match utils::parse_rating(text) {
+					Some(rating) => FrameValue::Popularimeter(Popularimeter::from_rating(rating)),
+					None => {
+						return Err(ID3v2Error::new(ID3v2ErrorKind::BadFrame(
+							String::from("POPM"),
+							"Text",
+						))
+						.into())
+					},
+				},
+				(id, ItemValue::Text(text)) if TimestampFrame::FRAME_IDS.contains(&id) => {
+					FrameValue::timestamp_or_text(text.clone())
+				},
 				(_, value) => value.into(),
 			}),
 			flags: FrameFlags::default(),
@@ -401,6 +543,18 @@ impl<'a> TryFrom<&'a TagItem> for FrameRef<'a> {
 	}
 }
 
+// The owner identifier of the `UFID` frame holding a MusicBrainz recording ID
+pub(crate) const MUSICBRAINZ_UFID_OWNER: &[u8] = b"http://musicbrainz.org";
+
+// UFID frames are made up of an owner identifier (null terminated) followed by up to 64 bytes of binary data
+fn musicbrainz_ufid(recording_id: &str) -> Vec<u8> {
+	let mut content = Vec::with_capacity(MUSICBRAINZ_UFID_OWNER.len() + 1 + recording_id.len());
+	content.extend(MUSICBRAINZ_UFID_OWNER);
+	content.push(0);
+	content.extend(recording_id.as_bytes());
+	content
+}
+
 impl<'a> Into<FrameValue> for &'a ItemValue {
 	fn into(self) -> FrameValue {
 		match self {
@@ -410,6 +564,7 @@ impl<'a> Into<FrameValue> for &'a ItemValue {
 			},
 			ItemValue::Locator(locator) => FrameValue::URL(locator.clone()),
 			ItemValue::Binary(binary) => FrameValue::Binary(binary.clone()),
+			typed => typed.clone().into_text_value().into(),
 		}
 	}
 }
@@ -4,13 +4,18 @@ use crate::error::{ID3v2Error, ID3v2ErrorKind, Result};
 use crate::id3::v2::frame::content::parse_content;
 use crate::id3::v2::{FrameValue, ID3v2Version};
 use crate::macros::try_vec;
+use crate::probe::ParsingMode;
 
 use std::io::Read;
 
 use byteorder::{BigEndian, ReadBytesExt};
 
 impl Frame {
-	pub(crate) fn read<R>(reader: &mut R, version: ID3v2Version) -> Result<(Option<Self>, bool)>
+	pub(crate) fn read<R>(
+		reader: &mut R,
+		version: ID3v2Version,
+		parse_mode: ParsingMode,
+	) -> Result<(Option<Self>, bool)>
 	where
 		R: Read,
 	{
@@ -71,7 +76,7 @@ impl Frame {
 
 			Some(FrameValue::Binary(content))
 		} else {
-			parse_content(&mut content_reader, id.as_str(), version)?
+			parse_content(&mut content_reader, id.as_str(), version, parse_mode)?
 		};
 
 		match value {
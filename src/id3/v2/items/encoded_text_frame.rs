@@ -9,6 +9,7 @@ use std::hash::{Hash, Hasher};
 /// This means for each `EncodedTextFrame` in the tag, the description
 /// must be unique.
 #[derive(Clone, Debug, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct EncodedTextFrame {
 	/// The encoding of the description and comment text
 	pub encoding: TextEncoding,
@@ -0,0 +1,195 @@
+use crate::error::{ID3v2Error, ID3v2ErrorKind, Result};
+use crate::id3::v2::TimestampFormat;
+
+use byteorder::{BigEndian, ReadBytesExt};
+
+#[derive(Copy, Clone, PartialEq, Debug, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[allow(missing_docs)]
+/// The type of event in an [`EventTimingCodes`] frame
+pub enum EventType {
+	Padding,
+	EndOfInitialSilence,
+	IntroStart,
+	MainPartStart,
+	OutroStart,
+	OutroEnd,
+	VerseStart,
+	RefrainStart,
+	InterludeStart,
+	ThemeStart,
+	VariationStart,
+	KeyChange,
+	TimeChange,
+	MomentaryUnwantedNoise,
+	SustainedNoise,
+	SustainedNoiseEnd,
+	IntroEnd,
+	MainPartEnd,
+	VerseEnd,
+	RefrainEnd,
+	ThemeEnd,
+	Profanity,
+	ProfanityEnd,
+	/// Not predefined synch 0-F (`$E0`-`$EF`)
+	NotPredefinedSync(u8),
+	AudioEnd,
+	AudioFileEnd,
+	/// Any reserved event type
+	Reserved(u8),
+}
+
+impl EventType {
+	/// Get an `EventType` from a u8
+	pub fn from_u8(byte: u8) -> Self {
+		match byte {
+			0x00 => Self::Padding,
+			0x01 => Self::EndOfInitialSilence,
+			0x02 => Self::IntroStart,
+			0x03 => Self::MainPartStart,
+			0x04 => Self::OutroStart,
+			0x05 => Self::OutroEnd,
+			0x06 => Self::VerseStart,
+			0x07 => Self::RefrainStart,
+			0x08 => Self::InterludeStart,
+			0x09 => Self::ThemeStart,
+			0x0A => Self::VariationStart,
+			0x0B => Self::KeyChange,
+			0x0C => Self::TimeChange,
+			0x0D => Self::MomentaryUnwantedNoise,
+			0x0E => Self::SustainedNoise,
+			0x0F => Self::SustainedNoiseEnd,
+			0x10 => Self::IntroEnd,
+			0x11 => Self::MainPartEnd,
+			0x12 => Self::VerseEnd,
+			0x13 => Self::RefrainEnd,
+			0x14 => Self::ThemeEnd,
+			0x15 => Self::Profanity,
+			0x16 => Self::ProfanityEnd,
+			0xE0..=0xEF => Self::NotPredefinedSync(byte - 0xE0),
+			0xFD => Self::AudioEnd,
+			0xFE => Self::AudioFileEnd,
+			_ => Self::Reserved(byte),
+		}
+	}
+
+	/// Get the u8 representation of the `EventType`
+	pub fn as_u8(self) -> u8 {
+		match self {
+			Self::Padding => 0x00,
+			Self::EndOfInitialSilence => 0x01,
+			Self::IntroStart => 0x02,
+			Self::MainPartStart => 0x03,
+			Self::OutroStart => 0x04,
+			Self::OutroEnd => 0x05,
+			Self::VerseStart => 0x06,
+			Self::RefrainStart => 0x07,
+			Self::InterludeStart => 0x08,
+			Self::ThemeStart => 0x09,
+			Self::VariationStart => 0x0A,
+			Self::KeyChange => 0x0B,
+			Self::TimeChange => 0x0C,
+			Self::MomentaryUnwantedNoise => 0x0D,
+			Self::SustainedNoise => 0x0E,
+			Self::SustainedNoiseEnd => 0x0F,
+			Self::IntroEnd => 0x10,
+			Self::MainPartEnd => 0x11,
+			Self::VerseEnd => 0x12,
+			Self::RefrainEnd => 0x13,
+			Self::ThemeEnd => 0x14,
+			Self::Profanity => 0x15,
+			Self::ProfanityEnd => 0x16,
+			Self::NotPredefinedSync(sync) => 0xE0 | (sync & 0x0F),
+			Self::AudioEnd => 0xFD,
+			Self::AudioFileEnd => 0xFE,
+			Self::Reserved(byte) => byte,
+		}
+	}
+}
+
+/// Represents an ID3v2 event timing codes frame (`ETCO`)
+#[derive(PartialEq, Clone, Debug, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct EventTimingCodes {
+	/// The format of the timestamps
+	pub timestamp_format: TimestampFormat,
+	/// Collection of timestamps and events, sorted chronologically
+	pub events: Vec<(u32, EventType)>,
+}
+
+impl EventTimingCodes {
+	/// Read an [`EventTimingCodes`] from a slice
+	///
+	/// NOTE: This expects the frame header to have already been skipped
+	///
+	/// # Errors
+	///
+	/// * The timestamp format is invalid
+	/// * An event is missing its timestamp
+	pub fn parse(mut data: &[u8]) -> Result<Self> {
+		let timestamp_format = TimestampFormat::from_u8(data.read_u8()?)
+			.ok_or_else(|| ID3v2Error::new(ID3v2ErrorKind::BadTimestampFormat))?;
+
+		let mut events = Vec::with_capacity(data.len() / 5);
+		while !data.is_empty() {
+			let event_type = EventType::from_u8(data.read_u8()?);
+			let timestamp = data
+				.read_u32::<BigEndian>()
+				.map_err(|_| ID3v2Error::new(ID3v2ErrorKind::BadFrameLength))?;
+
+			events.push((timestamp, event_type));
+		}
+
+		Ok(Self {
+			timestamp_format,
+			events,
+		})
+	}
+
+	/// Convert an [`EventTimingCodes`] to an ID3v2 ETCO frame byte Vec
+	///
+	/// NOTE: This does not include the frame header
+	pub fn as_bytes(&self) -> Vec<u8> {
+		let mut data = vec![self.timestamp_format as u8];
+
+		for (timestamp, event_type) in &self.events {
+			data.push(event_type.as_u8());
+			data.extend(timestamp.to_be_bytes());
+		}
+
+		data
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use crate::id3::v2::{EventTimingCodes, EventType, TimestampFormat};
+
+	#[test]
+	fn etco_re_read() {
+		let etco = EventTimingCodes {
+			timestamp_format: TimestampFormat::MS,
+			events: vec![
+				(0, EventType::EndOfInitialSilence),
+				(1500, EventType::VerseStart),
+				(20000, EventType::NotPredefinedSync(0xA)),
+				(30000, EventType::Reserved(0x20)),
+				(45000, EventType::AudioEnd),
+			],
+		};
+
+		let bytes = etco.as_bytes();
+		assert_eq!(bytes.len(), 1 + 5 * 5);
+		assert_eq!(bytes[11], 0xEA);
+
+		assert_eq!(EventTimingCodes::parse(&bytes).unwrap(), etco);
+	}
+
+	#[test]
+	fn etco_invalid() {
+		// Invalid timestamp format
+		assert!(EventTimingCodes::parse(&[3, 1, 0, 0, 0, 0]).is_err());
+		// Truncated timestamp
+		assert!(EventTimingCodes::parse(&[2, 1, 0, 0]).is_err());
+	}
+}
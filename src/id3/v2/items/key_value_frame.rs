@@ -0,0 +1,121 @@
+use crate::error::Result;
+use crate::id3::v2::frame::content::verify_encoding;
+use crate::id3::v2::ID3v2Version;
+use crate::util::text::{decode_text, encode_text, TextEncoding};
+
+use byteorder::ReadBytesExt;
+
+/// An `ID3v2` frame holding a list of key/value pairs
+///
+/// This is used in the frames `TIPL` (involved people list) and `TMCL` (musician credits list),
+/// where each pair is made up of a role (or instrument) and a name.
+///
+/// NOTE: The ID3v2.3 `IPLS` and ID3v2.2 `IPL` frames are upgraded to `TIPL` when read. As those frames
+/// were also used for musician credits, they may end up in `TIPL` rather than `TMCL`.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct KeyValueFrame {
+	/// The encoding of the text
+	pub encoding: TextEncoding,
+	/// The (role, name) pairs
+	pub key_value_pairs: Vec<(String, String)>,
+}
+
+impl KeyValueFrame {
+	/// The frame IDs that hold key/value pairs
+	pub const FRAME_IDS: [&'static str; 2] = ["TIPL", "TMCL"];
+
+	/// Read a [`KeyValueFrame`] from a slice
+	///
+	/// NOTE: If there is an odd number of strings, the final key will have an empty value.
+	///
+	/// # Errors
+	///
+	/// * Unable to decode the text
+	///
+	/// ID3v2.2:
+	///
+	/// * The encoding is not [`TextEncoding::Latin1`] or [`TextEncoding::UTF16`]
+	pub fn parse(mut content: &[u8], version: ID3v2Version) -> Result<Option<Self>> {
+		if content.len() < 2 {
+			return Ok(None);
+		}
+
+		let encoding = verify_encoding(content.read_u8()?, version)?;
+
+		let mut strings = Vec::new();
+		while !content.is_empty() {
+			strings.push(decode_text(&mut content, encoding, true)?.unwrap_or_default());
+		}
+
+		let mut strings = strings.into_iter();
+		let mut key_value_pairs = Vec::with_capacity(strings.len() / 2);
+
+		while let Some(key) = strings.next() {
+			key_value_pairs.push((key, strings.next().unwrap_or_default()));
+		}
+
+		Ok(Some(Self {
+			encoding,
+			key_value_pairs,
+		}))
+	}
+
+	/// Convert a [`KeyValueFrame`] to a byte vec
+	pub fn as_bytes(&self) -> Vec<u8> {
+		let mut bytes = vec![self.encoding as u8];
+
+		for (key, value) in &self.key_value_pairs {
+			bytes.extend(encode_text(key, self.encoding, true));
+			bytes.extend(encode_text(value, self.encoding, true));
+		}
+
+		bytes
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use crate::id3::v2::{ID3v2Version, KeyValueFrame};
+	use crate::util::text::TextEncoding;
+
+	#[test]
+	fn key_value_frame_re_read() {
+		for encoding in [
+			TextEncoding::Latin1,
+			TextEncoding::UTF16,
+			TextEncoding::UTF8,
+		] {
+			let frame = KeyValueFrame {
+				encoding,
+				key_value_pairs: vec![
+					(String::from("producer"), String::from("Foo producer")),
+					(String::from("mix"), String::new()),
+					(String::from("engineer"), String::from("Bar engineer")),
+				],
+			};
+
+			let bytes = frame.as_bytes();
+			let parsed = KeyValueFrame::parse(&bytes, ID3v2Version::V4)
+				.unwrap()
+				.unwrap();
+
+			assert_eq!(frame, parsed);
+		}
+	}
+
+	#[test]
+	fn key_value_frame_odd_strings() {
+		let parsed = KeyValueFrame::parse(b"\0guitar\0Foo\0drums", ID3v2Version::V3)
+			.unwrap()
+			.unwrap();
+
+		assert_eq!(
+			parsed.key_value_pairs,
+			vec![
+				(String::from("guitar"), String::from("Foo")),
+				(String::from("drums"), String::new()),
+			]
+		);
+	}
+}
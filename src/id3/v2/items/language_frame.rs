@@ -7,6 +7,7 @@ use std::hash::{Hash, Hasher};
 ///
 /// See [`EncodedTextFrame`](crate::id3::v2::EncodedTextFrame)
 #[derive(Clone, Debug, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct LanguageFrame {
 	/// The encoding of the description and comment text
 	pub encoding: TextEncoding,
@@ -1,5 +1,9 @@
 pub(super) mod encapsulated_object;
 pub(super) mod encoded_text_frame;
+pub(super) mod event_timing_codes;
+pub(super) mod key_value_frame;
 pub(super) mod language_frame;
 pub(super) mod popularimeter;
+pub(super) mod sync_tempo;
 pub(super) mod sync_text;
+pub(super) mod timestamp_frame;
@@ -1,3 +1,4 @@
+use crate::tag::rating::Rating;
 use crate::util::text::{encode_text, TextEncoding};
 
 use std::hash::{Hash, Hasher};
@@ -7,13 +8,15 @@ use std::hash::{Hash, Hasher};
 /// A tag can contain multiple "POPM" frames, but there must only be
 /// one with the same email address.
 #[derive(Clone, Debug, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Popularimeter {
 	/// An email address of the user performing the rating
 	pub email: String,
 	/// A rating of 1-255, where 1 is the worst and 255 is the best.
 	/// A rating of 0 is unknown.
 	///
-	/// For mapping this value to a star rating see: <https://en.wikipedia.org/wiki/ID3#ID3v2_star_rating_tag_issue>
+	/// For mapping this value to a star rating see: <https://en.wikipedia.org/wiki/ID3#ID3v2_star_rating_tag_issue>,
+	/// or [`Rating::from_popularimeter`].
 	pub rating: u8,
 	/// A play counter for the user. It is to be incremented each time the file is played.
 	///
@@ -22,6 +25,18 @@ pub struct Popularimeter {
 }
 
 impl Popularimeter {
+	/// The email used by Windows Media Player, which is also understood by most other applications
+	pub(crate) const DEFAULT_EMAIL: &'static str = "Windows Media Player 9 Series";
+
+	// Used when creating a POPM frame from a generic rating
+	pub(crate) fn from_rating(rating: Rating) -> Self {
+		Self {
+			email: String::from(Self::DEFAULT_EMAIL),
+			rating: rating.as_popularimeter(),
+			counter: 0,
+		}
+	}
+
 	/// Convert a [`Popularimeter`] into an ID3v2 POPM frame byte Vec
 	///
 	/// NOTE: This does not include a frame header
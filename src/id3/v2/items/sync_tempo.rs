@@ -0,0 +1,110 @@
+use crate::error::{ID3v2Error, ID3v2ErrorKind, Result};
+use crate::id3::v2::TimestampFormat;
+
+use byteorder::{BigEndian, ReadBytesExt};
+
+/// Represents an ID3v2 synchronized tempo codes frame (`SYTC`)
+///
+/// Tempos are stored in beats per minute, from 2 to 510. There are two special values:
+///
+/// * `0` - A beat-free time period
+/// * `1` - A single beat, followed by a beat-free time period
+#[derive(PartialEq, Clone, Debug, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SynchronizedTempo {
+	/// The format of the timestamps
+	pub timestamp_format: TimestampFormat,
+	/// Collection of timestamps and tempos, sorted chronologically
+	pub content: Vec<(u32, u16)>,
+}
+
+impl SynchronizedTempo {
+	/// The maximum tempo that can be stored
+	pub const MAX_TEMPO: u16 = 510;
+
+	/// Read a [`SynchronizedTempo`] from a slice
+	///
+	/// NOTE: This expects the frame header to have already been skipped
+	///
+	/// # Errors
+	///
+	/// * The timestamp format is invalid
+	/// * A tempo is missing its timestamp
+	pub fn parse(mut data: &[u8]) -> Result<Self> {
+		let timestamp_format = TimestampFormat::from_u8(data.read_u8()?)
+			.ok_or_else(|| ID3v2Error::new(ID3v2ErrorKind::BadTimestampFormat))?;
+
+		let mut content = Vec::with_capacity(data.len() / 5);
+		while !data.is_empty() {
+			let (tempo, timestamp) = (|| -> std::io::Result<(u16, u32)> {
+				// Tempos above 254 are stored as $FF followed by the remaining amount
+				let mut tempo = u16::from(data.read_u8()?);
+				if tempo == 0xFF {
+					tempo += u16::from(data.read_u8()?);
+				}
+
+				Ok((tempo, data.read_u32::<BigEndian>()?))
+			})()
+			.map_err(|_| ID3v2Error::new(ID3v2ErrorKind::BadFrameLength))?;
+
+			content.push((timestamp, tempo));
+		}
+
+		Ok(Self {
+			timestamp_format,
+			content,
+		})
+	}
+
+	/// Convert a [`SynchronizedTempo`] to an ID3v2 SYTC frame byte Vec
+	///
+	/// NOTE: This does not include the frame header
+	///
+	/// Tempos above [`SynchronizedTempo::MAX_TEMPO`] will be clamped.
+	pub fn as_bytes(&self) -> Vec<u8> {
+		let mut data = vec![self.timestamp_format as u8];
+
+		for (timestamp, tempo) in &self.content {
+			let tempo = (*tempo).min(Self::MAX_TEMPO);
+			if tempo >= 0xFF {
+				data.push(0xFF);
+				data.push((tempo - 0xFF) as u8);
+			} else {
+				data.push(tempo as u8);
+			}
+
+			data.extend(timestamp.to_be_bytes());
+		}
+
+		data
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use crate::id3::v2::{SynchronizedTempo, TimestampFormat};
+
+	#[test]
+	fn sytc_re_read() {
+		let sytc = SynchronizedTempo {
+			timestamp_format: TimestampFormat::MPEG,
+			content: vec![(0, 0), (10, 1), (500, 120), (1000, 255), (1500, 510)],
+		};
+
+		let bytes = sytc.as_bytes();
+		assert_eq!(
+			&bytes[16..],
+			&[0xFF, 0x00, 0, 0, 0x03, 0xE8, 0xFF, 0xFF, 0, 0, 0x05, 0xDC]
+		);
+
+		assert_eq!(SynchronizedTempo::parse(&bytes).unwrap(), sytc);
+	}
+
+	#[test]
+	fn sytc_invalid() {
+		// Invalid timestamp format
+		assert!(SynchronizedTempo::parse(&[0, 120, 0, 0, 0, 0]).is_err());
+		// Missing the second tempo byte
+		assert!(SynchronizedTempo::parse(&[2, 0xFF]).is_err());
+	}
+}
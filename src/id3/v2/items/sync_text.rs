@@ -2,11 +2,13 @@ use crate::error::{ErrorKind, ID3v2Error, ID3v2ErrorKind, LoftyError, Result};
 use crate::macros::err;
 use crate::util::text::{decode_text, encode_text, read_to_terminator, utf16_decode, TextEncoding};
 
+use std::fmt::Write as _;
 use std::io::{Cursor, Read, Seek, SeekFrom, Write};
 
 use byteorder::{BigEndian, ReadBytesExt, WriteBytesExt};
 
 #[derive(Copy, Clone, PartialEq, Debug, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[repr(u8)]
 /// The unit used for [`SynchronizedText`] timestamps
 pub enum TimestampFormat {
@@ -28,6 +30,7 @@ impl TimestampFormat {
 }
 
 #[derive(Copy, Clone, PartialEq, Debug, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[repr(u8)]
 #[allow(missing_docs)]
 /// The type of text stored in a [`SynchronizedText`]
@@ -62,6 +65,7 @@ impl SyncTextContentType {
 }
 
 #[derive(PartialEq, Clone, Debug, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 /// Information about a [`SynchronizedText`]
 pub struct SyncTextInformation {
 	/// The text encoding (description/text)
@@ -78,6 +82,7 @@ pub struct SyncTextInformation {
 
 /// Represents an ID3v2 synchronized text frame
 #[derive(PartialEq, Clone, Debug, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct SynchronizedText {
 	/// Information about the synchronized text
 	pub information: SyncTextInformation,
@@ -115,32 +120,23 @@ impl SynchronizedText {
 
 		// It's possible for the description to be the only string with a BOM
 		// To be safe, we change the encoding to the concrete variant determined from the description
-		if encoding == TextEncoding::UTF16 {
-			endianness = match [cursor.get_ref()[6], cursor.get_ref()[7]] {
-				[0xFF, 0xFE] => u16::from_le_bytes,
-				[0xFE, 0xFF] => u16::from_be_bytes,
-				// Since the description was already read, we can assume the BOM was valid
-				_ => unreachable!(),
-			};
+		if encoding == TextEncoding::UTF16 && data.get(6..8) == Some(&[0xFE, 0xFF]) {
+			endianness = u16::from_be_bytes;
 		}
 
-		let mut pos = cursor.stream_position()? as u32;
-		let total = (data.len() - 6) as u32 - pos;
-
 		let mut content = Vec::new();
 
-		while pos < total {
+		while (cursor.position() as usize) < cursor.get_ref().len() {
 			let text = (|| -> Result<String> {
 				if encoding == TextEncoding::UTF16 {
 					let mut bom = [0; 2];
 					cursor
 						.read_exact(&mut bom)
 						.map_err(|_| ID3v2Error::new(ID3v2ErrorKind::BadSyncText))?;
+					cursor.seek(SeekFrom::Current(-2))?;
 
 					// Encountered text that doesn't include a BOM
 					if bom != [0xFF, 0xFE] && bom != [0xFE, 0xFF] {
-						cursor.seek(SeekFrom::Current(-2))?;
-
 						if let Some(raw_text) = read_to_terminator(&mut cursor, TextEncoding::UTF16)
 						{
 							return utf16_decode(&raw_text, endianness)
@@ -156,12 +152,9 @@ impl SynchronizedText {
 					.unwrap_or_default())
 			})()?;
 
-			pos += text.len() as u32;
-
 			let time = cursor
 				.read_u32::<BigEndian>()
 				.map_err(|_| ID3v2Error::new(ID3v2ErrorKind::BadSyncText))?;
-			pos += 4;
 
 			content.push((time, text));
 		}
@@ -221,6 +214,162 @@ impl SynchronizedText {
 
 		Err(ID3v2Error::new(ID3v2ErrorKind::BadSyncText).into())
 	}
+
+	/// Create a [`SynchronizedText`] from the contents of an LRC file
+	///
+	/// Every timestamped line becomes an entry, with lines that have multiple timestamps being repeated.
+	/// The `[la:]` tag is used as the language if it is a valid ISO-639-2 code, otherwise it is `und`.
+	/// The `[offset:]` tag is applied to all timestamps, and all other tags are ignored.
+	///
+	/// The resulting entries are sorted by their timestamps, which are always in [`TimestampFormat::MS`].
+	///
+	/// # Errors
+	///
+	/// * A timestamp is invalid
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use lofty::id3::v2::SynchronizedText;
+	///
+	/// # fn main() -> lofty::Result<()> {
+	/// let lrc = "[ti:Foo title]\n[00:01.50]Foo lyrics\n[00:03.00][00:06.00]Bar lyrics";
+	/// let sylt = SynchronizedText::from_lrc(lrc)?;
+	///
+	/// assert_eq!(sylt.content[0], (1500, String::from("Foo lyrics")));
+	/// assert_eq!(sylt.content[2], (6000, String::from("Bar lyrics")));
+	///
+	/// assert_eq!(
+	/// 	sylt.to_lrc().as_deref(),
+	/// 	Some("[00:01.50]Foo lyrics\n[00:03.00]Bar lyrics\n[00:06.00]Bar lyrics\n")
+	/// );
+	/// # Ok(()) }
+	/// ```
+	pub fn from_lrc(lrc: &str) -> Result<Self> {
+		let mut language = String::from("und");
+		let mut offset = 0_i64;
+		let mut content = Vec::new();
+
+		for line in lrc.lines() {
+			let mut line = line.trim();
+			let mut timestamps = Vec::new();
+
+			while let Some(stripped) = line.strip_prefix('[') {
+				let (tag, remaining) = match stripped.split_once(']') {
+					Some(split) => split,
+					None => break,
+				};
+
+				line = remaining;
+
+				if tag.starts_with(|c: char| c.is_ascii_digit()) {
+					timestamps.push(parse_lrc_timestamp(tag)?);
+					continue;
+				}
+
+				match tag.split_once(':') {
+					Some(("la", lang))
+						if lang.len() == 3 && lang.chars().all(|c| c.is_ascii_lowercase()) =>
+					{
+						language = lang.to_string()
+					},
+					Some(("offset", value)) => {
+						offset = value.trim().parse().map_err(|_| {
+							ID3v2Error::new(ID3v2ErrorKind::Other("LRC has an invalid offset"))
+						})?
+					},
+					_ => {},
+				}
+			}
+
+			for timestamp in timestamps {
+				content.push((timestamp, line.to_string()));
+			}
+		}
+
+		// A positive offset means the lyrics appear sooner
+		for (timestamp, _) in &mut content {
+			*timestamp = (i64::from(*timestamp) - offset).clamp(0, i64::from(u32::MAX)) as u32;
+		}
+
+		content.sort_by_key(|(timestamp, _)| *timestamp);
+
+		Ok(Self {
+			information: SyncTextInformation {
+				encoding: TextEncoding::UTF8,
+				language,
+				timestamp_format: TimestampFormat::MS,
+				content_type: SyncTextContentType::Lyrics,
+				description: None,
+			},
+			content,
+		})
+	}
+
+	/// Convert the content to an LRC file
+	///
+	/// Entries starting with a newline begin a new line, with any entries in between
+	/// (such as syllables) being appended to it. If no entries start with a newline, every entry is its own line.
+	///
+	/// This will return `None` if the timestamps are not in [`TimestampFormat::MS`].
+	pub fn to_lrc(&self) -> Option<String> {
+		if self.information.timestamp_format != TimestampFormat::MS {
+			return None;
+		}
+
+		let is_line_start = |text: &str| text.starts_with(['\n', '\r']);
+		let grouped = self.content.iter().any(|(_, text)| is_line_start(text));
+
+		let mut lines: Vec<(u32, String)> = Vec::new();
+		for (timestamp, text) in &self.content {
+			match lines.last_mut() {
+				Some((_, line)) if grouped && !is_line_start(text) => line.push_str(text),
+				_ => lines.push((
+					*timestamp,
+					text.trim_start_matches(['\r', '\n']).to_string(),
+				)),
+			}
+		}
+
+		let mut lrc = String::new();
+		for (timestamp, line) in lines {
+			let centiseconds = timestamp / 10;
+			let _ = writeln!(
+				lrc,
+				"[{:02}:{:02}.{:02}]{}",
+				centiseconds / 6000,
+				(centiseconds / 100) % 60,
+				centiseconds % 100,
+				line
+			);
+		}
+
+		Some(lrc)
+	}
+}
+
+// Parses an LRC timestamp, "mm:ss", "mm:ss.xx", or "mm:ss.xxx", into milliseconds
+fn parse_lrc_timestamp(timestamp: &str) -> Result<u32> {
+	let invalid = || ID3v2Error::new(ID3v2ErrorKind::Other("LRC has an invalid timestamp"));
+
+	let (minutes, seconds) = timestamp.split_once(':').ok_or_else(invalid)?;
+	let (seconds, fraction) = seconds.split_once(['.', ':']).unwrap_or((seconds, "0"));
+
+	let minutes = minutes.parse::<u32>().map_err(|_| invalid())?;
+	let seconds = seconds.parse::<u32>().map_err(|_| invalid())?;
+
+	if seconds > 59 || fraction.is_empty() || fraction.len() > 3 {
+		return Err(invalid().into());
+	}
+
+	// The fraction is scaled to milliseconds, ".5" and ".50" are both 500
+	let fraction =
+		fraction.parse::<u32>().map_err(|_| invalid())? * 10_u32.pow(3 - fraction.len() as u32);
+
+	minutes
+		.checked_mul(60_000)
+		.and_then(|ms| ms.checked_add(seconds * 1000 + fraction))
+		.ok_or_else(|| invalid().into())
 }
 
 #[cfg(test)]
@@ -256,6 +405,13 @@ mod tests {
 		assert_eq!(parsed_sylt, expected);
 	}
 
+	#[test]
+	fn sylt_truncated_utf16() {
+		// A UTF-16 frame that ends in the middle of the description's BOM
+		let parsed = SynchronizedText::parse(&[1, b'e', b'n', b'g', 2, 1, 0xFE]).unwrap();
+		assert!(parsed.content.is_empty());
+	}
+
 	#[test]
 	fn sylt_encode() {
 		let to_encode = SynchronizedText {
@@ -282,4 +438,59 @@ mod tests {
 
 		assert_eq!(encoded, expected_bytes);
 	}
+
+	#[test]
+	fn sylt_from_lrc() {
+		let lrc = "[ar:Foo artist]\n\
+		           [la:deu]\n\
+		           [offset:+500]\n\
+		           [00:10.00][01:00.5]Foo\n\
+		           [00:05.123]Bar\n\
+		           Not a lyric\n\
+		           [00:00.00]\n";
+
+		let sylt = SynchronizedText::from_lrc(lrc).unwrap();
+
+		assert_eq!(sylt.information.language, "deu");
+		assert_eq!(sylt.information.timestamp_format, TimestampFormat::MS);
+		assert_eq!(
+			sylt.content,
+			vec![
+				(0, String::new()),
+				(4623, String::from("Bar")),
+				(9500, String::from("Foo")),
+				(60000, String::from("Foo")),
+			]
+		);
+
+		assert!(SynchronizedText::from_lrc("[00:60.00]Foo").is_err());
+		assert!(SynchronizedText::from_lrc("[00:10.0000]Foo").is_err());
+	}
+
+	#[test]
+	fn sylt_to_lrc() {
+		let mut sylt = SynchronizedText {
+			information: SyncTextInformation {
+				encoding: TextEncoding::Latin1,
+				language: String::from("eng"),
+				timestamp_format: TimestampFormat::MS,
+				content_type: SyncTextContentType::Lyrics,
+				description: None,
+			},
+			content: vec![
+				(0, String::from("\nLof")),
+				(500, String::from("ty")),
+				(10000, String::from("\nIs")),
+				(1_938_000, String::from("\nReading")),
+			],
+		};
+
+		assert_eq!(
+			sylt.to_lrc().as_deref(),
+			Some("[00:00.00]Lofty\n[00:10.00]Is\n[32:18.00]Reading\n")
+		);
+
+		sylt.information.timestamp_format = TimestampFormat::MPEG;
+		assert!(sylt.to_lrc().is_none());
+	}
 }
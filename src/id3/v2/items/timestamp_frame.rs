@@ -0,0 +1,33 @@
+use crate::tag::timestamp::Timestamp;
+use crate::util::text::{encode_text, TextEncoding};
+
+/// An `ID3v2` timestamp frame
+///
+/// This is used in the frames `TDEN`, `TDOR`, `TDRC`, `TDRL`, and `TDTG`.
+/// A frame that fails to parse as a [`Timestamp`] will be read as a [`FrameValue::Text`](crate::id3::v2::FrameValue::Text) instead.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct TimestampFrame {
+	/// The encoding of the timestamp
+	pub encoding: TextEncoding,
+	/// The timestamp itself
+	pub timestamp: Timestamp,
+}
+
+impl TimestampFrame {
+	/// The frame IDs that hold timestamps
+	pub const FRAME_IDS: [&'static str; 5] = ["TDEN", "TDOR", "TDRC", "TDRL", "TDTG"];
+
+	/// Convert a [`TimestampFrame`] to a byte vec
+	pub fn as_bytes(&self) -> Vec<u8> {
+		let mut bytes = vec![self.encoding as u8];
+
+		bytes.extend(encode_text(
+			&self.timestamp.to_string(),
+			self.encoding,
+			false,
+		));
+
+		bytes
+	}
+}
@@ -33,6 +33,10 @@ cfg_if::cfg_if! {
 		pub use items::language_frame::LanguageFrame;
 		pub use items::encapsulated_object::{GEOBInformation, GeneralEncapsulatedObject};
 		pub use items::sync_text::{SyncTextContentType, SyncTextInformation, SynchronizedText, TimestampFormat};
+		pub use items::event_timing_codes::{EventTimingCodes, EventType};
+		pub use items::sync_tempo::SynchronizedTempo;
+		pub use items::timestamp_frame::TimestampFrame;
+		pub use items::key_value_frame::KeyValueFrame;
 
 		mod frame;
 		pub use frame::id::FrameID;
@@ -49,7 +53,8 @@ cfg_if::cfg_if! {
 	if #[cfg(feature = "id3v2_restrictions")] {
 		mod restrictions;
 		pub use restrictions::{
-			ImageSizeRestrictions, TagRestrictions, TagSizeRestrictions, TextSizeRestrictions,
+			ImageSizeRestrictions, RestrictionMode, RestrictionViolation, RestrictionViolationKind,
+			TagRestrictions, TagSizeRestrictions, TextSizeRestrictions,
 		};
 	}
 }
@@ -58,6 +63,7 @@ cfg_if::cfg_if! {
 use flags::ID3v2TagFlags;
 
 #[derive(PartialEq, Eq, Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 /// The ID3v2 version
 pub enum ID3v2Version {
 	/// ID3v2.2
@@ -68,6 +74,26 @@ pub enum ID3v2Version {
 	V4,
 }
 
+#[derive(PartialEq, Eq, Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+/// The location of an ID3v2 tag within a file
+///
+/// ID3v2.4 allows for a tag to be appended to a file, located through its footer. A prepended tag
+/// may also point to an update tag further in the stream with a `SEEK` frame.
+///
+/// NOTE: Regardless of where the tag was read from, it will always be written to the start of the file,
+/// and any appended tag will be removed.
+pub enum ID3v2TagLocation {
+	/// The tag is at the start of the file
+	Prepended,
+	/// The tag is at the end of the file, located by its footer
+	Appended,
+	/// The tag is made up of a prepended tag, and an update tag located by its footer or a `SEEK` frame
+	///
+	/// Frames in the update tag replace those in the prepended tag.
+	PrependedAndAppended,
+}
+
 #[derive(Copy, Clone)]
 pub(crate) struct ID3v2Header {
 	#[cfg(feature = "id3v2")]
@@ -3,10 +3,15 @@ use super::tag::ID3v2Tag;
 use super::ID3v2Header;
 use crate::error::Result;
 use crate::macros::try_vec;
+use crate::probe::ParsingMode;
 
 use std::io::Read;
 
-pub(crate) fn parse_id3v2<R>(bytes: &mut R, header: ID3v2Header) -> Result<ID3v2Tag>
+pub(crate) fn parse_id3v2<R>(
+	bytes: &mut R,
+	header: ID3v2Header,
+	parse_mode: ParsingMode,
+) -> Result<ID3v2Tag>
 where
 	R: Read,
 {
@@ -25,7 +30,7 @@ where
 	let reader = &mut &*tag_bytes;
 
 	loop {
-		match Frame::read(reader, header.version)? {
+		match Frame::read(reader, header.version, parse_mode)? {
 			// No frame content found, and we can expect there are no more frames
 			(None, true) => break,
 			(Some(f), false) => drop(tag.insert(f)),
@@ -44,5 +49,5 @@ fn zero_size_id3v2() {
 
 	let mut f = Cursor::new(std::fs::read("tests/tags/assets/id3v2/zero.id3v2").unwrap());
 	let header = read_id3v2_header(&mut f).unwrap();
-	assert!(parse_id3v2(&mut f, header).is_ok());
+	assert!(parse_id3v2(&mut f, header, ParsingMode::Strict).is_ok());
 }
@@ -1,4 +1,19 @@
+use super::flags::ID3v2TagFlags;
+use super::frame::id::FrameID;
+use super::frame::{Frame, FrameRef, FrameValue};
+use super::items::encoded_text_frame::EncodedTextFrame;
+use super::items::key_value_frame::KeyValueFrame;
+use super::items::language_frame::LanguageFrame;
+use super::items::sync_text::{SyncTextInformation, SynchronizedText};
+use super::items::timestamp_frame::TimestampFrame;
+use crate::error::{ID3v2Error, ID3v2ErrorKind, LoftyError, Result};
+use crate::picture::{MimeType, PictureInformation};
+use crate::util::text::TextEncoding;
+
+use std::borrow::Cow;
+
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[allow(non_camel_case_types)]
 /// Restrictions on the tag size
 pub enum TagSizeRestrictions {
@@ -19,6 +34,7 @@ impl Default for TagSizeRestrictions {
 }
 
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[allow(non_camel_case_types)]
 /// Restrictions on text field sizes
 pub enum TextSizeRestrictions {
@@ -39,6 +55,7 @@ impl Default for TextSizeRestrictions {
 }
 
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[allow(non_camel_case_types)]
 /// Restrictions on all image sizes
 pub enum ImageSizeRestrictions {
@@ -59,6 +76,7 @@ impl Default for ImageSizeRestrictions {
 }
 
 #[derive(Default, Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 /// Restrictions on the content of an ID3v2 tag
 pub struct TagRestrictions {
 	/// Restriction on the size of the tag. See [`TagSizeRestrictions`]
@@ -89,7 +107,7 @@ impl TagRestrictions {
 		let restriction_flags = byte;
 
 		// xx000000
-		match restriction_flags & 0x0C {
+		match restriction_flags & 0xC0 {
 			64 => restrictions.size = TagSizeRestrictions::S_64F_128K,
 			128 => restrictions.size = TagSizeRestrictions::S_32F_40K,
 			192 => restrictions.size = TagSizeRestrictions::S_32F_4K,
@@ -134,7 +152,7 @@ impl TagRestrictions {
 			TagSizeRestrictions::S_128F_1M => {},
 			TagSizeRestrictions::S_64F_128K => byte |= 0x40,
 			TagSizeRestrictions::S_32F_40K => byte |= 0x80,
-			TagSizeRestrictions::S_32F_4K => byte |= 0x0C,
+			TagSizeRestrictions::S_32F_4K => byte |= 0xC0,
 		}
 
 		if self.text_encoding {
@@ -162,3 +180,662 @@ impl TagRestrictions {
 		byte
 	}
 }
+
+impl TagSizeRestrictions {
+	/// The maximum number of frames allowed in the tag
+	pub fn max_frames(self) -> usize {
+		match self {
+			TagSizeRestrictions::S_128F_1M => 128,
+			TagSizeRestrictions::S_64F_128K => 64,
+			TagSizeRestrictions::S_32F_40K | TagSizeRestrictions::S_32F_4K => 32,
+		}
+	}
+
+	/// The maximum size of the tag in bytes, including its header and footer
+	pub fn max_size(self) -> usize {
+		match self {
+			TagSizeRestrictions::S_128F_1M => 1024 * 1024,
+			TagSizeRestrictions::S_64F_128K => 128 * 1024,
+			TagSizeRestrictions::S_32F_40K => 40 * 1024,
+			TagSizeRestrictions::S_32F_4K => 4 * 1024,
+		}
+	}
+}
+
+impl TextSizeRestrictions {
+	/// The maximum number of characters allowed in a string, if any
+	pub fn max_chars(self) -> Option<usize> {
+		match self {
+			TextSizeRestrictions::None => None,
+			TextSizeRestrictions::C_1024 => Some(1024),
+			TextSizeRestrictions::C_128 => Some(128),
+			TextSizeRestrictions::C_30 => Some(30),
+		}
+	}
+}
+
+impl ImageSizeRestrictions {
+	/// Whether an image with the provided dimensions is allowed
+	pub fn allows(self, width: u32, height: u32) -> bool {
+		match self {
+			ImageSizeRestrictions::None => true,
+			ImageSizeRestrictions::P_256 => width <= 256 && height <= 256,
+			ImageSizeRestrictions::P_64 => width <= 64 && height <= 64,
+			ImageSizeRestrictions::P_64_64 => width == 64 && height == 64,
+		}
+	}
+}
+
+/// How to handle a tag that does not satisfy its [`TagRestrictions`]
+///
+/// See [`ID3v2Tag::apply_restrictions`](crate::id3::v2::ID3v2Tag::apply_restrictions)
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum RestrictionMode {
+	/// Error on the first violation, leaving the tag unchanged
+	///
+	/// This is always used when writing a tag.
+	Strict,
+	/// Truncate text, change encodings, and remove frames until the tag satisfies its restrictions
+	Lenient,
+}
+
+/// The kinds of [`RestrictionViolation`]s
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum RestrictionViolationKind {
+	/// The tag has too many frames, see [`TagSizeRestrictions`]
+	FrameCount,
+	/// The tag is too large, see [`TagSizeRestrictions`]
+	TagSize,
+	/// A string is too long, see [`TextSizeRestrictions`]
+	TextSize,
+	/// Text isn't encoded with [`TextEncoding::Latin1`] or [`TextEncoding::UTF8`]
+	TextEncoding,
+	/// An image isn't a `PNG` or `JPEG`
+	ImageEncoding,
+	/// An image's dimensions are not allowed (or could not be determined), see [`ImageSizeRestrictions`]
+	ImageSize,
+}
+
+/// A frame that violates the tag's [`TagRestrictions`]
+///
+/// When applying restrictions with [`RestrictionMode::Lenient`], this also describes the change that was made:
+///
+/// * [`RestrictionViolationKind::TextSize`] - The frame's text was truncated
+/// * [`RestrictionViolationKind::TextEncoding`] - The frame's text was re-encoded with [`TextEncoding::UTF8`]
+/// * Anything else - The frame was removed
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct RestrictionViolation {
+	/// The ID of the offending frame
+	pub frame_id: String,
+	/// The restriction that was violated
+	pub kind: RestrictionViolationKind,
+}
+
+impl TagRestrictions {
+	// Verifies a frame that is about to be written, `index` being its position in the tag
+	pub(super) fn verify_frame(self, index: usize, frame: &FrameRef<'_>) -> Result<()> {
+		let kind = if index >= self.size.max_frames() {
+			Some(RestrictionViolationKind::FrameCount)
+		} else {
+			self.text_violation(&frame.value)
+				.or_else(|| self.image_violation(&frame.value))
+		};
+
+		match kind {
+			Some(kind) => Err(violation(frame.id, kind)),
+			None => Ok(()),
+		}
+	}
+
+	// Verifies the size of a tag, after the frame with the provided ID was written
+	pub(super) fn verify_size(self, size: usize, frame_id: &str) -> Result<()> {
+		if size > self.size.max_size() {
+			return Err(violation(frame_id, RestrictionViolationKind::TagSize));
+		}
+
+		Ok(())
+	}
+
+	// Alters the frames to satisfy the restrictions, reporting every change
+	pub(super) fn apply_lenient(
+		self,
+		frames: &mut Vec<Frame>,
+		flags: ID3v2TagFlags,
+	) -> Result<Vec<RestrictionViolation>> {
+		let mut changes = Vec::new();
+
+		// Outdated frames are never written, so they are left alone
+		frames.retain_mut(|frame| {
+			if !matches!(frame.id, FrameID::Valid(_)) {
+				return true;
+			}
+
+			if self.text_encoding && fix_text_encoding(&mut frame.value) {
+				changes.push(frame_violation(
+					frame,
+					RestrictionViolationKind::TextEncoding,
+				));
+			}
+
+			if let Some(max_chars) = self.text_fields_size.max_chars() {
+				if fix_text_size(&mut frame.value, max_chars) {
+					changes.push(frame_violation(frame, RestrictionViolationKind::TextSize));
+				}
+			}
+
+			match self.image_violation(&frame.value) {
+				Some(kind) => {
+					changes.push(frame_violation(frame, kind));
+					false
+				},
+				None => true,
+			}
+		});
+
+		let mut written = frames
+			.iter()
+			.enumerate()
+			.filter(|(_, frame)| matches!(frame.id, FrameID::Valid(_)))
+			.map(|(i, _)| i)
+			.collect::<Vec<_>>();
+
+		// Drop the most recently added frames first
+		while written.len() > self.size.max_frames() {
+			// Guaranteed to be `Some` at this point
+			let removed = frames.remove(written.pop().unwrap());
+			changes.push(frame_violation(
+				&removed,
+				RestrictionViolationKind::FrameCount,
+			));
+		}
+
+		let mut frame_sizes = Vec::with_capacity(written.len());
+		for i in &written {
+			frame_sizes.push(frame_size(&frames[*i])?);
+		}
+
+		let mut size = tag_overhead(flags) + frame_sizes.iter().sum::<usize>();
+
+		// Drop the largest frames first, since they are usually pictures
+		while size > self.size.max_size() {
+			let (largest, largest_size) =
+				match frame_sizes.iter().enumerate().max_by_key(|(_, s)| **s) {
+					Some((pos, s)) => (pos, *s),
+					None => break,
+				};
+
+			frame_sizes.remove(largest);
+			let removed = frames.remove(written.remove(largest));
+			written.iter_mut().skip(largest).for_each(|i| *i -= 1);
+
+			size -= largest_size;
+			changes.push(frame_violation(&removed, RestrictionViolationKind::TagSize));
+		}
+
+		Ok(changes)
+	}
+
+	fn text_violation(self, value: &FrameValue) -> Option<RestrictionViolationKind> {
+		let (encoding, strings) = text_fields(value)?;
+
+		if self.text_encoding && !matches!(encoding, TextEncoding::Latin1 | TextEncoding::UTF8) {
+			return Some(RestrictionViolationKind::TextEncoding);
+		}
+
+		if let Some(max_chars) = self.text_fields_size.max_chars() {
+			let too_long = strings
+				.iter()
+				.flat_map(|s| s.split('\0'))
+				.any(|s| s.chars().count() > max_chars);
+
+			if too_long {
+				return Some(RestrictionViolationKind::TextSize);
+			}
+		}
+
+		None
+	}
+
+	fn image_violation(self, value: &FrameValue) -> Option<RestrictionViolationKind> {
+		let picture = match value {
+			FrameValue::Picture { picture, .. } => picture,
+			_ => return None,
+		};
+
+		if self.image_encoding && !matches!(picture.mime_type, MimeType::Png | MimeType::Jpeg) {
+			return Some(RestrictionViolationKind::ImageEncoding);
+		}
+
+		if self.image_size != ImageSizeRestrictions::None {
+			// Images with unknown dimensions can't be verified, so they are a violation
+			let allowed = match PictureInformation::from_picture(picture) {
+				Ok(information) if information.width != 0 && information.height != 0 => self
+					.image_size
+					.allows(information.width, information.height),
+				_ => false,
+			};
+
+			if !allowed {
+				return Some(RestrictionViolationKind::ImageSize);
+			}
+		}
+
+		None
+	}
+}
+
+fn violation(frame_id: &str, kind: RestrictionViolationKind) -> LoftyError {
+	ID3v2Error::new(ID3v2ErrorKind::RestrictionViolation(RestrictionViolation {
+		frame_id: frame_id.to_string(),
+		kind,
+	}))
+	.into()
+}
+
+fn frame_violation(frame: &Frame, kind: RestrictionViolationKind) -> RestrictionViolation {
+	RestrictionViolation {
+		frame_id: frame.id_str().to_string(),
+		kind,
+	}
+}
+
+// The encoding and strings of any frame with text fields
+fn text_fields(value: &FrameValue) -> Option<(TextEncoding, Vec<&str>)> {
+	match value {
+		FrameValue::Comment(LanguageFrame {
+			encoding,
+			description,
+			content,
+			..
+		})
+		| FrameValue::UnSyncText(LanguageFrame {
+			encoding,
+			description,
+			content,
+			..
+		})
+		| FrameValue::UserText(EncodedTextFrame {
+			encoding,
+			description,
+			content,
+		}) => Some((*encoding, vec![description, content])),
+		// The content of a `WXXX` frame is a URL, not text
+		FrameValue::UserURL(EncodedTextFrame {
+			encoding,
+			description,
+			..
+		}) => Some((*encoding, vec![description])),
+		FrameValue::Text { encoding, value } => Some((*encoding, vec![value])),
+		FrameValue::KeyValue(KeyValueFrame {
+			encoding,
+			key_value_pairs,
+		}) => Some((
+			*encoding,
+			key_value_pairs
+				.iter()
+				.flat_map(|(key, value)| [key.as_str(), value.as_str()])
+				.collect(),
+		)),
+		FrameValue::SynchronizedText(SynchronizedText {
+			information: SyncTextInformation {
+				encoding,
+				description,
+				..
+			},
+			content,
+		}) => Some((
+			*encoding,
+			description
+				.as_deref()
+				.into_iter()
+				.chain(content.iter().map(|(_, text)| text.as_str()))
+				.collect(),
+		)),
+		// A timestamp is always short enough, only its encoding matters
+		FrameValue::Timestamp(TimestampFrame { encoding, .. }) => Some((*encoding, Vec::new())),
+		FrameValue::Picture { encoding, picture } => Some((
+			*encoding,
+			picture.description.as_deref().into_iter().collect(),
+		)),
+		_ => None,
+	}
+}
+
+fn fix_text_encoding(value: &mut FrameValue) -> bool {
+	let encoding = match value {
+		FrameValue::Comment(LanguageFrame { encoding, .. })
+		| FrameValue::UnSyncText(LanguageFrame { encoding, .. })
+		| FrameValue::UserText(EncodedTextFrame { encoding, .. })
+		| FrameValue::UserURL(EncodedTextFrame { encoding, .. })
+		| FrameValue::Text { encoding, .. }
+		| FrameValue::Timestamp(TimestampFrame { encoding, .. })
+		| FrameValue::KeyValue(KeyValueFrame { encoding, .. })
+		| FrameValue::SynchronizedText(SynchronizedText {
+			information: SyncTextInformation { encoding, .. },
+			..
+		})
+		| FrameValue::Picture { encoding, .. } => encoding,
+		_ => return false,
+	};
+
+	if matches!(encoding, TextEncoding::UTF16 | TextEncoding::UTF16BE) {
+		*encoding = TextEncoding::UTF8;
+		return true;
+	}
+
+	false
+}
+
+fn fix_text_size(value: &mut FrameValue, max_chars: usize) -> bool {
+	let strings: Vec<&mut String> = match value {
+		FrameValue::Comment(LanguageFrame {
+			description,
+			content,
+			..
+		})
+		| FrameValue::UnSyncText(LanguageFrame {
+			description,
+			content,
+			..
+		})
+		| FrameValue::UserText(EncodedTextFrame {
+			description,
+			content,
+			..
+		}) => vec![description, content],
+		FrameValue::UserURL(EncodedTextFrame { description, .. }) => vec![description],
+		FrameValue::Text { value, .. } => vec![value],
+		FrameValue::KeyValue(KeyValueFrame {
+			key_value_pairs, ..
+		}) => key_value_pairs
+			.iter_mut()
+			.flat_map(|(key, value)| [key, value])
+			.collect(),
+		FrameValue::SynchronizedText(SynchronizedText {
+			information: SyncTextInformation { description, .. },
+			content,
+		}) => description
+			.iter_mut()
+			.chain(content.iter_mut().map(|(_, text)| text))
+			.collect(),
+		FrameValue::Picture { picture, .. } => picture
+			.description
+			.as_mut()
+			.map(Cow::to_mut)
+			.into_iter()
+			.collect(),
+		_ => return false,
+	};
+
+	let mut truncated = false;
+	for string in strings {
+		// Multiple values are separated by null terminators, each is its own string
+		if string.split('\0').all(|s| s.chars().count() <= max_chars) {
+			continue;
+		}
+
+		*string = string
+			.split('\0')
+			.map(|s| s.chars().take(max_chars).collect::<String>())
+			.collect::<Vec<_>>()
+			.join("\0");
+
+		truncated = true;
+	}
+
+	truncated
+}
+
+// The size of a frame once written, see `write::frame::write_frame`
+fn frame_size(frame: &Frame) -> Result<usize> {
+	let mut size = 10 + frame.value.as_bytes()?.len();
+
+	if frame.flags.encryption.is_some() {
+		// Method symbol (1) + Data length indicator (4)
+		size += 5;
+	} else if frame.flags.grouping_identity.is_some() {
+		size += 1;
+	}
+
+	Ok(size)
+}
+
+// The size of the tag's header, extended header, and footer
+fn tag_overhead(flags: ID3v2TagFlags) -> usize {
+	// Header (10) + Extended header (6) + Restrictions (2)
+	let mut size = 18;
+
+	if flags.crc {
+		size += 6;
+	}
+
+	if flags.footer {
+		size += 10;
+	}
+
+	size
+}
+
+#[cfg(test)]
+mod tests {
+	use crate::id3::v2::{
+		EncodedTextFrame, Frame, FrameFlags, FrameID, FrameValue, ID3v2Tag, ID3v2TagFlags,
+		ImageSizeRestrictions, RestrictionMode, RestrictionViolation, RestrictionViolationKind,
+		TagRestrictions, TagSizeRestrictions, TextSizeRestrictions,
+	};
+	use crate::tag::utils::test_utils::read_path;
+	use crate::util::text::TextEncoding;
+	use crate::{Accessor, MimeType, Picture, PictureType, TagExt};
+
+	fn tag_with_restrictions(restrictions: TagRestrictions) -> ID3v2Tag {
+		let mut tag = ID3v2Tag::default();
+		tag.set_flags(ID3v2TagFlags {
+			restrictions: Some(restrictions),
+			..ID3v2TagFlags::default()
+		});
+
+		tag
+	}
+
+	fn png_picture() -> Picture {
+		Picture::new_unchecked(
+			PictureType::CoverFront,
+			MimeType::Png,
+			None,
+			read_path("tests/picture/assets/png_640x628.png"),
+		)
+	}
+
+	#[test]
+	fn restrictions_byte_round_trip() {
+		let restrictions = TagRestrictions {
+			size: TagSizeRestrictions::S_32F_4K,
+			text_encoding: true,
+			text_fields_size: TextSizeRestrictions::C_128,
+			image_encoding: true,
+			image_size: ImageSizeRestrictions::P_64,
+		};
+
+		assert_eq!(restrictions.as_bytes(), 0b1111_0110);
+		assert_eq!(
+			TagRestrictions::from_byte(restrictions.as_bytes()),
+			restrictions
+		);
+	}
+
+	#[test]
+	fn strict_frame_count() {
+		let mut tag = tag_with_restrictions(TagRestrictions {
+			size: TagSizeRestrictions::S_32F_4K,
+			..TagRestrictions::default()
+		});
+
+		for i in 0..33 {
+			tag.insert(Frame {
+				id: FrameID::Valid(String::from("TXXX")),
+				value: FrameValue::UserText(EncodedTextFrame {
+					encoding: TextEncoding::UTF8,
+					description: format!("FOO_{}", i),
+					content: String::from("Foo"),
+				}),
+				flags: FrameFlags::default(),
+			});
+		}
+
+		assert!(tag.dump_to(&mut Vec::new()).is_err());
+		assert!(tag.apply_restrictions(RestrictionMode::Strict).is_err());
+
+		let changes = tag.apply_restrictions(RestrictionMode::Lenient).unwrap();
+		assert_eq!(
+			changes,
+			&[RestrictionViolation {
+				frame_id: String::from("TXXX"),
+				kind: RestrictionViolationKind::FrameCount,
+			}]
+		);
+		assert_eq!(tag.len(), 32);
+	}
+
+	#[test]
+	fn lenient_text() {
+		let mut tag = tag_with_restrictions(TagRestrictions {
+			text_encoding: true,
+			text_fields_size: TextSizeRestrictions::C_30,
+			..TagRestrictions::default()
+		});
+
+		tag.insert(Frame {
+			id: FrameID::Valid(String::from("TPE1")),
+			value: FrameValue::Text {
+				encoding: TextEncoding::UTF16,
+				value: String::from(
+					"Foo artist\0An artist with a name longer than thirty characters",
+				),
+			},
+			flags: FrameFlags::default(),
+		});
+
+		let changes = tag.apply_restrictions(RestrictionMode::Lenient).unwrap();
+		assert_eq!(
+			changes,
+			&[
+				RestrictionViolation {
+					frame_id: String::from("TPE1"),
+					kind: RestrictionViolationKind::TextEncoding,
+				},
+				RestrictionViolation {
+					frame_id: String::from("TPE1"),
+					kind: RestrictionViolationKind::TextSize,
+				}
+			]
+		);
+
+		assert_eq!(
+			tag.get("TPE1").unwrap().content(),
+			&FrameValue::Text {
+				encoding: TextEncoding::UTF8,
+				value: String::from("Foo artist\0An artist with a name longer t"),
+			}
+		);
+
+		// The tag is now valid
+		assert!(tag.apply_restrictions(RestrictionMode::Strict).is_ok());
+		assert!(tag.dump_to(&mut Vec::new()).is_ok());
+	}
+
+	#[test]
+	fn lenient_images() {
+		let mut tag = tag_with_restrictions(TagRestrictions {
+			image_encoding: true,
+			image_size: ImageSizeRestrictions::P_256,
+			..TagRestrictions::default()
+		});
+
+		tag.set_title(String::from("Foo title"));
+		tag.insert_picture(png_picture());
+		tag.insert_picture(Picture::new_unchecked(
+			PictureType::CoverBack,
+			MimeType::Bmp,
+			None,
+			read_path("tests/picture/assets/bmp_640x628.bmp"),
+		));
+
+		assert!(tag.dump_to(&mut Vec::new()).is_err());
+
+		let changes = tag.apply_restrictions(RestrictionMode::Lenient).unwrap();
+		assert_eq!(
+			changes,
+			&[
+				RestrictionViolation {
+					frame_id: String::from("APIC"),
+					kind: RestrictionViolationKind::ImageSize,
+				},
+				RestrictionViolation {
+					frame_id: String::from("APIC"),
+					kind: RestrictionViolationKind::ImageEncoding,
+				}
+			]
+		);
+
+		assert_eq!(tag.len(), 1);
+		assert_eq!(tag.title(), Some("Foo title"));
+	}
+
+	#[test]
+	fn unknown_image_size() {
+		let mut tag = tag_with_restrictions(TagRestrictions {
+			image_size: ImageSizeRestrictions::P_256,
+			..TagRestrictions::default()
+		});
+
+		// Not a valid PNG, so the dimensions can't be determined
+		tag.insert_picture(Picture::new_unchecked(
+			PictureType::CoverFront,
+			MimeType::Png,
+			None,
+			vec![1, 2, 3],
+		));
+
+		assert!(tag.dump_to(&mut Vec::new()).is_err());
+
+		let changes = tag.apply_restrictions(RestrictionMode::Lenient).unwrap();
+		assert_eq!(
+			changes,
+			&[RestrictionViolation {
+				frame_id: String::from("APIC"),
+				kind: RestrictionViolationKind::ImageSize,
+			}]
+		);
+		assert!(tag.is_empty());
+	}
+
+	#[test]
+	fn lenient_tag_size() {
+		let mut tag = tag_with_restrictions(TagRestrictions {
+			size: TagSizeRestrictions::S_32F_4K,
+			..TagRestrictions::default()
+		});
+
+		tag.set_title(String::from("Foo title"));
+		tag.insert_picture(png_picture());
+		tag.set_artist(String::from("Bar artist"));
+
+		let res = tag.dump_to(&mut Vec::new());
+		assert_eq!(
+			res.unwrap_err().to_string(),
+			"ID3v2: Frame \"APIC\" violates the tag's restrictions (TagSize)"
+		);
+
+		let changes = tag.apply_restrictions(RestrictionMode::Lenient).unwrap();
+		assert_eq!(
+			changes,
+			&[RestrictionViolation {
+				frame_id: String::from("APIC"),
+				kind: RestrictionViolationKind::TagSize,
+			}]
+		);
+
+		assert_eq!(tag.len(), 2);
+		assert!(tag.dump_to(&mut Vec::new()).is_ok());
+	}
+}
@@ -1,13 +1,24 @@
 use super::flags::ID3v2TagFlags;
 use super::frame::id::FrameID;
 use super::frame::{Frame, FrameFlags, FrameValue};
-use super::ID3v2Version;
+use super::util::genres;
+use super::{ID3v2TagLocation, ID3v2Version};
+#[cfg(feature = "id3v2_restrictions")]
+use super::{RestrictionMode, RestrictionViolation};
+use crate::diff::Change;
 use crate::error::{LoftyError, Result};
-use crate::id3::v2::frame::FrameRef;
+use crate::id3::v2::frame::{FrameRef, MUSICBRAINZ_UFID_OWNER};
 use crate::id3::v2::items::encoded_text_frame::EncodedTextFrame;
+use crate::id3::v2::items::key_value_frame::KeyValueFrame;
 use crate::id3::v2::items::language_frame::LanguageFrame;
+use crate::id3::v2::items::popularimeter::Popularimeter;
+use crate::id3::v2::items::timestamp_frame::TimestampFrame;
 use crate::picture::{Picture, PictureType};
 use crate::tag::item::{ItemKey, ItemValue, TagItem};
+use crate::tag::passthrough::{NativeElements, Passthrough};
+use crate::tag::rating::Rating;
+use crate::tag::timestamp::{year_from_date, Timestamp};
+use crate::tag::utils;
 use crate::tag::{Tag, TagType};
 use crate::traits::{Accessor, TagExt};
 use crate::util::text::TextEncoding;
@@ -56,10 +67,53 @@ macro_rules! impl_accessor {
 				}
 			)+
 		}
-	}
+	};
+	(@TYPED $($name:ident<$ty:ty> => $id:literal, $parse:path, $format:path;)+) => {
+		paste::paste! {
+			$(
+				fn $name(&self) -> Option<$ty> {
+					if let Some(Frame {
+						value: FrameValue::Text { value, .. },
+						..
+					}) = self.get($id)
+					{
+						return $parse(value);
+					}
+
+					None
+				}
+
+				fn [<set_ $name>](&mut self, value: $ty) {
+					self.insert(Frame::text($id, $format(value)));
+				}
+
+				fn [<remove_ $name>](&mut self) {
+					self.remove($id)
+				}
+			)+
+		}
+	};
+	(@USER_TEXT $($name:ident<$ty:ty> => $description:literal, $parse:path, $format:path;)+) => {
+		paste::paste! {
+			$(
+				fn $name(&self) -> Option<$ty> {
+					self.user_text($description).and_then($parse)
+				}
+
+				fn [<set_ $name>](&mut self, value: $ty) {
+					self.set_user_text($description, $format(value))
+				}
+
+				fn [<remove_ $name>](&mut self) {
+					self.remove_user_text($description)
+				}
+			)+
+		}
+	};
 }
 
 #[derive(PartialEq, Eq, Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 /// An `ID3v2` tag
 ///
 /// ## Supported file types
@@ -83,23 +137,36 @@ macro_rules! impl_accessor {
 ///    * `description` - Left empty, which is invalid if there are more than one of these frames. These frames can only be identified
 ///    by their descriptions, and as such they are expected to be unique for each.
 /// * [`ItemKey::Unknown("WXXX" | "TXXX")`](crate::ItemKey::Unknown) - These frames are also identified by their descriptions.
+/// * Items that map to a description rather than a frame ID (ReplayGain, MusicBrainz IDs, etc.) - Stored in `TXXX` frames, or `WXXX` frames for [`ItemValue::Locator`](crate::ItemValue::Locator)s.
+/// * [`ItemKey::MusicBrainzRecordingId`](crate::ItemKey::MusicBrainzRecordingId) - Stored in a `UFID` frame, with the owner `http://musicbrainz.org`.
+/// * Credits - These are collected into a single `TIPL` and `TMCL` frame, see [`KeyValueFrame`](crate::id3::v2::KeyValueFrame).
+///    * `TIPL` - [`ItemKey::Arranger`](crate::ItemKey::Arranger), [`ItemKey::Engineer`](crate::ItemKey::Engineer), [`ItemKey::MixDj`](crate::ItemKey::MixDj), [`ItemKey::MixEngineer`](crate::ItemKey::MixEngineer), [`ItemKey::Producer`](crate::ItemKey::Producer), and [`ItemKey::InvolvedPeople`](crate::ItemKey::InvolvedPeople)
+///    * `TMCL` - [`ItemKey::Performer`](crate::ItemKey::Performer) and [`ItemKey::MusicianCredits`](crate::ItemKey::MusicianCredits)
+///    * Any item with a role is expected to be formatted as "name (role)", the same as Vorbis Comments' `PERFORMER`.
+/// * [`ItemKey::Popularimeter`](crate::ItemKey::Popularimeter) - Expected to be a rating out of 100, stored in a `POPM` frame with the Windows Media Player email. See [`Rating`](crate::Rating) for the mapping.
 ///
 /// ### To `Tag`
 ///
 /// * TXXX/WXXX - These frames will be stored as an [`ItemKey`] by their description. Some variants exist for these descriptions, such as the one for `ReplayGain`,
 /// otherwise [`ItemKey::Unknown`] will be used.
 /// * Any [`LanguageFrame`] - With ID3v2 being the only format that allows for language-specific items, this information is not retained. These frames **will** be discarded.
+/// * TIPL/TMCL - Each pair becomes its own item, with the roles above mapping to their [`ItemKey`]s. Everything else is stored as "name (role)".
+/// * UFID - Only frames owned by MusicBrainz are converted to [`ItemKey::MusicBrainzRecordingId`], any others are kept as [`ItemValue::Binary`](crate::ItemValue::Binary).
+/// * POPM - The first frame with a known rating is converted to [`ItemKey::Popularimeter`] as a rating out of 100 (see [`Rating`](crate::Rating)). The email and play counter are not retained.
 ///
 /// ## Special Frames
 ///
-/// ID3v2 has `GEOB` and `SYLT` frames, which are not parsed by default, instead storing them as [`FrameValue::Binary`].
-/// They can easily be parsed with [`GeneralEncapsulatedObject::parse`](crate::id3::v2::GeneralEncapsulatedObject::parse)
-/// and [`SynchronizedText::parse`](crate::id3::v2::SynchronizedText::parse) respectively, and converted back to binary with
-/// [`GeneralEncapsulatedObject::as_bytes`](crate::id3::v2::GeneralEncapsulatedObject::as_bytes) and
-/// [`SynchronizedText::as_bytes`](crate::id3::v2::SynchronizedText::as_bytes) for writing.
+/// ID3v2 has `GEOB` frames, which are not parsed by default, instead storing them as [`FrameValue::Binary`].
+/// They can easily be parsed with [`GeneralEncapsulatedObject::parse`](crate::id3::v2::GeneralEncapsulatedObject::parse),
+/// and converted back to binary with [`GeneralEncapsulatedObject::as_bytes`](crate::id3::v2::GeneralEncapsulatedObject::as_bytes) for writing.
+///
+/// `SYLT`, `ETCO`, and `SYTC` frames are parsed into [`FrameValue::SynchronizedText`], [`FrameValue::EventTimingCodes`],
+/// and [`FrameValue::SynchronizedTempo`] respectively. With no equivalent in [`Tag`](crate::Tag), they are stored there as
+/// [`ItemValue::Binary`](crate::ItemValue::Binary).
 pub struct ID3v2Tag {
 	flags: ID3v2TagFlags,
 	pub(super) original_version: ID3v2Version,
+	pub(crate) location: ID3v2TagLocation,
 	frames: Vec<Frame>,
 }
 
@@ -117,6 +184,7 @@ impl Default for ID3v2Tag {
 		Self {
 			flags: ID3v2TagFlags::default(),
 			original_version: ID3v2Version::V4,
+			location: ID3v2TagLocation::Prepended,
 			frames: Vec::new(),
 		}
 	}
@@ -140,6 +208,74 @@ impl ID3v2Tag {
 	pub fn original_version(&self) -> ID3v2Version {
 		self.original_version
 	}
+
+	/// Makes the tag satisfy its [`TagRestrictions`](crate::id3::v2::TagRestrictions), if any
+	///
+	/// Tags with restrictions are always verified when written, and writing will fail if any are violated.
+	///
+	/// * [`RestrictionMode::Strict`] - Only verifies the tag
+	/// * [`RestrictionMode::Lenient`] - Truncates text, changes encodings, and removes frames as needed
+	///
+	/// The returned [`RestrictionViolation`]s describe every change made to the tag.
+	///
+	/// # Errors
+	///
+	/// * [`RestrictionMode::Strict`] - The tag violates any of its restrictions
+	/// * A frame could not be encoded to determine its size
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use lofty::id3::v2::{
+	/// 	ID3v2Tag, ID3v2TagFlags, RestrictionMode, RestrictionViolationKind, TagRestrictions,
+	/// 	TextSizeRestrictions,
+	/// };
+	/// use lofty::Accessor;
+	///
+	/// # fn main() -> lofty::Result<()> {
+	/// let mut tag = ID3v2Tag::default();
+	/// tag.set_title(String::from("A title that is much too long to fit"));
+	///
+	/// tag.set_flags(ID3v2TagFlags {
+	/// 	restrictions: Some(TagRestrictions {
+	/// 		text_fields_size: TextSizeRestrictions::C_30,
+	/// 		..TagRestrictions::default()
+	/// 	}),
+	/// 	..ID3v2TagFlags::default()
+	/// });
+	///
+	/// assert!(tag.apply_restrictions(RestrictionMode::Strict).is_err());
+	///
+	/// let changes = tag.apply_restrictions(RestrictionMode::Lenient)?;
+	/// assert_eq!(changes[0].kind, RestrictionViolationKind::TextSize);
+	/// assert_eq!(tag.title(), Some("A title that is much too long "));
+	/// # Ok(()) }
+	/// ```
+	#[cfg(feature = "id3v2_restrictions")]
+	pub fn apply_restrictions(
+		&mut self,
+		mode: RestrictionMode,
+	) -> Result<Vec<RestrictionViolation>> {
+		let restrictions = match self.flags.restrictions {
+			Some(restrictions) => restrictions,
+			None => return Ok(Vec::new()),
+		};
+
+		match mode {
+			RestrictionMode::Strict => {
+				self.dump_to(&mut std::io::sink())?;
+				Ok(Vec::new())
+			},
+			RestrictionMode::Lenient => restrictions.apply_lenient(&mut self.frames, self.flags),
+		}
+	}
+
+	/// Where the tag was located in the file
+	///
+	/// See [`ID3v2TagLocation`] for details.
+	pub fn location(&self) -> ID3v2TagLocation {
+		self.location
+	}
 }
 
 impl ID3v2Tag {
@@ -181,6 +317,70 @@ impl ID3v2Tag {
 		self.frames.retain(|f| f.id_str() != id)
 	}
 
+	/// Compare the tag to another, frame by frame
+	///
+	/// Frames are matched the same way as in [`ID3v2Tag::insert`], by their IDs, and their
+	/// descriptions, languages, etc. for frames that can appear multiple times. A matched frame is
+	/// considered [`Modified`](Change::Modified) if its content, encoding, or flags differ.
+	///
+	/// To compare tags of different formats, see [`Tag::diff`].
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use lofty::diff::Change;
+	/// use lofty::id3::v2::ID3v2Tag;
+	/// use lofty::Accessor;
+	///
+	/// let mut old = ID3v2Tag::default();
+	/// old.set_title(String::from("Foo title"));
+	///
+	/// let mut new = old.clone();
+	/// new.set_title(String::from("Bar title"));
+	///
+	/// let diff = old.diff(&new);
+	/// assert_eq!(diff.len(), 1);
+	/// assert!(matches!(diff[0], Change::Modified { old, new } if old.id_str() == "TIT2"));
+	/// ```
+	pub fn diff<'a>(&'a self, other: &'a ID3v2Tag) -> Vec<Change<&'a Frame>> {
+		fn identical(a: &Frame, b: &Frame) -> bool {
+			a.flags == b.flags
+				&& match (a.value.as_bytes(), b.value.as_bytes()) {
+					(Ok(a), Ok(b)) => a == b,
+					_ => a.value == b.value,
+				}
+		}
+
+		let mut changes = Vec::new();
+		let mut matched = vec![false; other.frames.len()];
+
+		for old in &self.frames {
+			let new = other
+				.frames
+				.iter()
+				.enumerate()
+				.find(|(i, new)| !matched[*i] && *new == old);
+
+			match new {
+				Some((i, new)) => {
+					matched[i] = true;
+					if !identical(old, new) {
+						changes.push(Change::Modified { old, new });
+					}
+				},
+				None => changes.push(Change::Removed(old)),
+			}
+		}
+
+		for (new, matched) in other.frames.iter().zip(matched) {
+			if !matched {
+				changes.push(Change::Added(new));
+			}
+		}
+
+		changes
+	}
+
 	/// Inserts a [`Picture`]
 	///
 	/// According to spec, there can only be one picture of type [`PictureType::Icon`] and [`PictureType::OtherIcon`].
@@ -268,6 +468,153 @@ impl ID3v2Tag {
 		})
 	}
 
+	/// Returns the genres in the `TCON` frame
+	///
+	/// References to ID3v1 genres, in both the ID3v2.4 (`"17"`) and ID3v2.3 (`"(17)Rock"`) forms, as well as
+	/// the `RX` (Remix) and `CR` (Cover) codes, are resolved into genre names. See [`GENRES`](crate::id3::v1::GENRES).
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use lofty::id3::v2::ID3v2Tag;
+	/// use lofty::Accessor;
+	///
+	/// let mut tag = ID3v2Tag::default();
+	/// tag.set_genre(String::from("(4)(RX)Eurodisco"));
+	///
+	/// assert_eq!(tag.genres().collect::<Vec<_>>(), vec!["Disco", "Remix", "Eurodisco"]);
+	/// assert_eq!(tag.genre(), Some("Disco"));
+	/// ```
+	pub fn genres(&self) -> impl Iterator<Item = &str> {
+		let genres = match self.get("TCON").map(Frame::content) {
+			Some(FrameValue::Text { value, .. }) => genres::parse_genres(value),
+			_ => Vec::new(),
+		};
+
+		genres.into_iter()
+	}
+
+	/// Rewrites the `TCON` frame in the ID3v2.4 form
+	///
+	/// Each genre is stored as a separate (null separated) value, with ID3v1 genres replaced by their
+	/// numbers, and "Remix" and "Cover" replaced by `RX` and `CR`. Any other genres are left as-is.
+	///
+	/// This is not done automatically, as not all applications are able to resolve genre numbers.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use lofty::id3::v2::{FrameValue, ID3v2Tag};
+	/// use lofty::Accessor;
+	///
+	/// let mut tag = ID3v2Tag::default();
+	/// tag.set_genre(String::from("(17)(RX)Rock"));
+	/// tag.normalize_genres();
+	///
+	/// assert!(matches!(
+	/// 	tag.get("TCON").map(|frame| frame.content()),
+	/// 	Some(FrameValue::Text { value, .. }) if value == "17\0RX"
+	/// ));
+	/// ```
+	pub fn normalize_genres(&mut self) {
+		let tcon = self.frames.iter_mut().find(|f| f.id_str() == "TCON");
+		if let Some(Frame {
+			value: FrameValue::Text { value, .. },
+			..
+		}) = tcon
+		{
+			*value = genres::parse_genres(value)
+				.into_iter()
+				.map(genres::normalize_genre)
+				.collect::<Vec<_>>()
+				.join("\0");
+		}
+	}
+
+	/// Returns the involved people list (`TIPL`) as (role, name) pairs
+	pub fn involved_people(&self) -> Option<&[(String, String)]> {
+		self.key_value_pairs("TIPL")
+	}
+
+	/// Sets the involved people list (`TIPL`) from (role, name) pairs
+	///
+	/// An empty list will remove the frame.
+	pub fn set_involved_people(&mut self, people: Vec<(String, String)>) {
+		self.set_key_value_pairs("TIPL", people)
+	}
+
+	/// Returns the musician credits list (`TMCL`) as (instrument, name) pairs
+	pub fn musician_credits(&self) -> Option<&[(String, String)]> {
+		self.key_value_pairs("TMCL")
+	}
+
+	/// Sets the musician credits list (`TMCL`) from (instrument, name) pairs
+	///
+	/// An empty list will remove the frame.
+	pub fn set_musician_credits(&mut self, credits: Vec<(String, String)>) {
+		self.set_key_value_pairs("TMCL", credits)
+	}
+
+	fn key_value_pairs(&self, id: &str) -> Option<&[(String, String)]> {
+		match self.get(id).map(Frame::content) {
+			Some(FrameValue::KeyValue(KeyValueFrame {
+				key_value_pairs, ..
+			})) => Some(key_value_pairs),
+			_ => None,
+		}
+	}
+
+	fn set_key_value_pairs(&mut self, id: &str, key_value_pairs: Vec<(String, String)>) {
+		if key_value_pairs.is_empty() {
+			self.remove(id);
+			return;
+		}
+
+		self.insert(Frame {
+			id: FrameID::Valid(String::from(id)),
+			value: FrameValue::KeyValue(KeyValueFrame {
+				encoding: TextEncoding::UTF8,
+				key_value_pairs,
+			}),
+			flags: FrameFlags::default(),
+		});
+	}
+
+	// `TXXX` frames are identified by their descriptions, which are compared case-insensitively
+	fn user_text(&self, description: &str) -> Option<&str> {
+		self.frames.iter().find_map(|frame| match frame.content() {
+			FrameValue::UserText(EncodedTextFrame {
+				description: desc,
+				content,
+				..
+			}) if desc.eq_ignore_ascii_case(description) => Some(content.as_str()),
+			_ => None,
+		})
+	}
+
+	fn set_user_text(&mut self, description: &str, content: String) {
+		self.remove_user_text(description);
+		self.frames.push(Frame {
+			id: FrameID::Valid(String::from("TXXX")),
+			value: FrameValue::UserText(EncodedTextFrame {
+				encoding: TextEncoding::UTF8,
+				description: String::from(description),
+				content,
+			}),
+			flags: FrameFlags::default(),
+		});
+	}
+
+	fn remove_user_text(&mut self, description: &str) {
+		self.frames.retain(|frame| {
+			!matches!(
+				frame.content(),
+				FrameValue::UserText(EncodedTextFrame { description: desc, .. })
+					if desc.eq_ignore_ascii_case(description)
+			)
+		})
+	}
+
 	fn split_num_pair(&self, id: &str) -> (Option<u32>, Option<u32>) {
 		if let Some(Frame {
 			value: FrameValue::Text { ref value, .. },
@@ -284,12 +631,51 @@ impl ID3v2Tag {
 
 impl Accessor for ID3v2Tag {
 	impl_accessor!(
-		title  => "TIT2";
-		artist => "TPE1";
-		album  => "TALB";
-		genre  => "TCON";
+		title                   => "TIT2";
+		artist                  => "TPE1";
+		album                   => "TALB";
+		album_artist            => "TPE2";
+		composer                => "TCOM";
+		title_sort_order        => "TSOT";
+		artist_sort_order       => "TSOP";
+		album_sort_order        => "TSOA";
+		album_artist_sort_order => "TSO2";
+		composer_sort_order     => "TSOC";
+		isrc                    => "TSRC";
+		label                   => "TPUB";
+	);
+
+	impl_accessor!(
+		@TYPED
+		bpm<u32>          => "TBPM", utils::parse_number, utils::format_number;
+		compilation<bool> => "TCMP", utils::parse_flag, utils::format_flag;
 	);
 
+	impl_accessor!(
+		@USER_TEXT
+		replay_gain_track_gain<f32> => "REPLAYGAIN_TRACK_GAIN", utils::parse_replay_gain, utils::format_replay_gain;
+		replay_gain_track_peak<f32> => "REPLAYGAIN_TRACK_PEAK", utils::parse_replay_gain_peak, utils::format_replay_gain_peak;
+		replay_gain_album_gain<f32> => "REPLAYGAIN_ALBUM_GAIN", utils::parse_replay_gain, utils::format_replay_gain;
+		replay_gain_album_peak<f32> => "REPLAYGAIN_ALBUM_PEAK", utils::parse_replay_gain_peak, utils::format_replay_gain_peak;
+	);
+
+	fn catalog_number(&self) -> Option<&str> {
+		self.user_text("CATALOGNUMBER")
+	}
+
+	fn set_catalog_number(&mut self, value: String) {
+		if value.is_empty() {
+			self.remove_user_text("CATALOGNUMBER");
+			return;
+		}
+
+		self.set_user_text("CATALOGNUMBER", value)
+	}
+
+	fn remove_catalog_number(&mut self) {
+		self.remove_user_text("CATALOGNUMBER")
+	}
+
 	fn track(&self) -> Option<u32> {
 		self.split_num_pair("TRCK").0
 	}
@@ -353,30 +739,82 @@ impl Accessor for ID3v2Tag {
 	}
 
 	fn year(&self) -> Option<u32> {
-		if let Some(Frame {
-			value: FrameValue::Text { value, .. },
-			..
-		}) = self.get("TDRC")
-		{
-			return value
-				.chars()
-				.take(4)
-				.collect::<String>()
-				.parse::<u32>()
-				.ok();
+		match self.get("TDRC").map(Frame::content) {
+			Some(FrameValue::Timestamp(TimestampFrame { timestamp, .. })) => {
+				Some(u32::from(timestamp.year))
+			},
+			Some(FrameValue::Text { value, .. }) => year_from_date(value),
+			_ => None,
 		}
-
-		None
 	}
 
 	fn set_year(&mut self, value: u32) {
-		self.insert(Frame::text("TDRC", value.to_string()));
+		self.insert(Frame {
+			id: FrameID::Valid(String::from("TDRC")),
+			value: FrameValue::timestamp_or_text(value.to_string()),
+			flags: FrameFlags::default(),
+		});
 	}
 
 	fn remove_year(&mut self) {
 		self.remove("TDRC");
 	}
 
+	fn date(&self) -> Option<Timestamp> {
+		match self.get("TDRC").map(Frame::content) {
+			Some(FrameValue::Timestamp(TimestampFrame { timestamp, .. })) => Some(*timestamp),
+			Some(FrameValue::Text { value, .. }) => utils::parse_date(value),
+			_ => None,
+		}
+	}
+
+	fn set_date(&mut self, value: Timestamp) {
+		self.insert(Frame {
+			id: FrameID::Valid(String::from("TDRC")),
+			value: FrameValue::Timestamp(TimestampFrame {
+				encoding: TextEncoding::Latin1,
+				timestamp: value,
+			}),
+			flags: FrameFlags::default(),
+		});
+	}
+
+	fn remove_date(&mut self) {
+		self.remove("TDRC");
+	}
+
+	fn rating(&self) -> Option<Rating> {
+		self.frames.iter().find_map(|frame| match frame.content() {
+			FrameValue::Popularimeter(popularimeter) => {
+				Rating::from_popularimeter(popularimeter.rating)
+			},
+			_ => None,
+		})
+	}
+
+	fn set_rating(&mut self, value: Rating) {
+		// We'll just replace the first rating if it exists, keeping its email and play counter
+		let first_popularimeter = self.frames.iter_mut().find(|f| f.id_str() == "POPM");
+		if let Some(Frame {
+			value: FrameValue::Popularimeter(popularimeter),
+			..
+		}) = first_popularimeter
+		{
+			popularimeter.rating = value.as_popularimeter();
+			return;
+		}
+
+		self.insert(Frame {
+			id: FrameID::Valid(String::from("POPM")),
+			value: FrameValue::Popularimeter(Popularimeter::from_rating(value)),
+			flags: FrameFlags::default(),
+		});
+	}
+
+	fn remove_rating(&mut self) {
+		self.remove("POPM");
+	}
+
 	fn comment(&self) -> Option<&str> {
 		if let Some(Frame {
 			value: FrameValue::Comment(LanguageFrame { content, .. }),
@@ -418,6 +856,59 @@ impl Accessor for ID3v2Tag {
 	fn remove_comment(&mut self) {
 		self.remove("COMM");
 	}
+
+	fn genre(&self) -> Option<&str> {
+		self.genres().next()
+	}
+
+	fn set_genre(&mut self, value: String) {
+		if value.is_empty() {
+			self.remove("TCON");
+			return;
+		}
+
+		self.insert(Frame::text("TCON", value));
+	}
+
+	fn remove_genre(&mut self) {
+		self.remove("TCON");
+	}
+
+	fn lyrics(&self) -> Option<&str> {
+		self.unsync_text()
+			.next()
+			.map(|lyrics| lyrics.content.as_str())
+	}
+
+	fn set_lyrics(&mut self, value: String) {
+		// Same as comments, only the first `USLT` frame is replaced
+		let first_lyrics = self.frames.iter_mut().find(|f| f.id_str() == "USLT");
+		if let Some(Frame {
+			value: FrameValue::UnSyncText(LanguageFrame { content, .. }),
+			..
+		}) = first_lyrics
+		{
+			*content = value;
+			return;
+		}
+
+		if !value.is_empty() {
+			self.insert(Frame {
+				id: FrameID::Valid(String::from("USLT")),
+				value: FrameValue::UnSyncText(LanguageFrame {
+					encoding: TextEncoding::UTF8,
+					language: *b"eng",
+					description: String::new(),
+					content: value,
+				}),
+				flags: FrameFlags::default(),
+			});
+		}
+	}
+
+	fn remove_lyrics(&mut self) {
+		self.remove("USLT");
+	}
 }
 
 impl TagExt for ID3v2Tag {
@@ -474,122 +965,206 @@ impl TagExt for ID3v2Tag {
 }
 
 impl From<ID3v2Tag> for Tag {
-	fn from(input: ID3v2Tag) -> Self {
-		fn split_pair(
-			content: &str,
-			tag: &mut Tag,
-			current_key: ItemKey,
-			total_key: ItemKey,
-		) -> Option<()> {
-			let mut split = content.splitn(2, &['\0', '/'][..]);
-			let current = split.next()?.to_string();
-			tag.items
-				.push(TagItem::new(current_key, ItemValue::Text(current)));
+	fn from(mut input: ID3v2Tag) -> Self {
+		let mut tag = Self::new(TagType::ID3v2);
 
-			if let Some(total) = split.next() {
-				tag.items
-					.push(TagItem::new(total_key, ItemValue::Text(total.to_string())))
+		// The original frames are kept, see `Tag#native-data`
+		let mut frames = NativeElements::new();
+		for frame in std::mem::take(&mut input.frames) {
+			// Pictures are already kept in the tag
+			if let FrameValue::Picture { .. } = frame.content() {
+				convert_frame(frame, &mut tag);
+				continue;
 			}
 
-			Some(())
+			let items_start = tag.items.len();
+			convert_frame(frame.clone(), &mut tag);
+			frames.record(frame, &tag, items_start);
 		}
 
-		let mut tag = Self::new(TagType::ID3v2);
+		tag.passthrough = Some(Passthrough::ID3v2 {
+			frames: frames.finish(&tag),
+			tag: input,
+		});
 
-		for frame in input.frames {
-			let id = frame.id;
-
-			// The text pairs need some special treatment
-			match (id.as_str(), frame.value) {
-				("TRCK", FrameValue::Text { value: content, .. })
-					if split_pair(
-						&content,
-						&mut tag,
-						ItemKey::TrackNumber,
-						ItemKey::TrackTotal,
-					)
-					.is_some() =>
-				{
-					continue
-				},
-				("TPOS", FrameValue::Text { value: content, .. })
-					if split_pair(&content, &mut tag, ItemKey::DiscNumber, ItemKey::DiscTotal)
-						.is_some() =>
+		tag
+	}
+}
+
+fn convert_frame(frame: Frame, tag: &mut Tag) {
+	fn split_pair(
+		content: &str,
+		tag: &mut Tag,
+		current_key: ItemKey,
+		total_key: ItemKey,
+	) -> Option<()> {
+		let mut split = content.splitn(2, &['\0', '/'][..]);
+		let current = split.next()?.to_string();
+		tag.items
+			.push(TagItem::new(current_key, ItemValue::Text(current)));
+
+		if let Some(total) = split.next() {
+			tag.items
+				.push(TagItem::new(total_key, ItemValue::Text(total.to_string())))
+		}
+
+		Some(())
+	}
+
+	let id = frame.id;
+
+	// The text pairs need some special treatment
+	match (id.as_str(), frame.value) {
+		("TRCK", FrameValue::Text { value: content, .. })
+			if split_pair(&content, tag, ItemKey::TrackNumber, ItemKey::TrackTotal).is_some() => {},
+		("TPOS", FrameValue::Text { value: content, .. })
+			if split_pair(&content, tag, ItemKey::DiscNumber, ItemKey::DiscTotal).is_some() => {},
+		// Genres are resolved into their names, see `ID3v2Tag::genres`
+		("TCON", FrameValue::Text { value: content, .. }) => {
+			for genre in genres::parse_genres(&content) {
+				tag.items.push(TagItem::new(
+					ItemKey::Genre,
+					ItemValue::Text(genre.to_string()),
+				));
+			}
+		},
+		(
+			"TIPL",
+			FrameValue::KeyValue(KeyValueFrame {
+				key_value_pairs, ..
+			}),
+		) => {
+			for (role, name) in key_value_pairs {
+				let item = match INVOLVED_PEOPLE_ROLES
+					.iter()
+					.find(|(r, _)| r.eq_ignore_ascii_case(&role))
 				{
-					continue
-				},
-				// Store TXXX/WXXX frames by their descriptions, rather than their IDs
-				(
-					"TXXX",
-					FrameValue::UserText(EncodedTextFrame {
-						ref description,
-						ref content,
-						..
-					}),
-				) => {
-					let item_key = ItemKey::from_key(TagType::ID3v2, description);
+					Some((_, item_key)) => TagItem::new(item_key.clone(), ItemValue::Text(name)),
+					None => TagItem::new(
+						ItemKey::InvolvedPeople,
+						ItemValue::Text(format_credit(&role, &name)),
+					),
+				};
+
+				tag.items.push(item);
+			}
+		},
+		(
+			"TMCL",
+			FrameValue::KeyValue(KeyValueFrame {
+				key_value_pairs, ..
+			}),
+		) => {
+			for (instrument, name) in key_value_pairs {
+				tag.items.push(TagItem::new(
+					ItemKey::Performer,
+					ItemValue::Text(format_credit(&instrument, &name)),
+				));
+			}
+		},
+		// MusicBrainz recording IDs are the only UFID frames with an `ItemKey`
+		("UFID", FrameValue::Binary(content))
+			if content.starts_with(MUSICBRAINZ_UFID_OWNER)
+				&& content.get(MUSICBRAINZ_UFID_OWNER.len()) == Some(&0) =>
+		{
+			let recording_id = &content[MUSICBRAINZ_UFID_OWNER.len() + 1..];
+			tag.items.push(TagItem::new(
+				ItemKey::MusicBrainzRecordingId,
+				ItemValue::Text(String::from_utf8_lossy(recording_id).into_owned()),
+			));
+		},
+		("UFID", FrameValue::Binary(content)) => {
+			tag.items.push(TagItem::new(
+				ItemKey::Unknown(String::from("UFID")),
+				ItemValue::Binary(content),
+			));
+		},
+		// Store TXXX/WXXX frames by their descriptions, rather than their IDs
+		(
+			"TXXX",
+			FrameValue::UserText(EncodedTextFrame {
+				ref description,
+				ref content,
+				..
+			}),
+		) => {
+			let item_key = ItemKey::from_key(TagType::ID3v2, description);
+			for c in content.split(&['\0', '/'][..]) {
+				tag.items.push(TagItem::new(
+					item_key.clone(),
+					ItemValue::Text(c.to_string()),
+				));
+			}
+		},
+		(
+			"WXXX",
+			FrameValue::UserURL(EncodedTextFrame {
+				ref description,
+				ref content,
+				..
+			}),
+		) => {
+			let item_key = ItemKey::from_key(TagType::ID3v2, description);
+			for c in content.split(&['\0', '/'][..]) {
+				tag.items.push(TagItem::new(
+					item_key.clone(),
+					ItemValue::Locator(c.to_string()),
+				));
+			}
+		},
+		(id, value) => {
+			let item_key = ItemKey::from_key(TagType::ID3v2, id);
+
+			let item_value = match value {
+				FrameValue::Comment(LanguageFrame { content, .. })
+				| FrameValue::UnSyncText(LanguageFrame { content, .. })
+				| FrameValue::Text { value: content, .. }
+				| FrameValue::UserText(EncodedTextFrame { content, .. }) => {
 					for c in content.split(&['\0', '/'][..]) {
 						tag.items.push(TagItem::new(
 							item_key.clone(),
 							ItemValue::Text(c.to_string()),
 						));
 					}
+
+					return;
 				},
-				(
-					"WXXX",
-					FrameValue::UserURL(EncodedTextFrame {
-						ref description,
-						ref content,
-						..
-					}),
-				) => {
-					let item_key = ItemKey::from_key(TagType::ID3v2, description);
-					for c in content.split(&['\0', '/'][..]) {
-						tag.items.push(TagItem::new(
-							item_key.clone(),
-							ItemValue::Locator(c.to_string()),
-						));
-					}
+				FrameValue::URL(content)
+				| FrameValue::UserURL(EncodedTextFrame { content, .. }) => ItemValue::Locator(content),
+				FrameValue::Picture { picture, .. } => {
+					tag.push_picture(picture);
+					return;
 				},
-				(id, value) => {
-					let item_key = ItemKey::from_key(TagType::ID3v2, id);
-
-					let item_value = match value {
-						FrameValue::Comment(LanguageFrame { content, .. })
-						| FrameValue::UnSyncText(LanguageFrame { content, .. })
-						| FrameValue::Text { value: content, .. }
-						| FrameValue::UserText(EncodedTextFrame { content, .. }) => {
-							for c in content.split(&['\0', '/'][..]) {
-								tag.items.push(TagItem::new(
-									item_key.clone(),
-									ItemValue::Text(c.to_string()),
-								));
-							}
-
-							continue;
+				// Only the first rating is kept, see `Rating` for the mapping
+				FrameValue::Popularimeter(popularimeter) => {
+					match Rating::from_popularimeter(popularimeter.rating) {
+						Some(rating) if tag.get_item_ref(&item_key).is_none() => {
+							ItemValue::Integer(i64::from(rating.value()))
 						},
-						FrameValue::URL(content)
-						| FrameValue::UserURL(EncodedTextFrame { content, .. }) => ItemValue::Locator(content),
-						FrameValue::Picture { picture, .. } => {
-							tag.push_picture(picture);
-							continue;
-						},
-						FrameValue::Popularimeter(_) => continue,
-						FrameValue::Binary(binary) => ItemValue::Binary(binary),
-					};
-
-					tag.items.push(TagItem::new(item_key, item_value));
+						_ => return,
+					}
 				},
-			}
-		}
+				FrameValue::KeyValue(_) => return,
+				FrameValue::Timestamp(TimestampFrame { timestamp, .. }) => {
+					ItemValue::Text(timestamp.to_string())
+				},
+				// These have no generic equivalent, but are kept as binary so they can be written back
+				value @ (FrameValue::SynchronizedText(_)
+				| FrameValue::EventTimingCodes(_)
+				| FrameValue::SynchronizedTempo(_)) => match value.as_bytes() {
+					Ok(bytes) => ItemValue::Binary(bytes),
+					Err(_) => return,
+				},
+				FrameValue::Binary(binary) => ItemValue::Binary(binary),
+			};
 
-		tag
+			tag.items.push(TagItem::new(item_key, item_value));
+		},
 	}
 }
-
 impl From<Tag> for ID3v2Tag {
 	fn from(mut input: Tag) -> Self {
-		fn join_items(input: &mut Tag, key: &ItemKey) -> String {
+		fn join_items(input: &mut Tag, key: &ItemKey, separator: char) -> String {
 			let mut iter = input.take_strings(key);
 
 			match iter.next() {
@@ -598,7 +1173,7 @@ impl From<Tag> for ID3v2Tag {
 					let mut s = String::with_capacity(iter.size_hint().0);
 					s.push_str(&first);
 					iter.for_each(|i| {
-						s.push('/');
+						s.push(separator);
 						s.push_str(&i);
 					});
 
@@ -607,15 +1182,41 @@ impl From<Tag> for ID3v2Tag {
 			}
 		}
 
-		let mut id3v2_tag = ID3v2Tag {
-			frames: Vec::with_capacity(input.item_count() as usize),
-			..ID3v2Tag::default()
+		// Frames that are still accurately represented by the items are kept as-is, see `Tag#native-data`
+		let mut id3v2_tag = match input.passthrough.take() {
+			Some(Passthrough::ID3v2 { mut tag, frames }) => {
+				tag.frames = frames.retain_unchanged(&mut input);
+				tag
+			},
+			_ => ID3v2Tag::default(),
 		};
 
-		let artists = join_items(&mut input, &ItemKey::TrackArtist);
-		id3v2_tag.set_artist(artists);
+		input.convert_typed_items_to_text();
+
+		let artists = join_items(&mut input, &ItemKey::TrackArtist, '/');
+		if !artists.is_empty() {
+			id3v2_tag.set_artist(artists);
+		}
+
+		// Genres can contain '/' ("Pop/Funk"), so they are stored as separate values
+		let genres = join_items(&mut input, &ItemKey::Genre, '\0');
+		if !genres.is_empty() {
+			id3v2_tag.set_genre(genres);
+		}
+
+		for (id, value) in credit_frames(&input.items) {
+			id3v2_tag.insert(Frame {
+				id: FrameID::Valid(String::from(id)),
+				value,
+				flags: FrameFlags::default(),
+			});
+		}
 
 		for item in input.items {
+			if is_credit_key(item.key()) {
+				continue;
+			}
+
 			let frame: Frame = match item.into() {
 				Some(frame) => frame,
 				None => continue,
@@ -658,9 +1259,27 @@ pub(crate) fn tag_frames(tag: &Tag) -> impl Iterator<Item = FrameRef<'_>> + '_ {
 	let items = tag
 		.items()
 		.iter()
+		.filter(|item| !is_credit_key(item.key()) && item.key() != &ItemKey::Genre)
 		.map(TryInto::<FrameRef<'_>>::try_into)
 		.filter_map(Result::ok);
 
+	// Multiple genres are stored in a single frame, see `From<Tag> for ID3v2Tag`
+	let genres = tag.get_strings(&ItemKey::Genre).collect::<Vec<_>>();
+	let genres = (!genres.is_empty()).then(|| FrameRef {
+		id: "TCON",
+		value: Cow::Owned(FrameValue::Text {
+			encoding: TextEncoding::UTF8,
+			value: genres.join("\0"),
+		}),
+		flags: FrameFlags::default(),
+	});
+
+	let credits = credit_frames(tag.items()).map(|(id, value)| FrameRef {
+		id,
+		value: Cow::Owned(value),
+		flags: FrameFlags::default(),
+	});
+
 	let pictures = tag.pictures().iter().map(|p| FrameRef {
 		id: "APIC",
 		value: Cow::Owned(FrameValue::Picture {
@@ -670,7 +1289,81 @@ pub(crate) fn tag_frames(tag: &Tag) -> impl Iterator<Item = FrameRef<'_>> + '_ {
 		flags: FrameFlags::default(),
 	});
 
-	items.chain(pictures)
+	items.chain(genres).chain(credits).chain(pictures)
+}
+
+// The `TIPL` roles that have their own `ItemKey`s, any others use `ItemKey::InvolvedPeople`
+const INVOLVED_PEOPLE_ROLES: [(&str, ItemKey); 5] = [
+	("arranger", ItemKey::Arranger),
+	("engineer", ItemKey::Engineer),
+	("DJ-mix", ItemKey::MixDj),
+	("mix", ItemKey::MixEngineer),
+	("producer", ItemKey::Producer),
+];
+
+pub(crate) fn is_credit_key(key: &ItemKey) -> bool {
+	matches!(
+		key,
+		ItemKey::InvolvedPeople | ItemKey::MusicianCredits | ItemKey::Performer
+	) || INVOLVED_PEOPLE_ROLES.iter().any(|(_, k)| k == key)
+}
+
+// Credits are stored in a `Tag` as "name (role)", the same as Vorbis Comments' `PERFORMER`
+fn format_credit(role: &str, name: &str) -> String {
+	if role.is_empty() {
+		return name.to_string();
+	}
+
+	format!("{name} ({role})")
+}
+
+// Splits a "name (role)" credit into (role, name)
+fn split_credit(credit: &str) -> (String, String) {
+	if let Some(stripped) = credit.strip_suffix(')') {
+		if let Some((name, role)) = stripped.rsplit_once(" (") {
+			return (role.to_string(), name.to_string());
+		}
+	}
+
+	(String::new(), credit.to_string())
+}
+
+// Collects the credits in a `Tag` into `TIPL` and `TMCL` frames
+fn credit_frames(items: &[TagItem]) -> impl Iterator<Item = (&'static str, FrameValue)> {
+	let mut involved_people = Vec::new();
+	let mut musician_credits = Vec::new();
+
+	for item in items {
+		let text = match item.value() {
+			ItemValue::Text(text) => text,
+			_ => continue,
+		};
+
+		match item.key() {
+			ItemKey::InvolvedPeople => involved_people.push(split_credit(text)),
+			ItemKey::MusicianCredits | ItemKey::Performer => {
+				musician_credits.push(split_credit(text))
+			},
+			key => {
+				if let Some((role, _)) = INVOLVED_PEOPLE_ROLES.iter().find(|(_, k)| k == key) {
+					involved_people.push(((*role).to_string(), text.clone()));
+				}
+			},
+		}
+	}
+
+	[("TIPL", involved_people), ("TMCL", musician_credits)]
+		.into_iter()
+		.filter(|(_, key_value_pairs)| !key_value_pairs.is_empty())
+		.map(|(id, key_value_pairs)| {
+			(
+				id,
+				FrameValue::KeyValue(KeyValueFrame {
+					encoding: TextEncoding::UTF8,
+					key_value_pairs,
+				}),
+			)
+		})
 }
 
 impl<'a, I: Iterator<Item = FrameRef<'a>> + 'a> Id3v2TagRef<'a, I> {
@@ -690,13 +1383,16 @@ impl<'a, I: Iterator<Item = FrameRef<'a>> + 'a> Id3v2TagRef<'a, I> {
 mod tests {
 	use crate::id3::v2::items::popularimeter::Popularimeter;
 	use crate::id3::v2::{
-		read_id3v2_header, EncodedTextFrame, Frame, FrameFlags, FrameID, FrameValue, ID3v2Tag,
-		ID3v2Version, LanguageFrame,
+		read_id3v2_header, EncodedTextFrame, EventTimingCodes, EventType, Frame, FrameFlags,
+		FrameID, FrameValue, ID3v2Tag, ID3v2Version, KeyValueFrame, LanguageFrame,
+		SyncTextContentType, SyncTextInformation, SynchronizedTempo, SynchronizedText,
+		TimestampFormat, TimestampFrame,
 	};
 	use crate::tag::utils::test_utils::read_path;
 	use crate::util::text::TextEncoding;
 	use crate::{
 		ItemKey, ItemValue, MimeType, Picture, PictureType, Tag, TagExt, TagItem, TagType,
+		Timestamp,
 	};
 
 	fn read_tag(path: &str) -> ID3v2Tag {
@@ -705,7 +1401,7 @@ mod tests {
 		let mut reader = std::io::Cursor::new(&tag_bytes[..]);
 
 		let header = read_id3v2_header(&mut reader).unwrap();
-		crate::id3::v2::read::parse_id3v2(&mut reader, header).unwrap()
+		crate::id3::v2::read::parse_id3v2(&mut reader, header, crate::ParsingMode::Strict).unwrap()
 	}
 
 	#[test]
@@ -768,10 +1464,10 @@ mod tests {
 		expected_tag.insert(
 			Frame::new(
 				"TDRC",
-				FrameValue::Text {
+				FrameValue::Timestamp(TimestampFrame {
 					encoding,
-					value: String::from("1984"),
-				},
+					timestamp: Timestamp::from_year(1984),
+				}),
 				flags,
 			)
 			.unwrap(),
@@ -816,7 +1512,9 @@ mod tests {
 		let temp_reader = &mut &*writer;
 
 		let temp_header = read_id3v2_header(temp_reader).unwrap();
-		let temp_parsed_tag = crate::id3::v2::read::parse_id3v2(temp_reader, temp_header).unwrap();
+		let temp_parsed_tag =
+			crate::id3::v2::read::parse_id3v2(temp_reader, temp_header, crate::ParsingMode::Strict)
+				.unwrap();
 
 		assert_eq!(parsed_tag, temp_parsed_tag);
 	}
@@ -1014,7 +1712,9 @@ mod tests {
 		let mut reader = &mut &writer[..];
 
 		let header = read_id3v2_header(&mut reader).unwrap();
-		assert!(crate::id3::v2::read::parse_id3v2(reader, header).is_ok());
+		assert!(
+			crate::id3::v2::read::parse_id3v2(reader, header, crate::ParsingMode::Strict).is_ok()
+		);
 
 		assert_eq!(writer[3..10], writer[writer.len() - 7..])
 	}
@@ -1039,7 +1739,8 @@ mod tests {
 		let mut reader = &mut &writer[..];
 
 		let header = read_id3v2_header(&mut reader).unwrap();
-		let tag = crate::id3::v2::read::parse_id3v2(reader, header).unwrap();
+		let tag =
+			crate::id3::v2::read::parse_id3v2(reader, header, crate::ParsingMode::Strict).unwrap();
 
 		assert_eq!(tag.len(), 1);
 		assert_eq!(
@@ -1191,4 +1892,744 @@ mod tests {
 		assert_eq!(tag.frames.len(), 2);
 		assert_eq!(&tag.frames, &[txxx_frame, wxxx_frame])
 	}
+
+	#[test]
+	fn musicbrainz_tag_conversion() {
+		let mut tag = Tag::new(TagType::ID3v2);
+		tag.insert_text(
+			ItemKey::MusicBrainzRecordingId,
+			String::from("3bbd2f0c-7d0e-4f41-9f35-0d3f1b0e8c8a"),
+		);
+		tag.insert_text(
+			ItemKey::MusicBrainzReleaseId,
+			String::from("0b2a5f11-5bc1-4a4d-8b9b-3c1d8e4f1a2b"),
+		);
+		tag.insert_text(
+			ItemKey::AcoustId,
+			String::from("a1b2c3d4-e5f6-4a5b-8c9d-0e1f2a3b4c5d"),
+		);
+
+		let expected_frames = [
+			Frame::new(
+				"UFID",
+				FrameValue::Binary(
+					b"http://musicbrainz.org\03bbd2f0c-7d0e-4f41-9f35-0d3f1b0e8c8a".to_vec(),
+				),
+				FrameFlags::default(),
+			)
+			.unwrap(),
+			Frame::new(
+				"TXXX",
+				FrameValue::UserText(EncodedTextFrame {
+					encoding: TextEncoding::UTF8,
+					description: String::from("MusicBrainz Album Id"),
+					content: String::from("0b2a5f11-5bc1-4a4d-8b9b-3c1d8e4f1a2b"),
+				}),
+				FrameFlags::default(),
+			)
+			.unwrap(),
+			Frame::new(
+				"TXXX",
+				FrameValue::UserText(EncodedTextFrame {
+					encoding: TextEncoding::UTF8,
+					description: String::from("Acoustid Id"),
+					content: String::from("a1b2c3d4-e5f6-4a5b-8c9d-0e1f2a3b4c5d"),
+				}),
+				FrameFlags::default(),
+			)
+			.unwrap(),
+		];
+
+		// Writing a `Tag` directly should produce the same frames
+		let mut writer = Vec::new();
+		super::Id3v2TagRef {
+			flags: super::ID3v2TagFlags::default(),
+			frames: super::tag_frames(&tag),
+		}
+		.dump_to(&mut writer)
+		.unwrap();
+
+		let mut reader = &writer[..];
+		let header = read_id3v2_header(&mut reader).unwrap();
+		let written_tag =
+			crate::id3::v2::read::parse_id3v2(&mut reader, header, crate::ParsingMode::Strict)
+				.unwrap();
+		assert_eq!(written_tag.frames, expected_frames);
+
+		let id3v2: ID3v2Tag = tag.clone().into();
+		assert_eq!(id3v2.frames, expected_frames);
+
+		let converted_tag: Tag = id3v2.into();
+		assert_eq!(converted_tag.items(), tag.items());
+	}
+
+	#[test]
+	fn other_ufid_frames_are_kept() {
+		let ufid = Frame::new(
+			"UFID",
+			FrameValue::Binary(b"http://example.com\0some id".to_vec()),
+			FrameFlags::default(),
+		)
+		.unwrap();
+
+		let mut id3v2 = ID3v2Tag::default();
+		id3v2.insert(ufid.clone());
+
+		let tag: Tag = id3v2.into();
+		assert!(tag.get_item_ref(&ItemKey::MusicBrainzRecordingId).is_none());
+
+		let id3v2: ID3v2Tag = tag.into();
+		assert_eq!(id3v2.frames, [ufid]);
+	}
+
+	#[test]
+	fn timestamp_frame_conversion() {
+		use crate::traits::Accessor;
+		let mut tag = Tag::new(TagType::VorbisComments);
+		tag.insert_text(ItemKey::RecordingDate, String::from("2022-09-22 10:00:00"));
+
+		let id3v2: ID3v2Tag = tag.into();
+		assert_eq!(id3v2.year(), Some(2022));
+		assert_eq!(
+			id3v2.get("TDRC").map(|frame| frame.content()),
+			Some(&FrameValue::Timestamp(TimestampFrame {
+				encoding: TextEncoding::Latin1,
+				timestamp: Timestamp {
+					year: 2022,
+					month: Some(9),
+					day: Some(22),
+					hour: Some(10),
+					minute: Some(0),
+					second: Some(0),
+				},
+			}))
+		);
+
+		let tag: Tag = id3v2.into();
+		assert_eq!(
+			tag.get_string(&ItemKey::RecordingDate),
+			Some("2022-09-22T10:00:00")
+		);
+
+		// Anything that isn't a valid timestamp is kept as text
+		let mut id3v2 = ID3v2Tag::default();
+		id3v2.insert(
+			Frame::new(
+				"TDRC",
+				FrameValue::Text {
+					encoding: TextEncoding::UTF8,
+					value: String::from("2022, sometime in summer"),
+				},
+				FrameFlags::default(),
+			)
+			.unwrap(),
+		);
+
+		let mut writer = Vec::new();
+		id3v2.dump_to(&mut writer).unwrap();
+
+		let mut reader = std::io::Cursor::new(&writer[..]);
+		let header = read_id3v2_header(&mut reader).unwrap();
+		let parsed =
+			crate::id3::v2::read::parse_id3v2(&mut reader, header, crate::ParsingMode::Strict)
+				.unwrap();
+
+		assert!(matches!(
+			parsed.get("TDRC").map(|frame| frame.content()),
+			Some(FrameValue::Text { .. })
+		));
+		assert_eq!(parsed.year(), Some(2022));
+	}
+
+	#[test]
+	fn key_value_frame_conversion() {
+		let mut tag = Tag::new(TagType::ID3v2);
+		tag.insert_text(ItemKey::Producer, String::from("Foo producer"));
+		tag.insert_text(ItemKey::InvolvedPeople, String::from("Bar (orchestra)"));
+		tag.push_item(TagItem::new(
+			ItemKey::Performer,
+			ItemValue::Text(String::from("Baz (guitar)")),
+		));
+		tag.push_item(TagItem::new(
+			ItemKey::Performer,
+			ItemValue::Text(String::from("Qux")),
+		));
+
+		let id3v2: ID3v2Tag = tag.clone().into();
+		assert_eq!(
+			id3v2.involved_people(),
+			Some(
+				&[
+					(String::from("producer"), String::from("Foo producer")),
+					(String::from("orchestra"), String::from("Bar")),
+				][..]
+			)
+		);
+		assert_eq!(
+			id3v2.musician_credits(),
+			Some(
+				&[
+					(String::from("guitar"), String::from("Baz")),
+					(String::new(), String::from("Qux")),
+				][..]
+			)
+		);
+
+		let converted: Tag = id3v2.into();
+
+		for key in [
+			ItemKey::Producer,
+			ItemKey::InvolvedPeople,
+			ItemKey::Performer,
+		] {
+			assert_eq!(
+				converted.get_strings(&key).collect::<Vec<_>>(),
+				tag.get_strings(&key).collect::<Vec<_>>()
+			);
+		}
+	}
+
+	#[test]
+	fn credits_to_other_formats() {
+		let mut id3v2 = ID3v2Tag::default();
+		id3v2.set_involved_people(vec![
+			(String::from("producer"), String::from("Foo producer")),
+			(String::from("orchestra"), String::from("Bar")),
+		]);
+		id3v2.set_musician_credits(vec![(String::from("guitar"), String::from("Baz"))]);
+
+		let tag: Tag = id3v2.into();
+
+		let vorbis_comments: crate::ogg::VorbisComments = tag.clone().into();
+		assert_eq!(vorbis_comments.get("PRODUCER"), Some("Foo producer"));
+		assert_eq!(
+			vorbis_comments.get_all("PERFORMER").collect::<Vec<_>>(),
+			["Bar (orchestra)", "Baz (guitar)"]
+		);
+
+		let ape: crate::ape::ApeTag = tag.into();
+		assert_eq!(
+			ape.get_key("Producer").map(crate::ape::ApeItem::value),
+			Some(&ItemValue::Text(String::from("Foo producer")))
+		);
+		assert_eq!(
+			ape.get_key("Performer").map(crate::ape::ApeItem::value),
+			Some(&ItemValue::Text(String::from(
+				"Bar (orchestra)\0Baz (guitar)"
+			)))
+		);
+
+		// And back, with every credit stored as a performer
+		let id3v2: ID3v2Tag = Tag::from(ape).into();
+		assert_eq!(
+			id3v2.involved_people(),
+			Some(&[(String::from("producer"), String::from("Foo producer"))][..])
+		);
+		assert_eq!(
+			id3v2.musician_credits(),
+			Some(
+				&[
+					(String::from("orchestra"), String::from("Bar")),
+					(String::from("guitar"), String::from("Baz")),
+				][..]
+			)
+		);
+	}
+
+	#[test]
+	fn malformed_synchronized_frame() {
+		// An invalid timestamp format
+		let content = [0, b'e', b'n', b'g', 9, 1, 0];
+
+		let mut tag_bytes = vec![b'I', b'D', b'3', 4, 0, 0, 0, 0, 0, 10 + content.len() as u8];
+		tag_bytes.extend(b"SYLT");
+		tag_bytes.extend((content.len() as u32).to_be_bytes());
+		tag_bytes.extend([0, 0]);
+		tag_bytes.extend(content);
+
+		let mut reader = std::io::Cursor::new(&tag_bytes[..]);
+		let header = read_id3v2_header(&mut reader).unwrap();
+		assert!(
+			crate::id3::v2::read::parse_id3v2(&mut reader, header, crate::ParsingMode::Strict)
+				.is_err()
+		);
+
+		// Outside of strict mode, the frame is kept as binary
+		let mut reader = std::io::Cursor::new(&tag_bytes[..]);
+		let header = read_id3v2_header(&mut reader).unwrap();
+		let tag =
+			crate::id3::v2::read::parse_id3v2(&mut reader, header, crate::ParsingMode::Relaxed)
+				.unwrap();
+
+		assert_eq!(
+			tag.get("SYLT").map(Frame::content),
+			Some(&FrameValue::Binary(content.to_vec()))
+		);
+	}
+
+	#[test]
+	fn ipls_upgrade() {
+		let content = b"\0producer\0Foo producer\0guitar\0Bar";
+
+		let mut tag_bytes = vec![b'I', b'D', b'3', 3, 0, 0, 0, 0, 0, 10 + content.len() as u8];
+		tag_bytes.extend(b"IPLS");
+		tag_bytes.extend((content.len() as u32).to_be_bytes());
+		tag_bytes.extend([0, 0]);
+		tag_bytes.extend(content);
+
+		let mut reader = std::io::Cursor::new(&tag_bytes[..]);
+		let header = read_id3v2_header(&mut reader).unwrap();
+		let tag =
+			crate::id3::v2::read::parse_id3v2(&mut reader, header, crate::ParsingMode::Strict)
+				.unwrap();
+
+		assert_eq!(tag.original_version(), ID3v2Version::V3);
+		assert_eq!(
+			tag.get("TIPL").map(Frame::content),
+			Some(&FrameValue::KeyValue(KeyValueFrame {
+				encoding: TextEncoding::Latin1,
+				key_value_pairs: vec![
+					(String::from("producer"), String::from("Foo producer")),
+					(String::from("guitar"), String::from("Bar")),
+				],
+			}))
+		);
+	}
+
+	#[test]
+	fn synchronized_frames() {
+		let frames = [
+			Frame::new(
+				"SYLT",
+				FrameValue::SynchronizedText(SynchronizedText {
+					information: SyncTextInformation {
+						encoding: TextEncoding::UTF16,
+						language: String::from("eng"),
+						timestamp_format: TimestampFormat::MS,
+						content_type: SyncTextContentType::Lyrics,
+						description: Some(String::from("Foo description")),
+					},
+					content: vec![(0, String::from("Foo")), (1000, String::from("Bar"))],
+				}),
+				FrameFlags::default(),
+			)
+			.unwrap(),
+			Frame::new(
+				"ETCO",
+				FrameValue::EventTimingCodes(EventTimingCodes {
+					timestamp_format: TimestampFormat::MS,
+					events: vec![(0, EventType::IntroStart), (1000, EventType::IntroEnd)],
+				}),
+				FrameFlags::default(),
+			)
+			.unwrap(),
+			Frame::new(
+				"SYTC",
+				FrameValue::SynchronizedTempo(SynchronizedTempo {
+					timestamp_format: TimestampFormat::MS,
+					content: vec![(0, 120), (1000, 300)],
+				}),
+				FrameFlags::default(),
+			)
+			.unwrap(),
+		];
+
+		let mut tag = ID3v2Tag::default();
+		for frame in frames.clone() {
+			tag.insert(frame);
+		}
+
+		let mut writer = Vec::new();
+		tag.dump_to(&mut writer).unwrap();
+
+		let mut reader = std::io::Cursor::new(&writer[..]);
+		let header = read_id3v2_header(&mut reader).unwrap();
+		let parsed =
+			crate::id3::v2::read::parse_id3v2(&mut reader, header, crate::ParsingMode::Strict)
+				.unwrap();
+
+		for frame in &frames {
+			assert_eq!(
+				parsed.get(frame.id_str()).map(Frame::content),
+				Some(frame.content())
+			);
+		}
+
+		// The frames survive a conversion to and from `Tag`
+		let tag: Tag = parsed.into();
+		assert_eq!(tag.item_count(), 3);
+
+		let mut writer = Vec::new();
+		tag.dump_to(&mut writer).unwrap();
+
+		let mut reader = std::io::Cursor::new(&writer[..]);
+		let header = read_id3v2_header(&mut reader).unwrap();
+		let parsed =
+			crate::id3::v2::read::parse_id3v2(&mut reader, header, crate::ParsingMode::Strict)
+				.unwrap();
+
+		for frame in &frames {
+			assert_eq!(
+				parsed.get(frame.id_str()).map(Frame::content),
+				Some(frame.content())
+			);
+		}
+	}
+
+	#[test]
+	fn user_text_accessors() {
+		use crate::traits::Accessor;
+
+		let mut tag = ID3v2Tag::default();
+		tag.insert(Frame {
+			id: FrameID::Valid(String::from("TXXX")),
+			value: FrameValue::UserText(EncodedTextFrame {
+				encoding: TextEncoding::UTF8,
+				description: String::from("replaygain_track_gain"),
+				content: String::from("-3.10 dB"),
+			}),
+			flags: FrameFlags::default(),
+		});
+
+		// Descriptions are matched case-insensitively
+		assert_eq!(tag.replay_gain_track_gain(), Some(-3.1));
+
+		tag.set_replay_gain_track_gain(-1.0);
+		assert_eq!(tag.len(), 1);
+		assert_eq!(tag.replay_gain_track_gain(), Some(-1.0));
+
+		tag.set_catalog_number(String::from("CAT-001"));
+		assert_eq!(tag.catalog_number(), Some("CAT-001"));
+		assert_eq!(tag.len(), 2);
+
+		tag.remove_replay_gain_track_gain();
+		assert_eq!(tag.replay_gain_track_gain(), None);
+		assert_eq!(tag.catalog_number(), Some("CAT-001"));
+
+		tag.set_lyrics(String::from("Foo lyrics"));
+		assert_eq!(tag.lyrics(), Some("Foo lyrics"));
+		assert!(tag.get("USLT").is_some());
+	}
+
+	#[test]
+	fn popm_to_tag_and_back() {
+		use crate::traits::Accessor;
+		use crate::Rating;
+
+		let id3v2 = read_tag("tests/tags/assets/id3v2/test_popm.id3v24");
+
+		let tag: Tag = id3v2.into();
+		assert_eq!(
+			tag.get_item_ref(&ItemKey::Popularimeter)
+				.map(TagItem::value),
+			Some(&ItemValue::Integer(80))
+		);
+		assert_eq!(tag.rating(), Some(Rating::from_stars(4)));
+
+		// Unmodified, the original frame is kept
+		let id3v2: ID3v2Tag = tag.clone().into();
+		assert_eq!(
+			id3v2.get("POPM").map(Frame::content),
+			Some(&FrameValue::Popularimeter(Popularimeter {
+				email: String::from("foo@bar.com"),
+				rating: 196,
+				counter: 65535
+			}))
+		);
+
+		let mut tag = tag;
+		tag.clear_passthrough();
+
+		let id3v2: ID3v2Tag = tag.clone().into();
+		assert_eq!(
+			id3v2.get("POPM").map(Frame::content),
+			Some(&FrameValue::Popularimeter(Popularimeter {
+				email: String::from("Windows Media Player 9 Series"),
+				rating: 196,
+				counter: 0
+			}))
+		);
+
+		// The generic tag should be able to write the frame as well
+		let mut writer = Vec::new();
+		tag.dump_to(&mut writer).unwrap();
+
+		let mut reader = std::io::Cursor::new(&writer[..]);
+		let header = read_id3v2_header(&mut reader).unwrap();
+		let parsed_tag =
+			crate::id3::v2::read::parse_id3v2(&mut reader, header, crate::ParsingMode::Strict)
+				.unwrap();
+		assert_eq!(parsed_tag.rating(), Some(Rating::from_stars(4)));
+	}
+
+	#[test]
+	fn rating_accessor() {
+		use crate::traits::Accessor;
+		use crate::Rating;
+
+		let mut id3v2 = read_tag("tests/tags/assets/id3v2/test_popm.id3v24");
+		assert_eq!(id3v2.rating(), Some(Rating::from_stars(4)));
+
+		// The existing frame's email and play counter should be kept
+		id3v2.set_rating(Rating::from_stars(1));
+		assert_eq!(
+			id3v2.get("POPM").map(Frame::content),
+			Some(&FrameValue::Popularimeter(Popularimeter {
+				email: String::from("foo@bar.com"),
+				rating: 1,
+				counter: 65535
+			}))
+		);
+
+		id3v2.remove_rating();
+		assert!(id3v2.get("POPM").is_none());
+		assert_eq!(id3v2.rating(), None);
+
+		id3v2.set_rating(Rating::new(50));
+		assert_eq!(id3v2.rating(), Some(Rating::from_stars(3)));
+	}
+
+	#[test]
+	fn genres_to_tag_and_back() {
+		use crate::id3::v1::ID3v1Tag;
+		use crate::traits::Accessor;
+
+		let mut id3v2 = ID3v2Tag::default();
+		id3v2.set_genre(String::from("(62)(RX)Eurodisco"));
+
+		let mut tag: Tag = id3v2.into();
+		assert_eq!(
+			tag.get_strings(&ItemKey::Genre).collect::<Vec<_>>(),
+			vec!["Pop/Funk", "Remix", "Eurodisco"]
+		);
+
+		// Unmodified, the original frame is kept
+		let id3v2: ID3v2Tag = tag.clone().into();
+		assert_eq!(
+			id3v2.get("TCON").map(Frame::content),
+			Some(&FrameValue::Text {
+				encoding: TextEncoding::UTF8,
+				value: String::from("(62)(RX)Eurodisco")
+			})
+		);
+
+		tag.clear_passthrough();
+
+		let id3v1: ID3v1Tag = tag.clone().into();
+		assert_eq!(id3v1.genre, Some(62));
+
+		// Multiple genres are written as separate values in a single frame
+		let id3v2: ID3v2Tag = tag.clone().into();
+		assert_eq!(
+			id3v2.get("TCON").map(Frame::content),
+			Some(&FrameValue::Text {
+				encoding: TextEncoding::UTF8,
+				value: String::from("Pop/Funk\0Remix\0Eurodisco")
+			})
+		);
+
+		let mut writer = Vec::new();
+		tag.dump_to(&mut writer).unwrap();
+
+		let mut reader = std::io::Cursor::new(&writer[..]);
+		let header = read_id3v2_header(&mut reader).unwrap();
+		let mut parsed_tag =
+			crate::id3::v2::read::parse_id3v2(&mut reader, header, crate::ParsingMode::Strict)
+				.unwrap();
+		assert_eq!(
+			parsed_tag.genres().collect::<Vec<_>>(),
+			vec!["Pop/Funk", "Remix", "Eurodisco"]
+		);
+
+		parsed_tag.normalize_genres();
+		assert_eq!(
+			parsed_tag.get("TCON").map(Frame::content),
+			Some(&FrameValue::Text {
+				encoding: TextEncoding::UTF8,
+				value: String::from("62\0RX\0Eurodisco")
+			})
+		);
+		assert_eq!(parsed_tag.genre(), Some("Pop/Funk"));
+	}
+
+	#[test]
+	fn only_tcon_reads_multiple_values() {
+		let mut tag = ID3v2Tag::default();
+		tag.insert(Frame::text("TIT2", String::from("Foo\0Bar")));
+		tag.insert(Frame::text("TCON", String::from("Foo\0Bar")));
+
+		let mut writer = Vec::new();
+		tag.dump_to(&mut writer).unwrap();
+
+		let mut reader = std::io::Cursor::new(&writer[..]);
+		let header = read_id3v2_header(&mut reader).unwrap();
+		let parsed_tag =
+			crate::id3::v2::read::parse_id3v2(&mut reader, header, crate::ParsingMode::Strict)
+				.unwrap();
+
+		assert_eq!(
+			parsed_tag.get("TIT2").map(Frame::content),
+			Some(&FrameValue::Text {
+				encoding: TextEncoding::UTF8,
+				value: String::from("Foo")
+			})
+		);
+		assert_eq!(parsed_tag.genres().collect::<Vec<_>>(), vec!["Foo", "Bar"]);
+	}
+
+	#[test]
+	fn frame_by_frame_diff() {
+		use crate::diff::Change;
+		use crate::traits::Accessor;
+
+		let user_text = |content: &str| Frame {
+			id: FrameID::Valid(String::from("TXXX")),
+			value: FrameValue::UserText(EncodedTextFrame {
+				encoding: TextEncoding::UTF8,
+				description: String::from("FOO"),
+				content: String::from(content),
+			}),
+			flags: FrameFlags::default(),
+		};
+
+		let mut old = ID3v2Tag::default();
+		old.set_title(String::from("Foo title"));
+		old.set_comment(String::from("Foo comment"));
+		old.insert(user_text("Foo"));
+
+		let mut new = ID3v2Tag::default();
+		new.insert(Frame {
+			id: FrameID::Valid(String::from("TIT2")),
+			value: FrameValue::Text {
+				encoding: TextEncoding::Latin1,
+				value: String::from("Foo title"),
+			},
+			flags: FrameFlags::default(),
+		});
+		new.insert(user_text("Bar"));
+		new.set_artist(String::from("Foo artist"));
+
+		let diff = old.diff(&new);
+		assert_eq!(diff.len(), 4);
+		assert!(matches!(diff[0], Change::Modified { old, .. } if old.id_str() == "TIT2"));
+		assert!(matches!(diff[1], Change::Removed(frame) if frame.id_str() == "COMM"));
+		assert_eq!(
+			diff[2],
+			Change::Modified {
+				old: &user_text("Foo"),
+				new: &user_text("Bar")
+			}
+		);
+		assert!(matches!(diff[3], Change::Added(frame) if frame.id_str() == "TPE1"));
+
+		assert!(old.diff(&old).is_empty());
+	}
+
+	#[test]
+	fn pictures_not_kept_as_native_data() {
+		let picture =
+			|data| Picture::new_unchecked(PictureType::CoverFront, MimeType::Png, None, data);
+
+		let mut id3v2 = ID3v2Tag::default();
+		id3v2.insert_picture(picture(vec![1, 2, 3]));
+
+		// The picture is only created once, from the tag's pictures
+		let tag: Tag = id3v2.into();
+		assert!(tag.has_passthrough());
+		let converted = ID3v2Tag::from(tag.clone());
+		assert_eq!(converted.iter().filter(|f| f.id_str() == "APIC").count(), 1);
+
+		let mut tag = tag;
+		tag.remove_picture_type(PictureType::CoverFront);
+		tag.push_picture(picture(vec![4, 5, 6]));
+
+		let converted: Tag = ID3v2Tag::from(tag).into();
+		assert_eq!(converted.pictures(), &[picture(vec![4, 5, 6])]);
+	}
+
+	#[test]
+	fn native_data_through_tag() {
+		use crate::diff::Change;
+		use crate::traits::Accessor;
+
+		let comment = |description: &str| Frame {
+			id: FrameID::Valid(String::from("COMM")),
+			value: FrameValue::Comment(LanguageFrame {
+				encoding: TextEncoding::UTF16,
+				language: *b"eng",
+				description: String::from(description),
+				content: String::from("Foo comment"),
+			}),
+			flags: FrameFlags::default(),
+		};
+
+		let mut id3v2 = ID3v2Tag::default();
+		id3v2.insert(Frame {
+			id: FrameID::Valid(String::from("TIT2")),
+			value: FrameValue::Text {
+				encoding: TextEncoding::UTF16BE,
+				value: String::from("Foo title"),
+			},
+			flags: FrameFlags {
+				read_only: true,
+				..FrameFlags::default()
+			},
+		});
+		id3v2.insert(Frame {
+			id: FrameID::Valid(String::from("TALB")),
+			value: FrameValue::Text {
+				encoding: TextEncoding::UTF16BE,
+				value: String::from("Foo album"),
+			},
+			flags: FrameFlags {
+				read_only: true,
+				..FrameFlags::default()
+			},
+		});
+		id3v2.insert(comment("Foo"));
+		id3v2.insert(comment("Bar"));
+		id3v2.insert(Frame {
+			id: FrameID::Valid(String::from("GEOB")),
+			value: FrameValue::Binary(vec![0, b'f', b'o', b'o', 0, 0, 1, 2, 3]),
+			flags: FrameFlags::default(),
+		});
+
+		// Unmodified, the tag should be identical
+		let tag: Tag = id3v2.clone().into();
+		assert_eq!(ID3v2Tag::from(tag.clone()).diff(&id3v2), Vec::new());
+
+		// Only the modified frame should change
+		let mut tag = tag;
+		tag.set_title(String::from("Bar title"));
+
+		let converted = ID3v2Tag::from(tag.clone());
+		let diff = converted.diff(&id3v2);
+		assert_eq!(diff.len(), 1);
+		assert!(matches!(diff[0], Change::Modified { new, .. } if new.id_str() == "TIT2"));
+		assert_eq!(converted.title(), Some("Bar title"));
+
+		// The native data is written with the generic tag as well
+		let mut writer = Vec::new();
+		tag.dump_to(&mut writer).unwrap();
+
+		let mut reader = std::io::Cursor::new(&writer[..]);
+		let header = read_id3v2_header(&mut reader).unwrap();
+		let parsed_tag =
+			crate::id3::v2::read::parse_id3v2(&mut reader, header, crate::ParsingMode::Strict)
+				.unwrap();
+		assert_eq!(parsed_tag.get("TALB"), id3v2.get("TALB"));
+		assert_eq!(parsed_tag.get("GEOB"), id3v2.get("GEOB"));
+		assert_eq!(
+			parsed_tag.iter().filter(|f| f.id_str() == "COMM").count(),
+			2
+		);
+
+		// Without it, only the items are used
+		tag.clear_passthrough();
+		let converted = ID3v2Tag::from(tag);
+		assert_eq!(
+			converted.get("TALB").map(Frame::flags),
+			Some(&FrameFlags::default())
+		);
+	}
 }
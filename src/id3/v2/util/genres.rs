@@ -0,0 +1,139 @@
+//! Utilities for working with `TCON` (content type) frames
+//!
+//! A `TCON` frame can reference the ID3v1 genres in a few ways:
+//!
+//! * ID3v2.4: Each (null separated) value is either a genre name, an ID3v1 genre number (`"17"`),
+//!   or one of the special codes `"RX"` (Remix) and `"CR"` (Cover)
+//! * ID3v2.2/ID3v2.3: Genre numbers and codes are wrapped in parentheses (`"(17)"`), and can be
+//!   followed by a refinement (`"(17)Rock"`, `"(4)Eurodisco"`). A refinement beginning with a `(`
+//!   is escaped as `((`.
+
+use crate::id3::v1::constants::{genre_index, GENRES};
+
+const REMIX: &str = "Remix";
+const COVER: &str = "Cover";
+
+/// Resolves the contents of a `TCON` frame into a list of genre names
+pub(crate) fn parse_genres(content: &str) -> Vec<&str> {
+	let mut genres = Vec::new();
+
+	// Unlike other text frames, '/' is not treated as a separator, as it appears in genre names ("Pop/Funk")
+	for value in content.split('\0') {
+		if value.is_empty() {
+			continue;
+		}
+
+		// ID3v2.4 numbers and codes
+		if let Some(genre) = resolve_reference(value) {
+			genres.push(genre);
+			continue;
+		}
+
+		// ID3v2.3 references
+		let references_start = genres.len();
+		let mut remaining = value;
+		while remaining.starts_with('(') && !remaining.starts_with("((") {
+			let genre = remaining
+				.find(')')
+				.and_then(|end| resolve_reference(&remaining[1..end]).map(|genre| (end, genre)));
+
+			match genre {
+				Some((end, genre)) => {
+					genres.push(genre);
+					remaining = &remaining[end + 1..];
+				},
+				// Not a reference, treat the rest as text
+				None => break,
+			}
+		}
+
+		if remaining.starts_with("((") {
+			remaining = &remaining[1..];
+		}
+
+		// A refinement that matches one of its references adds nothing (ex. "(17)Rock")
+		if remaining.is_empty()
+			|| genres[references_start..]
+				.iter()
+				.any(|genre| genre.eq_ignore_ascii_case(remaining))
+		{
+			continue;
+		}
+
+		genres.push(remaining);
+	}
+
+	genres
+}
+
+/// Converts a genre name into the numeric ID3v2.4 form, if possible
+///
+/// Names that are not ID3v1 genres are returned as-is
+pub(crate) fn normalize_genre(genre: &str) -> String {
+	if genre.eq_ignore_ascii_case(REMIX) {
+		return String::from("RX");
+	}
+
+	if genre.eq_ignore_ascii_case(COVER) {
+		return String::from("CR");
+	}
+
+	match genre_index(genre) {
+		Some(index) => index.to_string(),
+		None => genre.to_string(),
+	}
+}
+
+fn resolve_reference(reference: &str) -> Option<&'static str> {
+	match reference {
+		"RX" => Some(REMIX),
+		"CR" => Some(COVER),
+		_ if !reference.is_empty() && reference.bytes().all(|b| b.is_ascii_digit()) => reference
+			.parse::<usize>()
+			.ok()
+			.and_then(|index| GENRES.get(index).copied()),
+		_ => None,
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::{normalize_genre, parse_genres};
+
+	#[test]
+	fn id3v24_genres() {
+		assert_eq!(parse_genres("17"), vec!["Rock"]);
+		assert_eq!(parse_genres("17\0Foo\0RX"), vec!["Rock", "Foo", "Remix"]);
+		assert_eq!(parse_genres("Pop/Funk"), vec!["Pop/Funk"]);
+		assert_eq!(parse_genres("CR"), vec!["Cover"]);
+		// Out of range numbers are left untouched
+		assert_eq!(parse_genres("500"), vec!["500"]);
+	}
+
+	#[test]
+	fn id3v23_genres() {
+		assert_eq!(parse_genres("(17)"), vec!["Rock"]);
+		assert_eq!(parse_genres("(17)Rock"), vec!["Rock"]);
+		assert_eq!(parse_genres("(4)Eurodisco"), vec!["Disco", "Eurodisco"]);
+		assert_eq!(parse_genres("(17)(RX)"), vec!["Rock", "Remix"]);
+		assert_eq!(parse_genres("(CR)(31)"), vec!["Cover", "Trance"]);
+		assert_eq!(parse_genres("(17)(RX)Rock"), vec!["Rock", "Remix"]);
+		assert_eq!(
+			parse_genres("(4)(RX)Eurodisco"),
+			vec!["Disco", "Remix", "Eurodisco"]
+		);
+		assert_eq!(parse_genres("((Foo) bar"), vec!["(Foo) bar"]);
+		assert_eq!(parse_genres("(Foo) bar"), vec!["(Foo) bar"]);
+		assert_eq!(parse_genres("(17"), vec!["(17"]);
+	}
+
+	#[test]
+	fn normalize() {
+		assert_eq!(normalize_genre("Rock"), "17");
+		assert_eq!(normalize_genre("rock"), "17");
+		assert_eq!(normalize_genre("Remix"), "RX");
+		assert_eq!(normalize_genre("Cover"), "CR");
+		assert_eq!(normalize_genre("Synthpop"), "147");
+		assert_eq!(normalize_genre("Foo"), "Foo");
+	}
+}
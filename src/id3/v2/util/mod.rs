@@ -2,6 +2,7 @@
 
 cfg_if::cfg_if! {
 	if #[cfg(feature = "id3v2")] {
+		pub(crate) mod genres;
 		pub(crate) mod upgrade;
 
 		use crate::error::{ID3v2Error, ID3v2ErrorKind, Result};
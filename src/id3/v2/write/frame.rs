@@ -1,5 +1,7 @@
 use crate::error::{ID3v2Error, ID3v2ErrorKind, Result};
 use crate::id3::v2::frame::{FrameFlags, FrameRef, FrameValue};
+use crate::id3::v2::items::key_value_frame::KeyValueFrame;
+use crate::id3::v2::items::timestamp_frame::TimestampFrame;
 use crate::id3::v2::util::synch_u32;
 
 use std::io::Write;
@@ -23,16 +25,22 @@ where
 	Ok(())
 }
 
-fn verify_frame(frame: &FrameRef<'_>) -> Result<()> {
+pub(in crate::id3::v2) fn verify_frame(frame: &FrameRef<'_>) -> Result<()> {
 	match (frame.id, frame.value.as_ref()) {
 		("APIC", FrameValue::Picture { .. })
 		| ("USLT", FrameValue::UnSyncText(_))
 		| ("COMM", FrameValue::Comment(_))
 		| ("TXXX", FrameValue::UserText(_))
 		| ("WXXX", FrameValue::UserURL(_))
+		| ("POPM", FrameValue::Popularimeter(_))
+		| ("SYLT", FrameValue::SynchronizedText(_))
+		| ("ETCO", FrameValue::EventTimingCodes(_))
+		| ("SYTC", FrameValue::SynchronizedTempo(_))
 		| (_, FrameValue::Binary(_))
 		| ("WFED" | "GRP1" | "MVNM" | "MVIN", FrameValue::Text { .. }) => Ok(()),
 		(id, FrameValue::Text { .. }) if id.starts_with('T') => Ok(()),
+		(id, FrameValue::Timestamp(_)) if TimestampFrame::FRAME_IDS.contains(&id) => Ok(()),
+		(id, FrameValue::KeyValue(_)) if KeyValueFrame::FRAME_IDS.contains(&id) => Ok(()),
 		(id, FrameValue::URL(_)) if id.starts_with('W') => Ok(()),
 		(id, frame_value) => Err(ID3v2Error::new(ID3v2ErrorKind::BadFrame(
 			id.to_string(),
@@ -45,6 +53,11 @@ fn verify_frame(frame: &FrameRef<'_>) -> Result<()> {
 				FrameValue::UserURL(_) => "UserURL",
 				FrameValue::Picture { .. } => "Picture",
 				FrameValue::Popularimeter(_) => "Popularimeter",
+				FrameValue::Timestamp(_) => "Timestamp",
+				FrameValue::KeyValue(_) => "KeyValue",
+				FrameValue::SynchronizedText(_) => "SynchronizedText",
+				FrameValue::EventTimingCodes(_) => "EventTimingCodes",
+				FrameValue::SynchronizedTempo(_) => "SynchronizedTempo",
 				FrameValue::Binary(_) => "Binary",
 			},
 		))
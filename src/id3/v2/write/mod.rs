@@ -1,13 +1,13 @@
 mod chunk_file;
-mod frame;
+pub(super) mod frame;
 
 use super::ID3v2TagFlags;
 use crate::error::Result;
 use crate::file::FileType;
-use crate::id3::find_id3v2;
 use crate::id3::v2::frame::FrameRef;
 use crate::id3::v2::tag::Id3v2TagRef;
 use crate::id3::v2::util::synch_u32;
+use crate::id3::{find_appended_id3v2, find_id3v2, ID3FindResults};
 use crate::macros::err;
 use crate::probe::Probe;
 
@@ -57,12 +57,30 @@ pub(crate) fn write_id3v2<'a, I: Iterator<Item = FrameRef<'a>> + 'a>(
 
 	let id3v2 = create_tag(tag)?;
 
+	// The tag is always written to the start of the file, so an appended tag has to be removed.
+	// Otherwise, its frames would replace those of the new tag when read.
+	let mut appended_tag = None;
+	if file_type == Some(FileType::MPEG) {
+		if let ID3FindResults(Some(header), offset) = find_appended_id3v2(data)? {
+			// Header (10) + Footer (10)
+			appended_tag = Some((offset, u64::from(header.size) + 20));
+		}
+	}
+
 	// find_id3v2 will seek us to the end of the tag
 	find_id3v2(data, false)?;
 
+	let tag_end = data.stream_position()?;
+
 	let mut file_bytes = Vec::new();
 	data.read_to_end(&mut file_bytes)?;
 
+	// The appended tag may be the same as the prepended one, if there is no audio in between
+	if let Some((offset, len)) = appended_tag.filter(|(offset, _)| *offset >= tag_end) {
+		let start = (offset - tag_end) as usize;
+		file_bytes.drain(start..start + len as usize);
+	}
+
 	file_bytes.splice(0..0, id3v2);
 
 	data.rewind()?;
@@ -92,6 +110,24 @@ pub(super) fn create_tag<'a, I: Iterator<Item = FrameRef<'a>> + 'a>(
 	let header_len = id3v2.get_ref().len();
 
 	// Write the items
+	#[cfg(feature = "id3v2_restrictions")]
+	if let Some(restrictions) = tag.flags.restrictions {
+		let footer_len = if has_footer { 10 } else { 0 };
+
+		// Each frame is verified as it is written, to find the one that exceeds the size limit
+		for (i, frame) in peek.enumerate() {
+			restrictions.verify_frame(i, &frame)?;
+
+			let id = frame.id;
+			frame::create_items(&mut id3v2, &mut std::iter::once(frame))?;
+
+			restrictions.verify_size(id3v2.get_ref().len() + footer_len, id)?;
+		}
+	} else {
+		frame::create_items(&mut id3v2, &mut peek)?;
+	}
+
+	#[cfg(not(feature = "id3v2_restrictions"))]
 	frame::create_items(&mut id3v2, &mut peek)?;
 
 	let len = id3v2.get_ref().len() - header_len;
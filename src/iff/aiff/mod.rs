@@ -3,10 +3,12 @@ mod read;
 
 #[cfg(feature = "id3v2")]
 use crate::id3::v2::tag::ID3v2Tag;
-use crate::properties::FileProperties;
 
 use lofty_attr::LoftyFile;
 
+// Exports
+pub use properties::{AiffCompressionType, AiffProperties};
+
 cfg_if::cfg_if! {
 	if #[cfg(feature = "aiff_text_chunks")] {
 		pub(crate) mod tag;
@@ -28,5 +30,5 @@ pub struct AiffFile {
 	#[lofty(tag_type = "ID3v2")]
 	pub(crate) id3v2_tag: Option<ID3v2Tag>,
 	/// The file's audio properties
-	pub(crate) properties: FileProperties,
+	pub(crate) properties: AiffProperties,
 }
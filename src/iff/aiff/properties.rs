@@ -7,12 +7,188 @@ use std::time::Duration;
 
 use byteorder::{BigEndian, ReadBytesExt};
 
+/// The compression type of an AIFF-C file
+///
+/// Plain AIFF files are never compressed, and have no compression type.
+#[allow(missing_docs, non_camel_case_types)]
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum AiffCompressionType {
+	/// `NONE`, big-endian PCM
+	None,
+	/// `twos`, big-endian PCM (as written by QuickTime)
+	TWOS,
+	/// `sowt`, little-endian PCM
+	SOWT,
+	/// `fl32`, 32-bit IEEE float
+	FL32,
+	/// `fl64`, 64-bit IEEE float
+	FL64,
+	/// `alaw`, ITU G.711 A-law
+	ALAW,
+	/// `ulaw`, ITU G.711 µ-law
+	ULAW,
+	/// `ima4`, Apple IMA ADPCM
+	IMA4,
+	/// `MAC3`, MACE 3:1
+	MAC3,
+	/// `MAC6`, MACE 6:1
+	MAC6,
+	/// `GSM `, GSM 6.10
+	GSM,
+	Other([u8; 4]),
+}
+
+impl AiffCompressionType {
+	fn from_fourcc(fourcc: [u8; 4]) -> Self {
+		match &fourcc {
+			b"NONE" => Self::None,
+			b"twos" => Self::TWOS,
+			b"sowt" => Self::SOWT,
+			b"fl32" | b"FL32" => Self::FL32,
+			b"fl64" | b"FL64" => Self::FL64,
+			b"alaw" | b"ALAW" => Self::ALAW,
+			b"ulaw" | b"ULAW" => Self::ULAW,
+			b"ima4" => Self::IMA4,
+			b"MAC3" => Self::MAC3,
+			b"MAC6" => Self::MAC6,
+			b"GSM " => Self::GSM,
+			_ => Self::Other(fourcc),
+		}
+	}
+
+	/// The compression type's four character code
+	pub fn fourcc(&self) -> [u8; 4] {
+		match self {
+			Self::None => *b"NONE",
+			Self::TWOS => *b"twos",
+			Self::SOWT => *b"sowt",
+			Self::FL32 => *b"fl32",
+			Self::FL64 => *b"fl64",
+			Self::ALAW => *b"alaw",
+			Self::ULAW => *b"ulaw",
+			Self::IMA4 => *b"ima4",
+			Self::MAC3 => *b"MAC3",
+			Self::MAC6 => *b"MAC6",
+			Self::GSM => *b"GSM ",
+			Self::Other(fourcc) => *fourcc,
+		}
+	}
+
+	// The number of samples (per channel) in each of the "sample frames" counted in the COMM chunk
+	//
+	// For packet based formats, the COMM chunk stores the number of packets.
+	fn samples_per_frame(self) -> u32 {
+		match self {
+			Self::IMA4 => 64,
+			Self::MAC3 | Self::MAC6 => 6,
+			Self::GSM => 160,
+			_ => 1,
+		}
+	}
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[non_exhaustive]
+/// An AIFF file's audio properties
+pub struct AiffProperties {
+	pub(crate) duration: Duration,
+	pub(crate) overall_bitrate: u32,
+	pub(crate) audio_bitrate: u32,
+	pub(crate) sample_rate: u32,
+	pub(crate) sample_size: u16,
+	pub(crate) channels: u16,
+	pub(crate) compression_type: Option<AiffCompressionType>,
+	pub(crate) compression_name: Option<String>,
+	pub(crate) format_version: Option<u32>,
+}
+
+impl From<AiffProperties> for FileProperties {
+	fn from(input: AiffProperties) -> Self {
+		Self {
+			duration: input.duration,
+			overall_bitrate: Some(input.overall_bitrate),
+			audio_bitrate: Some(input.audio_bitrate),
+			sample_rate: Some(input.sample_rate),
+			bit_depth: Some(input.bit_depth()),
+			channels: Some(input.channels as u8),
+		}
+	}
+}
+
+impl AiffProperties {
+	/// Duration
+	pub fn duration(&self) -> Duration {
+		self.duration
+	}
+
+	/// Overall bitrate (kbps)
+	pub fn overall_bitrate(&self) -> u32 {
+		self.overall_bitrate
+	}
+
+	/// Audio bitrate (kbps)
+	pub fn audio_bitrate(&self) -> u32 {
+		self.audio_bitrate
+	}
+
+	/// Sample rate (Hz)
+	pub fn sample_rate(&self) -> u32 {
+		self.sample_rate
+	}
+
+	/// The sample size, as stated in the COMM chunk
+	///
+	/// For compressed files, this is usually the size of the decoded samples. See [`AiffProperties::bit_depth`].
+	pub fn sample_size(&self) -> u16 {
+		self.sample_size
+	}
+
+	/// Bits per sample, as stored in the file
+	///
+	/// This differs from [`AiffProperties::sample_size`] for `fl32`, `fl64`, `alaw`, `ulaw`, and `ima4`.
+	pub fn bit_depth(&self) -> u8 {
+		match self.compression_type {
+			Some(AiffCompressionType::FL32) => 32,
+			Some(AiffCompressionType::FL64) => 64,
+			Some(AiffCompressionType::ALAW | AiffCompressionType::ULAW) => 8,
+			Some(AiffCompressionType::IMA4) => 4,
+			_ => self.sample_size as u8,
+		}
+	}
+
+	/// Channel count
+	pub fn channels(&self) -> u16 {
+		self.channels
+	}
+
+	/// The compression type (AIFF-C only)
+	pub fn compression_type(&self) -> Option<&AiffCompressionType> {
+		self.compression_type.as_ref()
+	}
+
+	/// The human-readable name of the compression type, as stored in the file (AIFF-C only)
+	pub fn compression_name(&self) -> Option<&str> {
+		self.compression_name.as_deref()
+	}
+
+	/// The AIFF-C version, from the `FVER` chunk (AIFF-C only)
+	///
+	/// The only defined version is `0xA2805140` (AIFF-C Version 1).
+	pub fn format_version(&self) -> Option<u32> {
+		self.format_version
+	}
+}
+
 pub(super) fn read_properties(
 	comm: &mut &[u8],
+	compressed: bool,
+	format_version: Option<u32>,
 	stream_len: u32,
 	file_length: u64,
-) -> Result<FileProperties> {
-	let channels = comm.read_u16::<BigEndian>()? as u8;
+) -> Result<AiffProperties> {
+	let channels = comm.read_u16::<BigEndian>()?;
 
 	if channels == 0 {
 		decode_err!(@BAIL AIFF, "File contains 0 channels");
@@ -50,24 +226,49 @@ pub(super) fn read_properties(
 
 	let sample_rate = float.round() as u32;
 
-	let (duration, overall_bitrate, audio_bitrate) = if sample_rate > 0 && sample_frames > 0 {
-		let length = (f64::from(sample_frames) * 1000.0) / f64::from(sample_rate);
+	let mut compression_type = None;
+	let mut compression_name = None;
+
+	if compressed {
+		if comm.len() < 4 {
+			decode_err!(@BAIL AIFF, "AIFC file has an invalid \"COMM\" chunk size (< 22)");
+		}
+
+		let mut fourcc = [0; 4];
+		comm.read_exact(&mut fourcc)?;
+		compression_type = Some(AiffCompressionType::from_fourcc(fourcc));
+
+		// The compression name is a pstring, which some encoders leave out
+		if let Some((&len, name)) = comm.split_first() {
+			let name = &name[..name.len().min(usize::from(len))];
+			compression_name = Some(name.iter().map(|c| char::from(*c)).collect::<String>());
+		}
+	}
+
+	let samples_per_frame = compression_type.map_or(1, AiffCompressionType::samples_per_frame);
+	let total_samples = u64::from(sample_frames) * u64::from(samples_per_frame);
+
+	let (duration, overall_bitrate, audio_bitrate) = if sample_rate > 0 && total_samples > 0 {
+		let length = (total_samples as f64 * 1000.0) / f64::from(sample_rate);
 
 		(
 			Duration::from_millis(length as u64),
-			Some(((file_length as f64) * 8.0 / length + 0.5) as u32),
-			Some((f64::from(stream_len) * 8.0 / length + 0.5) as u32),
+			((file_length as f64) * 8.0 / length + 0.5) as u32,
+			(f64::from(stream_len) * 8.0 / length + 0.5) as u32,
 		)
 	} else {
-		(Duration::ZERO, None, None)
+		(Duration::ZERO, 0, 0)
 	};
 
-	Ok(FileProperties {
+	Ok(AiffProperties {
 		duration,
 		overall_bitrate,
 		audio_bitrate,
-		sample_rate: Some(sample_rate),
-		bit_depth: Some(sample_size as u8),
-		channels: Some(channels),
+		sample_rate,
+		sample_size,
+		channels,
+		compression_type,
+		compression_name,
+		format_version,
 	})
 }
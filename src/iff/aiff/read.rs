@@ -1,3 +1,4 @@
+use super::properties::AiffProperties;
 #[cfg(feature = "aiff_text_chunks")]
 use super::tag::{AIFFTextChunks, Comment};
 use super::AiffFile;
@@ -7,7 +8,6 @@ use crate::id3::v2::tag::ID3v2Tag;
 use crate::iff::chunk::Chunks;
 use crate::macros::{decode_err, err};
 use crate::probe::ParseOptions;
-use crate::properties::FileProperties;
 
 use std::io::{Read, Seek, SeekFrom};
 
@@ -15,7 +15,8 @@ use byteorder::BigEndian;
 #[cfg(feature = "aiff_text_chunks")]
 use byteorder::ReadBytesExt;
 
-pub(in crate::iff) fn verify_aiff<R>(data: &mut R) -> Result<()>
+// Returns the form type, either "AIFF" or "AIFC"
+pub(in crate::iff) fn verify_aiff<R>(data: &mut R) -> Result<[u8; 4]>
 where
 	R: Read + Seek,
 {
@@ -26,7 +27,7 @@ where
 		err!(UnknownFormat);
 	}
 
-	Ok(())
+	Ok([id[8], id[9], id[10], id[11]])
 }
 
 pub(crate) fn read_from<R>(data: &mut R, parse_options: ParseOptions) -> Result<AiffFile>
@@ -35,7 +36,7 @@ where
 {
 	// TODO: Maybe one day the `Seek` bound can be removed?
 	// let file_size = verify_aiff(data)?;
-	verify_aiff(data)?;
+	let form_type = verify_aiff(data)?;
 
 	let current_pos = data.stream_position()?;
 	let file_len = data.seek(SeekFrom::End(0))?;
@@ -43,6 +44,7 @@ where
 	data.seek(SeekFrom::Start(current_pos))?;
 
 	let mut comm = None;
+	let mut format_version = None;
 	let mut stream_len = 0;
 
 	#[cfg(feature = "aiff_text_chunks")]
@@ -60,7 +62,7 @@ where
 	while chunks.next(data).is_ok() {
 		match &chunks.fourcc {
 			#[cfg(feature = "id3v2")]
-			b"ID3 " | b"id3 " => id3v2_tag = Some(chunks.id3_chunk(data)?),
+			b"ID3 " | b"id3 " => id3v2_tag = Some(chunks.id3_chunk(data, parse_options.parsing_mode)?),
 			b"COMM" if parse_options.read_properties && comm.is_none() => {
 				if chunks.size < 18 {
 					decode_err!(@BAIL AIFF, "File has an invalid \"COMM\" chunk size (< 18)");
@@ -69,6 +71,15 @@ where
 				comm = Some(chunks.content(data)?);
 				chunks.correct_position(data)?;
 			},
+			b"FVER" if parse_options.read_properties && format_version.is_none() => {
+				if chunks.size < 4 {
+					decode_err!(@BAIL AIFF, "File has an invalid \"FVER\" chunk size (< 4)");
+				}
+
+				let fver = chunks.content(data)?;
+				format_version = Some(u32::from_be_bytes([fver[0], fver[1], fver[2], fver[3]]));
+				chunks.correct_position(data)?;
+			},
 			b"SSND" if parse_options.read_properties => {
 				stream_len = chunks.size;
 				chunks.skip(data)?;
@@ -140,6 +151,8 @@ where
 
 				properties = super::properties::read_properties(
 					&mut &*comm,
+					&form_type == b"AIFC",
+					format_version,
 					stream_len,
 					data.stream_position()?,
 				)?;
@@ -147,7 +160,7 @@ where
 			None => decode_err!(@BAIL AIFF, "File does not contain a \"COMM\" chunk"),
 		}
 	} else {
-		properties = FileProperties::default();
+		properties = AiffProperties::default();
 	};
 
 	Ok(AiffFile {
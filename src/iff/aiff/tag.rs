@@ -235,6 +235,8 @@ impl From<AIFFTextChunks> for Tag {
 
 impl From<Tag> for AIFFTextChunks {
 	fn from(mut input: Tag) -> Self {
+		input.convert_typed_items_to_text();
+
 		let name = input.take_strings(&ItemKey::TrackTitle).next();
 		let author = input.take_strings(&ItemKey::TrackArtist).next();
 		let copyright = input.take_strings(&ItemKey::CopyrightMessage).next();
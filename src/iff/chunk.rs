@@ -93,7 +93,11 @@ impl<B: ByteOrder> Chunks<B> {
 	}
 
 	#[cfg(feature = "id3v2")]
-	pub fn id3_chunk<R>(&mut self, data: &mut R) -> Result<ID3v2Tag>
+	pub fn id3_chunk<R>(
+		&mut self,
+		data: &mut R,
+		parse_mode: crate::probe::ParsingMode,
+	) -> Result<ID3v2Tag>
 	where
 		R: Read + Seek,
 	{
@@ -105,7 +109,7 @@ impl<B: ByteOrder> Chunks<B> {
 		let reader = &mut &*content;
 
 		let header = read_id3v2_header(reader)?;
-		let id3v2 = parse_id3v2(reader, header)?;
+		let id3v2 = parse_id3v2(reader, header, parse_mode)?;
 
 		// Skip over the footer
 		if id3v2.flags().footer {
@@ -7,8 +7,8 @@ pub(crate) mod wav;
 
 // Exports
 
-pub use aiff::AiffFile;
-pub use wav::{WavFile, WavFormat, WavProperties};
+pub use aiff::{AiffCompressionType, AiffFile, AiffProperties};
+pub use wav::{ChannelMask, Speaker, WavFile, WavFormat, WavProperties};
 
 cfg_if::cfg_if! {
 	if #[cfg(feature = "aiff_text_chunks")] {
@@ -14,7 +14,7 @@ cfg_if::cfg_if! {
 }
 
 // Exports
-pub use crate::iff::wav::properties::{WavFormat, WavProperties};
+pub use crate::iff::wav::properties::{ChannelMask, Speaker, WavFormat, WavProperties};
 
 /// A WAV file
 #[derive(LoftyFile)]
@@ -2,30 +2,171 @@ use crate::error::Result;
 use crate::macros::decode_err;
 use crate::properties::FileProperties;
 
+use std::io::Read;
 use std::time::Duration;
 
 use byteorder::{LittleEndian, ReadBytesExt};
 
 const PCM: u16 = 0x0001;
+const ADPCM: u16 = 0x0002;
 const IEEE_FLOAT: u16 = 0x0003;
+const ALAW: u16 = 0x0006;
+const MULAW: u16 = 0x0007;
+const IMA_ADPCM: u16 = 0x0011;
+const GSM610: u16 = 0x0031;
+const MPEG: u16 = 0x0050;
+const MPEGLAYER3: u16 = 0x0055;
 const EXTENSIBLE: u16 = 0xFFFE;
 
+// Every `KSDATAFORMAT_SUBTYPE_*` GUID for a format tag is `XXXXXXXX-0000-0010-8000-00AA00389B71`,
+// with the tag in the first 2 bytes. These are the remaining 14 bytes.
+const SUBFORMAT_GUID_SUFFIX: [u8; 14] = [
+	0x00, 0x00, 0x00, 0x00, 0x10, 0x00, 0x80, 0x00, 0x00, 0xAA, 0x00, 0x38, 0x9B, 0x71,
+];
+
 #[allow(missing_docs, non_camel_case_types)]
 #[derive(Debug, Copy, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[non_exhaustive]
 /// A WAV file's format
+///
+/// For `WAVE_FORMAT_EXTENSIBLE` files, this is the format described by the subformat GUID.
+/// If the GUID isn't for a known format tag, this will be `WavFormat::Other(0xFFFE)`.
 pub enum WavFormat {
 	PCM,
 	IEEE_FLOAT,
+	/// Microsoft ADPCM
+	ADPCM,
+	/// IMA/DVI ADPCM
+	IMA_ADPCM,
+	/// ITU G.711 A-law
+	ALAW,
+	/// ITU G.711 µ-law
+	MULAW,
+	/// GSM 6.10
+	GSM610,
+	/// MPEG-1 Layer I/II
+	MPEG,
+	/// MPEG-1 Layer III
+	MPEGLAYER3,
 	Other(u16),
 }
 
+impl WavFormat {
+	fn from_tag(tag: u16) -> Self {
+		match tag {
+			PCM => Self::PCM,
+			ADPCM => Self::ADPCM,
+			IEEE_FLOAT => Self::IEEE_FLOAT,
+			ALAW => Self::ALAW,
+			MULAW => Self::MULAW,
+			IMA_ADPCM => Self::IMA_ADPCM,
+			GSM610 => Self::GSM610,
+			MPEG => Self::MPEG,
+			MPEGLAYER3 => Self::MPEGLAYER3,
+			other => Self::Other(other),
+		}
+	}
+
+	/// Whether the format stores each sample in a fixed number of bits
+	///
+	/// The duration of all other formats can only be known from the `fact` chunk.
+	pub fn is_uncompressed(&self) -> bool {
+		matches!(
+			self,
+			Self::PCM | Self::IEEE_FLOAT | Self::ALAW | Self::MULAW
+		)
+	}
+}
+
 impl Default for WavFormat {
 	fn default() -> Self {
 		Self::Other(0)
 	}
 }
 
+/// A speaker position, as used in a [`ChannelMask`]
+#[allow(missing_docs)]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+#[repr(u32)]
+pub enum Speaker {
+	FrontLeft = 0x1,
+	FrontRight = 0x2,
+	FrontCenter = 0x4,
+	LowFrequency = 0x8,
+	BackLeft = 0x10,
+	BackRight = 0x20,
+	FrontLeftOfCenter = 0x40,
+	FrontRightOfCenter = 0x80,
+	BackCenter = 0x100,
+	SideLeft = 0x200,
+	SideRight = 0x400,
+	TopCenter = 0x800,
+	TopFrontLeft = 0x1000,
+	TopFrontCenter = 0x2000,
+	TopFrontRight = 0x4000,
+	TopBackLeft = 0x8000,
+	TopBackCenter = 0x10000,
+	TopBackRight = 0x20000,
+}
+
+impl Speaker {
+	/// Every speaker position, in channel order
+	pub const ALL: [Speaker; 18] = [
+		Self::FrontLeft,
+		Self::FrontRight,
+		Self::FrontCenter,
+		Self::LowFrequency,
+		Self::BackLeft,
+		Self::BackRight,
+		Self::FrontLeftOfCenter,
+		Self::FrontRightOfCenter,
+		Self::BackCenter,
+		Self::SideLeft,
+		Self::SideRight,
+		Self::TopCenter,
+		Self::TopFrontLeft,
+		Self::TopFrontCenter,
+		Self::TopFrontRight,
+		Self::TopBackLeft,
+		Self::TopBackCenter,
+		Self::TopBackRight,
+	];
+}
+
+/// The speaker positions of the channels in a `WAVE_FORMAT_EXTENSIBLE` file
+///
+/// WavPack files use the same mask.
+///
+/// Channels are stored in the order of [`Speaker::ALL`], skipping those not present in the mask.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ChannelMask(pub(crate) u32);
+
+impl ChannelMask {
+	/// The raw mask
+	pub fn bits(&self) -> u32 {
+		self.0
+	}
+
+	/// Whether the mask contains a speaker
+	pub fn contains(&self, speaker: Speaker) -> bool {
+		self.0 & speaker as u32 != 0
+	}
+
+	/// The speaker positions in the mask, in channel order
+	///
+	/// NOTE: Any reserved bits in the mask are ignored
+	pub fn speakers(&self) -> impl Iterator<Item = Speaker> {
+		let mask = *self;
+		Speaker::ALL
+			.into_iter()
+			.filter(move |speaker| mask.contains(*speaker))
+	}
+}
+
 #[derive(Debug, Copy, Clone, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[non_exhaustive]
 /// A WAV file's audio properties
 pub struct WavProperties {
@@ -36,6 +177,9 @@ pub struct WavProperties {
 	pub(crate) sample_rate: u32,
 	pub(crate) bit_depth: u8,
 	pub(crate) channels: u8,
+	pub(crate) valid_bits_per_sample: Option<u16>,
+	pub(crate) channel_mask: Option<ChannelMask>,
+	pub(crate) subformat: Option<[u8; 16]>,
 }
 
 impl From<WavProperties> for FileProperties {
@@ -86,6 +230,26 @@ impl WavProperties {
 	pub fn format(&self) -> &WavFormat {
 		&self.format
 	}
+
+	/// The number of bits per sample that hold audio data (`WAVE_FORMAT_EXTENSIBLE` only)
+	///
+	/// This may be less than the container size, in which case it is used as the bit depth.
+	pub fn valid_bits_per_sample(&self) -> Option<u16> {
+		self.valid_bits_per_sample
+	}
+
+	/// The speaker positions of the channels (`WAVE_FORMAT_EXTENSIBLE` only)
+	pub fn channel_mask(&self) -> Option<ChannelMask> {
+		self.channel_mask
+	}
+
+	/// The raw subformat GUID (`WAVE_FORMAT_EXTENSIBLE` only)
+	///
+	/// This is mostly useful when the format is [`WavFormat::Other`], as known subformats
+	/// are already resolved in [`WavProperties::format`].
+	pub fn subformat(&self) -> Option<[u8; 16]> {
+		self.subformat
+	}
 }
 
 pub(super) fn read_properties(
@@ -115,6 +279,10 @@ pub(super) fn read_properties(
 		(bytes_per_sample * 8) as u8
 	};
 
+	let mut valid_bits_per_sample = None;
+	let mut channel_mask = None;
+	let mut subformat = None;
+
 	if format_tag == EXTENSIBLE {
 		if fmt.len() + 16 < 40 {
 			decode_err!(@BAIL WAV, "Extensible format identified, invalid \"fmt \" chunk size found (< 40)");
@@ -123,26 +291,47 @@ pub(super) fn read_properties(
 		// cbSize (Size of extra format information) (2)
 		let _cb_size = fmt.read_u16::<LittleEndian>()?;
 		// Valid bits per sample (2)
-		let valid_bits_per_sample = fmt.read_u16::<LittleEndian>()?;
+		let valid_bits = fmt.read_u16::<LittleEndian>()?;
 		// Channel mask (4)
-		let _channel_mask = fmt.read_u32::<LittleEndian>()?;
+		channel_mask = Some(ChannelMask(fmt.read_u32::<LittleEndian>()?));
 
-		if valid_bits_per_sample > 0 {
-			bit_depth = valid_bits_per_sample as u8;
+		if valid_bits > 0 {
+			bit_depth = valid_bits as u8;
 		}
-		format_tag = fmt.read_u16::<LittleEndian>()?;
-	}
+		valid_bits_per_sample = Some(valid_bits);
 
-	let non_pcm = format_tag != PCM && format_tag != IEEE_FLOAT;
+		// Subformat GUID (16)
+		let mut guid = [0; 16];
+		fmt.read_exact(&mut guid)?;
 
-	if non_pcm && total_samples == 0 {
-		decode_err!(@BAIL WAV, "Non-PCM format identified, no \"fact\" chunk found");
+		format_tag = if guid[2..] == SUBFORMAT_GUID_SUFFIX {
+			u16::from_le_bytes([guid[0], guid[1]])
+		} else {
+			EXTENSIBLE
+		};
+		subformat = Some(guid);
 	}
 
-	if bits_per_sample > 0 {
-		total_samples = stream_len / u32::from(u16::from(channels) * ((bits_per_sample + 7) / 8))
-	} else if !non_pcm {
-		total_samples = 0
+	let format = WavFormat::from_tag(format_tag);
+
+	if format.is_uncompressed() {
+		if bits_per_sample > 0 {
+			total_samples =
+				stream_len / u32::from(u16::from(channels) * ((bits_per_sample + 7) / 8))
+		} else {
+			total_samples = 0
+		}
+	} else if total_samples == 0 {
+		// Unknown subformats (such as Ambisonic B-Format) are usually PCM, so the sample count is
+		// estimated from the block size
+		if format_tag == EXTENSIBLE {
+			if block_align > 0 {
+				total_samples = stream_len / u32::from(block_align);
+			}
+		} else {
+			// Compressed formats don't have a fixed sample size, so the sample count has to come from the "fact" chunk
+			decode_err!(@BAIL WAV, "Non-PCM format identified, no \"fact\" chunk found");
+		}
 	}
 
 	let (duration, overall_bitrate, audio_bitrate) = if sample_rate > 0 && total_samples > 0 {
@@ -151,7 +340,7 @@ pub(super) fn read_properties(
 			(Duration::ZERO, 0, 0)
 		} else {
 			let overall_bitrate = ((file_length * 8) / length) as u32;
-			let audio_bitrate = (u64::from(stream_len * 8) / length) as u32;
+			let audio_bitrate = ((u64::from(stream_len) * 8) / length) as u32;
 
 			(
 				Duration::from_millis(length),
@@ -175,16 +364,15 @@ pub(super) fn read_properties(
 	};
 
 	Ok(WavProperties {
-		format: match format_tag {
-			PCM => WavFormat::PCM,
-			IEEE_FLOAT => WavFormat::IEEE_FLOAT,
-			other => WavFormat::Other(other),
-		},
+		format,
 		duration,
 		overall_bitrate,
 		audio_bitrate,
 		sample_rate,
 		bit_depth,
 		channels,
+		valid_bits_per_sample,
+		channel_mask,
+		subformat,
 	})
 }
@@ -93,7 +93,7 @@ where
 				}
 			},
 			#[cfg(feature = "id3v2")]
-			b"ID3 " | b"id3 " => id3v2_tag = Some(chunks.id3_chunk(data)?),
+			b"ID3 " | b"id3 " => id3v2_tag = Some(chunks.id3_chunk(data, parse_options.parsing_mode)?),
 			_ => chunks.skip(data)?,
 		}
 	}
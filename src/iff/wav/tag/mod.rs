@@ -3,6 +3,9 @@ mod write;
 
 use crate::error::{LoftyError, Result};
 use crate::tag::item::{ItemKey, ItemValue, TagItem};
+use crate::tag::rating::Rating;
+use crate::tag::timestamp::Timestamp;
+use crate::tag::utils;
 use crate::tag::{Tag, TagType};
 use crate::traits::{Accessor, TagExt};
 
@@ -27,7 +30,24 @@ macro_rules! impl_accessor {
 				}
 			)+
 		}
-	}
+	};
+	(@TYPED $($name:ident<$ty:ty> => $key:literal, $parse:path, $format:path;)+) => {
+		paste::paste! {
+			$(
+				fn $name(&self) -> Option<$ty> {
+					self.get($key).and_then($parse)
+				}
+
+				fn [<set_ $name>](&mut self, value: $ty) {
+					self.insert(String::from($key), $format(value))
+				}
+
+				fn [<remove_ $name>](&mut self) {
+					self.remove($key)
+				}
+			)+
+		}
+	};
 }
 
 #[derive(Default, Debug, PartialEq, Eq, Clone)]
@@ -92,11 +112,18 @@ impl RIFFInfoList {
 
 impl Accessor for RIFFInfoList {
 	impl_accessor!(
-		artist  => "IART";
-		title   => "INAM";
-		album   => "IPRD";
-		genre   => "IGNR";
-		comment => "ICMT";
+		artist   => "IART";
+		title    => "INAM";
+		album    => "IPRD";
+		genre    => "IGNR";
+		comment  => "ICMT";
+		composer => "IMUS";
+	);
+
+	impl_accessor!(
+		@TYPED
+		date<Timestamp> => "ICRD", utils::parse_date, utils::format_date;
+		rating<Rating>  => "IRTD", utils::parse_rating, utils::format_rating;
 	);
 
 	fn track(&self) -> Option<u32> {
@@ -184,7 +211,9 @@ impl From<RIFFInfoList> for Tag {
 }
 
 impl From<Tag> for RIFFInfoList {
-	fn from(input: Tag) -> Self {
+	fn from(mut input: Tag) -> Self {
+		input.convert_typed_items_to_text();
+
 		let mut riff_info = RIFFInfoList::default();
 
 		for item in input.items {
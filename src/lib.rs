@@ -105,12 +105,14 @@
 //! * `ape`
 //! * `id3v1`
 //! * `id3v2`
+//! * `lyrics3`
 //! * `mp4_ilst`
 //! * `riff_info_list`
 //! * `vorbis_comments`
 //!
 //! ## Utilities
-//! * `id3v2_restrictions` - Parses ID3v2 extended headers and exposes flags for fine grained control
+//! * `id3v2_restrictions` - Parses ID3v2 extended headers and exposes flags for fine grained control, enforcing restrictions when writing
+//! * `serde` - Implements `Serialize`/`Deserialize` for tags, pictures, and properties. Picture data is base64 encoded in human-readable formats, and can be left out with [`WithoutPictureData`]
 //!
 //! # Important format-specific notes
 //!
@@ -167,12 +169,15 @@
 extern crate self as lofty;
 
 pub mod ape;
+pub mod diff;
 pub mod error;
+pub mod export;
 pub(crate) mod file;
 pub mod flac;
 pub mod id3;
 pub mod iff;
 pub(crate) mod macros;
+pub mod merge;
 pub mod mp4;
 pub mod mpeg;
 pub mod ogg;
@@ -190,14 +195,18 @@ pub use crate::error::{LoftyError, Result};
 pub use crate::probe::{read_from, read_from_path, ParseOptions, ParsingMode, Probe};
 
 pub use crate::file::{AudioFile, FileType, TaggedFile};
+#[cfg(feature = "serde")]
+pub use crate::picture::WithoutPictureData;
 pub use crate::picture::{MimeType, Picture, PictureType};
 pub use crate::properties::FileProperties;
+pub use crate::tag::rating::Rating;
+pub use crate::tag::timestamp::Timestamp;
 pub use crate::tag::{Tag, TagType};
 pub use tag::item::{ItemKey, ItemValue, TagItem};
 
 pub use crate::traits::{Accessor, TagExt};
 
-#[cfg(feature = "vorbis_comments")]
+#[cfg(any(feature = "vorbis_comments", feature = "id3v2_restrictions"))]
 pub use picture::PictureInformation;
 
 // TODO: https://github.com/rust-lang/rust/issues/88581
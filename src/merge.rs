@@ -0,0 +1,501 @@
+//! Tools to copy items between tags
+//!
+//! Unlike [`Tag::re_map`], which drops any items without a mapping for the new [`TagType`],
+//! [`Tag::merge`] can store these items in custom fields, and reports anything it
+//! could not carry over.
+//!
+//! # Examples
+//!
+//! ```rust
+//! use lofty::merge::{MergeOptions, MergePolicy, UnmappedItems};
+//! use lofty::{Accessor, ItemKey, ItemValue, Tag, TagItem, TagType};
+//!
+//! let mut vorbis_comments = Tag::new(TagType::VorbisComments);
+//! vorbis_comments.set_title(String::from("Foo title"));
+//! vorbis_comments.insert_item_unchecked(TagItem::new(
+//! 	ItemKey::Unknown(String::from("MY_MOOD")),
+//! 	ItemValue::Text(String::from("Happy")),
+//! ));
+//!
+//! let mut id3v2 = Tag::new(TagType::ID3v2);
+//! id3v2.set_title(String::from("Bar title"));
+//!
+//! let report = id3v2.merge(
+//! 	&vorbis_comments,
+//! 	MergeOptions::new()
+//! 		.policy(MergePolicy::FillMissing)
+//! 		.unmapped_items(UnmappedItems::Custom),
+//! );
+//!
+//! // The existing title was kept, and the unmapped item will be written to a `TXXX` frame
+//! assert!(report.is_empty());
+//! assert_eq!(id3v2.title(), Some("Bar title"));
+//! assert_eq!(id3v2.get_string(&ItemKey::Unknown(String::from("MY_MOOD"))), Some("Happy"));
+//! ```
+
+use crate::picture::{Picture, PictureType};
+use crate::tag::item::{ItemKey, ItemValue, TagItem};
+use crate::tag::{Tag, TagType};
+
+/// How to handle items that already exist in the target tag
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum MergePolicy {
+	/// Replace all of the target's values for a key with those from the source
+	Overwrite,
+	/// Only copy items for keys that the target does not have
+	FillMissing,
+	/// Append the source's values to the target's, skipping any duplicate values
+	Append,
+}
+
+/// How to handle items that have no mapping in the target's [`TagType`]
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum UnmappedItems {
+	/// Report the items as unmapped
+	Discard,
+	/// Store the items in a custom field, if the format supports them
+	///
+	/// The custom field is named after the item's Vorbis Comments key if it has one
+	/// (such as `BARCODE`), otherwise its key in the source tag is used.
+	///
+	/// | Format          | Custom field                         |
+	/// |-----------------|--------------------------------------|
+	/// | ID3v2           | `TXXX` frame, with the name as the description |
+	/// | MP4             | `----:com.apple.iTunes:<name>` freeform atom |
+	/// | Vorbis Comments | `<name>`                             |
+	/// | APE             | `<name>`                             |
+	///
+	/// All other formats have no custom fields, and the items will be reported as unmapped. The same
+	/// goes for ID3v2 when the name is a valid frame ID (such as `MOOD`), as it would be written as that frame.
+	Custom,
+}
+
+/// Options to control how tags are merged
+#[derive(Copy, Clone, Debug)]
+#[non_exhaustive]
+pub struct MergeOptions {
+	pub(crate) policy: MergePolicy,
+	pub(crate) unmapped_items: UnmappedItems,
+}
+
+impl Default for MergeOptions {
+	/// The default implementation for `MergeOptions`
+	///
+	/// The defaults are as follows:
+	///
+	/// ```rust,ignore
+	/// MergeOptions {
+	/// 	policy: MergePolicy::Overwrite,
+	/// 	unmapped_items: UnmappedItems::Discard,
+	/// }
+	/// ```
+	fn default() -> Self {
+		Self {
+			policy: MergePolicy::Overwrite,
+			unmapped_items: UnmappedItems::Discard,
+		}
+	}
+}
+
+impl MergeOptions {
+	/// Creates a new `MergeOptions`, alias for `Default` implementation
+	///
+	/// See also: [`MergeOptions::default`]
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use lofty::merge::MergeOptions;
+	///
+	/// let merge_options = MergeOptions::new();
+	/// ```
+	pub fn new() -> Self {
+		Self::default()
+	}
+
+	/// How to handle items that already exist in the target tag
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use lofty::merge::{MergeOptions, MergePolicy};
+	///
+	/// // Keep all of the target's existing items
+	/// let merge_options = MergeOptions::new().policy(MergePolicy::FillMissing);
+	/// ```
+	pub fn policy(&mut self, policy: MergePolicy) -> Self {
+		self.policy = policy;
+		*self
+	}
+
+	/// How to handle items that have no mapping in the target's [`TagType`]
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use lofty::merge::{MergeOptions, UnmappedItems};
+	///
+	/// // Store unmapped items in TXXX frames, freeform atoms, etc.
+	/// let merge_options = MergeOptions::new().unmapped_items(UnmappedItems::Custom);
+	/// ```
+	pub fn unmapped_items(&mut self, unmapped_items: UnmappedItems) -> Self {
+		self.unmapped_items = unmapped_items;
+		*self
+	}
+}
+
+/// The items and pictures that could not be carried over in a merge
+///
+/// See [`Tag::merge`]
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct MergeReport {
+	pub(crate) unmapped_items: Vec<TagItem>,
+	pub(crate) unsupported_pictures: Vec<PictureType>,
+}
+
+impl MergeReport {
+	/// Returns the items that have no mapping (or custom field) in the target's [`TagType`]
+	pub fn unmapped_items(&self) -> &[TagItem] {
+		&self.unmapped_items
+	}
+
+	/// Returns the types of the pictures that were not copied, as the target's [`TagType`] doesn't support pictures
+	pub fn unsupported_pictures(&self) -> &[PictureType] {
+		&self.unsupported_pictures
+	}
+
+	/// Whether everything was carried over
+	pub fn is_empty(&self) -> bool {
+		self.unmapped_items.is_empty() && self.unsupported_pictures.is_empty()
+	}
+}
+
+pub(crate) fn merge_tags(target: &mut Tag, source: &Tag, options: MergeOptions) -> MergeReport {
+	let mut report = MergeReport::default();
+
+	// Group the source values by the keys they will be stored under in the target
+	let mut grouped: Vec<(ItemKey, Vec<&ItemValue>)> = Vec::new();
+	for item in source.items() {
+		let key = match target_key(item, source.tag_type(), target.tag_type(), options) {
+			Some(key) => key,
+			None => {
+				report.unmapped_items.push(item.clone());
+				continue;
+			},
+		};
+
+		match grouped.iter_mut().find(|(k, _)| k == &key) {
+			Some((_, values)) => values.push(item.value()),
+			None => grouped.push((key, vec![item.value()])),
+		}
+	}
+
+	for (key, values) in grouped {
+		let exists = target.items.iter().any(|item| item.key() == &key);
+		match options.policy {
+			MergePolicy::FillMissing if exists => continue,
+			MergePolicy::Overwrite => target.remove_key(&key),
+			_ => {},
+		}
+
+		for value in values {
+			let duplicate = target
+				.items
+				.iter()
+				.any(|item| item.key() == &key && item.value() == value);
+
+			if !duplicate {
+				target.items.push(TagItem::new(key.clone(), value.clone()));
+			}
+		}
+	}
+
+	if !supports_pictures(target.tag_type()) {
+		report.unsupported_pictures = source.pictures().iter().map(Picture::pic_type).collect();
+		return report;
+	}
+
+	// Pictures are grouped by type, so multiple pictures of the same type are carried over together
+	let mut grouped_pictures: Vec<(PictureType, Vec<&Picture>)> = Vec::new();
+	for picture in source.pictures() {
+		match grouped_pictures
+			.iter_mut()
+			.find(|(pic_type, _)| *pic_type == picture.pic_type())
+		{
+			Some((_, pictures)) => pictures.push(picture),
+			None => grouped_pictures.push((picture.pic_type(), vec![picture])),
+		}
+	}
+
+	for (pic_type, pictures) in grouped_pictures {
+		let exists = target.pictures().iter().any(|p| p.pic_type() == pic_type);
+		match options.policy {
+			MergePolicy::FillMissing if exists => continue,
+			MergePolicy::Overwrite => target.remove_picture_type(pic_type),
+			_ => {},
+		}
+
+		for picture in pictures {
+			if !target.pictures().contains(picture) {
+				target.push_picture(picture.clone());
+			}
+		}
+	}
+
+	report
+}
+
+// The key an item will be stored under in the target, if it can be represented
+fn target_key(
+	item: &TagItem,
+	source_type: TagType,
+	target_type: TagType,
+	options: MergeOptions,
+) -> Option<ItemKey> {
+	if item.re_map(target_type) {
+		return Some(item.key().clone());
+	}
+
+	if options.unmapped_items == UnmappedItems::Discard {
+		return None;
+	}
+
+	let name = custom_field_name(item.key(), source_type)?;
+	match target_type {
+		// A name that looks like a frame ID would be written as that frame, rather than a `TXXX` frame
+		TagType::ID3v2 if is_frame_id(name) => None,
+		TagType::ID3v2 | TagType::VorbisComments | TagType::APE => {
+			Some(ItemKey::Unknown(name.to_string()))
+		},
+		TagType::MP4ilst => Some(ItemKey::Unknown(format!("----:com.apple.iTunes:{name}"))),
+		_ => None,
+	}
+}
+
+fn custom_field_name(key: &ItemKey, source_type: TagType) -> Option<&str> {
+	let name = key
+		.map_key(TagType::VorbisComments, false)
+		.or_else(|| key.map_key(source_type, true))?;
+
+	// MP4 freeform atoms are named by the part after the mean ("----:com.apple.iTunes:NAME")
+	let name = match name.strip_prefix("----:") {
+		Some(freeform) => freeform.rsplit(':').next().unwrap_or(freeform),
+		None => name,
+	};
+
+	// Vorbis Comments and APE keys are limited to printable ASCII, and can't contain '='
+	let valid = (2..=255).contains(&name.len())
+		&& name
+			.bytes()
+			.all(|b| (0x20..=0x7D).contains(&b) && b != b'=');
+
+	valid.then(|| name)
+}
+
+fn is_frame_id(name: &str) -> bool {
+	name.len() == 4
+		&& name
+			.bytes()
+			.all(|b| b.is_ascii_uppercase() || b.is_ascii_digit())
+}
+
+fn supports_pictures(tag_type: TagType) -> bool {
+	matches!(
+		tag_type,
+		TagType::APE | TagType::ID3v2 | TagType::MP4ilst | TagType::VorbisComments
+	)
+}
+
+#[cfg(test)]
+mod tests {
+	use super::{MergeOptions, MergePolicy, UnmappedItems};
+	use crate::{
+		Accessor, ItemKey, ItemValue, MimeType, Picture, PictureType, Tag, TagItem, TagType,
+	};
+
+	fn source_tag() -> Tag {
+		let mut tag = Tag::new(TagType::VorbisComments);
+		tag.set_title(String::from("Foo title"));
+		tag.push_item(TagItem::new(
+			ItemKey::TrackArtist,
+			ItemValue::Text(String::from("Foo artist")),
+		));
+		tag.push_item(TagItem::new(
+			ItemKey::TrackArtist,
+			ItemValue::Text(String::from("Bar artist")),
+		));
+		tag.insert_text(ItemKey::CatalogNumber, String::from("0123456789"));
+		tag.insert_item_unchecked(TagItem::new(
+			ItemKey::Unknown(String::from("MY_MOOD")),
+			ItemValue::Text(String::from("Happy")),
+		));
+		tag.push_picture(Picture::new_unchecked(
+			PictureType::CoverFront,
+			MimeType::Png,
+			None,
+			vec![1, 2, 3],
+		));
+		tag
+	}
+
+	#[test]
+	fn policies() {
+		let source = source_tag();
+
+		let mut target = Tag::new(TagType::ID3v2);
+		target.set_title(String::from("Bar title"));
+		target.set_artist(String::from("Bar artist"));
+
+		let mut overwritten = target.clone();
+		overwritten.merge(&source, MergeOptions::new().policy(MergePolicy::Overwrite));
+		assert_eq!(overwritten.title(), Some("Foo title"));
+		assert_eq!(
+			overwritten
+				.get_strings(&ItemKey::TrackArtist)
+				.collect::<Vec<_>>(),
+			vec!["Foo artist", "Bar artist"]
+		);
+		assert_eq!(overwritten.pictures().len(), 1);
+
+		let mut filled = target.clone();
+		filled.merge(
+			&source,
+			MergeOptions::new().policy(MergePolicy::FillMissing),
+		);
+		assert_eq!(filled.title(), Some("Bar title"));
+		assert_eq!(
+			filled
+				.get_strings(&ItemKey::TrackArtist)
+				.collect::<Vec<_>>(),
+			vec!["Bar artist"]
+		);
+
+		let mut appended = target;
+		appended.merge(&source, MergeOptions::new().policy(MergePolicy::Append));
+		assert_eq!(
+			appended
+				.get_strings(&ItemKey::TrackTitle)
+				.collect::<Vec<_>>(),
+			vec!["Bar title", "Foo title"]
+		);
+		assert_eq!(
+			appended
+				.get_strings(&ItemKey::TrackArtist)
+				.collect::<Vec<_>>(),
+			vec!["Bar artist", "Foo artist"]
+		);
+	}
+
+	#[test]
+	fn duplicate_picture_types() {
+		let picture = |data| Picture::new_unchecked(PictureType::Other, MimeType::Png, None, data);
+
+		let mut source = Tag::new(TagType::VorbisComments);
+		source.push_picture(picture(vec![1, 2, 3]));
+		source.push_picture(picture(vec![4, 5, 6]));
+
+		let mut target = Tag::new(TagType::ID3v2);
+		target.push_picture(picture(vec![7, 8, 9]));
+
+		let mut overwritten = target.clone();
+		overwritten.merge(&source, MergeOptions::new().policy(MergePolicy::Overwrite));
+		assert_eq!(overwritten.pictures(), source.pictures());
+
+		let mut filled = target.clone();
+		filled.merge(
+			&source,
+			MergeOptions::new().policy(MergePolicy::FillMissing),
+		);
+		assert_eq!(filled.pictures(), target.pictures());
+
+		let mut empty = Tag::new(TagType::ID3v2);
+		empty.merge(
+			&source,
+			MergeOptions::new().policy(MergePolicy::FillMissing),
+		);
+		assert_eq!(empty.pictures(), source.pictures());
+	}
+
+	#[test]
+	fn unmapped_items() {
+		let source = source_tag();
+
+		// ItemKey::CatalogNumber has no mapping in RIFF INFO, and there are no custom fields
+		let mut riff_info = Tag::new(TagType::RIFFInfo);
+		let report = riff_info.merge(&source, MergeOptions::new());
+		assert_eq!(
+			report
+				.unmapped_items()
+				.iter()
+				.map(TagItem::key)
+				.collect::<Vec<_>>(),
+			vec![
+				&ItemKey::CatalogNumber,
+				&ItemKey::Unknown(String::from("MY_MOOD"))
+			]
+		);
+		assert_eq!(report.unsupported_pictures(), &[PictureType::CoverFront]);
+		assert_eq!(riff_info.title(), Some("Foo title"));
+
+		let options = MergeOptions::new().unmapped_items(UnmappedItems::Custom);
+
+		let mut ilst = Tag::new(TagType::MP4ilst);
+		let report = source.copy_into(&mut ilst, options);
+		assert!(report.is_empty());
+		assert_eq!(
+			ilst.get_string(&ItemKey::CatalogNumber),
+			Some("0123456789"),
+			"CatalogNumber has an MP4 mapping"
+		);
+		assert_eq!(
+			ilst.get_string(&ItemKey::Unknown(String::from(
+				"----:com.apple.iTunes:MY_MOOD"
+			))),
+			Some("Happy")
+		);
+
+		let mut id3v2 = Tag::new(TagType::ID3v2);
+		let report = source.copy_into(&mut id3v2, options);
+		assert!(report.is_empty());
+		assert_eq!(
+			id3v2.get_string(&ItemKey::Unknown(String::from("MY_MOOD"))),
+			Some("Happy")
+		);
+
+		// The custom field should survive being written
+		let id3v2 = crate::id3::v2::ID3v2Tag::from(id3v2);
+		assert!(id3v2.iter().any(|frame| frame.id_str() == "TXXX"));
+	}
+
+	#[test]
+	fn custom_field_written_to_file() {
+		use crate::{AudioFile, ParseOptions, TagExt};
+		use std::io::{Seek, SeekFrom, Write};
+
+		let source = source_tag();
+
+		let mut id3v2 = Tag::new(TagType::ID3v2);
+		let report = source.copy_into(
+			&mut id3v2,
+			MergeOptions::new().unmapped_items(UnmappedItems::Custom),
+		);
+		assert!(report.is_empty());
+
+		let mut file = tempfile::tempfile().unwrap();
+		file.write_all(&std::fs::read("tests/files/assets/minimal/full_test.mp3").unwrap())
+			.unwrap();
+		file.seek(SeekFrom::Start(0)).unwrap();
+
+		id3v2.save_to(&mut file).unwrap();
+
+		file.seek(SeekFrom::Start(0)).unwrap();
+		let mpeg_file = crate::mpeg::MPEGFile::read_from(&mut file, ParseOptions::new()).unwrap();
+		let tag: Tag = mpeg_file.id3v2().unwrap().clone().into();
+		assert_eq!(
+			tag.get_string(&ItemKey::Unknown(String::from("MY_MOOD"))),
+			Some("Happy")
+		);
+	}
+}
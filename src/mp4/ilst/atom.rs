@@ -6,6 +6,8 @@ use std::fmt::{Debug, Formatter};
 // Atoms with multiple values aren't all that common,
 // so there's no need to create a bunch of single-element Vecs
 #[derive(PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(untagged))]
 pub(super) enum AtomDataStorage {
 	Single(AtomData),
 	Multiple(Vec<AtomData>),
@@ -79,6 +81,7 @@ impl<'a> Iterator for AtomDataStorageIter<'a> {
 }
 
 #[derive(PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 /// Represents an `MP4` atom
 pub struct Atom {
 	pub(crate) ident: AtomIdent,
@@ -143,6 +146,32 @@ impl Atom {
 	}
 }
 
+// Atoms are deserialized through `Atom::from_collection`, so atoms without data are rejected
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for Atom {
+	fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+	where
+		D: serde::Deserializer<'de>,
+	{
+		#[derive(serde::Deserialize)]
+		#[serde(rename = "Atom")]
+		struct AtomFields {
+			ident: AtomIdent,
+			data: AtomDataStorage,
+		}
+
+		let fields = AtomFields::deserialize(deserializer)?;
+
+		let data = match fields.data {
+			AtomDataStorage::Single(data) => vec![data],
+			AtomDataStorage::Multiple(data) => data,
+		};
+
+		Self::from_collection(fields.ident, data)
+			.ok_or_else(|| serde::de::Error::custom("Atom has no data"))
+	}
+}
+
 impl Debug for Atom {
 	fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
 		f.debug_struct("Atom")
@@ -154,6 +183,7 @@ impl Debug for Atom {
 
 // TODO: Bool variant for the various flag atoms?
 #[derive(Debug, PartialEq, Eq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 /// The data of an atom
 ///
 /// NOTES:
@@ -9,6 +9,10 @@ use crate::error::LoftyError;
 use crate::mp4::ilst::atom::AtomDataStorage;
 use crate::picture::{Picture, PictureType};
 use crate::tag::item::{ItemKey, ItemValue, TagItem};
+use crate::tag::passthrough::{NativeElements, Passthrough};
+use crate::tag::rating::Rating;
+use crate::tag::timestamp::{year_from_date, Timestamp};
+use crate::tag::utils;
 use crate::tag::{Tag, TagType};
 use crate::traits::{Accessor, TagExt};
 use atom::{AdvisoryRating, Atom, AtomData};
@@ -25,35 +29,55 @@ const GENRE: AtomIdent = AtomIdent::Fourcc(*b"\xa9gen");
 const COMMENT: AtomIdent = AtomIdent::Fourcc(*b"\xa9cmt");
 
 macro_rules! impl_accessor {
-	($($name:ident => $const:ident;)+) => {
+	($($name:ident => $ident:expr;)+) => {
 		paste::paste! {
 			$(
 				fn $name(&self) -> Option<&str> {
-					if let Some(atom) = self.atom(&$const) {
-						if let Some(AtomData::UTF8(val) | AtomData::UTF16(val)) = atom.data().next() {
-							return Some(val)
-						}
-					}
-
-					None
+					self.text(&$ident)
 				}
 
 				fn [<set_ $name>](&mut self, value: String) {
 					self.replace_atom(Atom {
-						ident: $const,
+						ident: $ident,
 						data: AtomDataStorage::Single(AtomData::UTF8(value)),
 					})
 				}
 
 				fn [<remove_ $name>](&mut self) {
-					self.remove_atom(&$const)
+					self.remove_atom(&$ident)
 				}
 			)+
 		}
+	};
+	(@TYPED $($name:ident<$ty:ty> => $ident:expr, $parse:path, $format:path;)+) => {
+		paste::paste! {
+			$(
+				fn $name(&self) -> Option<$ty> {
+					self.text(&$ident).and_then($parse)
+				}
+
+				fn [<set_ $name>](&mut self, value: $ty) {
+					self.replace_atom(Atom::text($ident, $format(value)))
+				}
+
+				fn [<remove_ $name>](&mut self) {
+					self.remove_atom(&$ident)
+				}
+			)+
+		}
+	};
+}
+
+// Freeform atoms with the `com.apple.iTunes` mean, as written by iTunes and MusicBrainz Picard
+fn itunes_freeform(name: &str) -> AtomIdent {
+	AtomIdent::Freeform {
+		mean: String::from("com.apple.iTunes"),
+		name: String::from(name),
 	}
 }
 
 #[derive(Default, PartialEq, Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 /// An MP4 ilst atom
 ///
 /// ## Supported file types
@@ -69,15 +93,23 @@ macro_rules! impl_accessor {
 ///
 /// ### To `Tag`
 ///
-/// When converting to [`Tag`], only atoms with a value of [`AtomData::UTF8`] and [`AtomData::UTF16`],
-/// with the exception of the `trkn` and `disk` atoms, as well as pictures, will be preserved.
+/// When converting to [`Tag`], only atoms with a value of [`AtomData::UTF8`], [`AtomData::UTF16`],
+/// [`AtomData::SignedInteger`], and [`AtomData::UnsignedInteger`], with the exception of the `trkn` and
+/// `disk` atoms, as well as pictures, will be preserved.
+///
+/// Integer atoms are converted to [`ItemValue::Integer`](crate::ItemValue::Integer), apart from flags
+/// such as `cpil` and `pcst`, which are converted to [`ItemValue::Bool`](crate::ItemValue::Bool).
 ///
 /// Do note, all pictures will be [`PictureType::Other`](crate::PictureType::Other)
 ///
 /// ### From `Tag`
 ///
-/// When converting from [`Tag`], only items with a value of [`ItemValue::Text`](crate::ItemValue::Text), as
-/// well as pictures, will be preserved.
+/// When converting from [`Tag`], only items with a value of [`ItemValue::Text`](crate::ItemValue::Text),
+/// [`ItemValue::Integer`](crate::ItemValue::Integer), [`ItemValue::Bool`](crate::ItemValue::Bool), and
+/// [`ItemValue::Date`](crate::ItemValue::Date), as well as pictures, will be preserved.
+///
+/// Items for integer atoms (`tmpo`, `rtng`, `cpil`, etc.) are written as integers, if their value can be
+/// represented as one. All other typed values are written as text.
 ///
 /// An attempt will be made to create the `TrackNumber/TrackTotal` (trkn) and `DiscNumber/DiscTotal` (disk) pairs.
 pub struct Ilst {
@@ -197,6 +229,23 @@ impl Ilst {
 		self.extract_number(*b"disk", 6)
 	}
 
+	fn text(&self, ident: &AtomIdent) -> Option<&str> {
+		match self.atom(ident)?.data().next() {
+			Some(AtomData::UTF8(val) | AtomData::UTF16(val)) => Some(val),
+			_ => None,
+		}
+	}
+
+	// Integer atoms may also be found as text
+	fn extract_integer(&self, fourcc: [u8; 4]) -> Option<i64> {
+		match self.atom(&AtomIdent::Fourcc(fourcc))?.data().next()? {
+			AtomData::SignedInteger(int) => Some(i64::from(*int)),
+			AtomData::UnsignedInteger(uint) => Some(i64::from(*uint)),
+			AtomData::UTF8(text) | AtomData::UTF16(text) => text.trim().parse().ok(),
+			_ => None,
+		}
+	}
+
 	// Extracts a u16 from an integer pair
 	fn extract_number(&self, fourcc: [u8; 4], expected_size: usize) -> Option<u16> {
 		if let Some(atom) = self.atom(&AtomIdent::Fourcc(fourcc)) {
@@ -217,11 +266,32 @@ impl Ilst {
 
 impl Accessor for Ilst {
 	impl_accessor!(
-		artist  => ARTIST;
-		title   => TITLE;
-		album   => ALBUM;
-		genre   => GENRE;
-		comment => COMMENT;
+		artist                  => ARTIST;
+		title                   => TITLE;
+		album                   => ALBUM;
+		genre                   => GENRE;
+		comment                 => COMMENT;
+		album_artist            => AtomIdent::Fourcc(*b"aART");
+		composer                => AtomIdent::Fourcc(*b"\xa9wrt");
+		lyrics                  => AtomIdent::Fourcc(*b"\xa9lyr");
+		title_sort_order        => AtomIdent::Fourcc(*b"sonm");
+		artist_sort_order       => AtomIdent::Fourcc(*b"soar");
+		album_sort_order        => AtomIdent::Fourcc(*b"soal");
+		album_artist_sort_order => AtomIdent::Fourcc(*b"soaa");
+		composer_sort_order     => AtomIdent::Fourcc(*b"soco");
+		isrc                    => itunes_freeform("ISRC");
+		label                   => itunes_freeform("LABEL");
+		catalog_number          => itunes_freeform("CATALOGNUMBER");
+	);
+
+	impl_accessor!(
+		@TYPED
+		date<Timestamp>             => AtomIdent::Fourcc(*b"\xa9day"), utils::parse_date, utils::format_date;
+		replay_gain_track_gain<f32> => itunes_freeform("replaygain_track_gain"), utils::parse_replay_gain, utils::format_replay_gain;
+		replay_gain_track_peak<f32> => itunes_freeform("replaygain_track_peak"), utils::parse_replay_gain_peak, utils::format_replay_gain_peak;
+		replay_gain_album_gain<f32> => itunes_freeform("replaygain_album_gain"), utils::parse_replay_gain, utils::format_replay_gain;
+		replay_gain_album_peak<f32> => itunes_freeform("replaygain_album_peak"), utils::parse_replay_gain_peak, utils::format_replay_gain_peak;
+		rating<Rating>              => itunes_freeform("rate"), utils::parse_rating, utils::format_rating;
 	);
 
 	fn track(&self) -> Option<u32> {
@@ -307,7 +377,7 @@ impl Accessor for Ilst {
 	fn year(&self) -> Option<u32> {
 		if let Some(atom) = self.atom(&AtomIdent::Fourcc(*b"\xa9day")) {
 			if let Some(AtomData::UTF8(text)) = atom.data().next() {
-				return text.chars().take(4).collect::<String>().parse::<u32>().ok();
+				return year_from_date(text);
 			}
 		}
 
@@ -322,7 +392,40 @@ impl Accessor for Ilst {
 	}
 
 	fn remove_year(&mut self) {
-		self.remove_atom(&AtomIdent::Fourcc(*b"Year"));
+		self.remove_atom(&AtomIdent::Fourcc(*b"\xa9day"));
+	}
+
+	fn bpm(&self) -> Option<u32> {
+		self.extract_integer(*b"tmpo")
+			.and_then(|bpm| u32::try_from(bpm).ok())
+	}
+
+	fn set_bpm(&mut self, value: u32) {
+		self.replace_atom(Atom {
+			ident: AtomIdent::Fourcc(*b"tmpo"),
+			data: AtomDataStorage::Single(AtomData::SignedInteger(
+				i32::try_from(value).unwrap_or(i32::MAX),
+			)),
+		})
+	}
+
+	fn remove_bpm(&mut self) {
+		self.remove_atom(&AtomIdent::Fourcc(*b"tmpo"));
+	}
+
+	fn compilation(&self) -> Option<bool> {
+		self.extract_integer(*b"cpil").map(|flag| flag != 0)
+	}
+
+	fn set_compilation(&mut self, value: bool) {
+		self.replace_atom(Atom {
+			ident: AtomIdent::Fourcc(*b"cpil"),
+			data: AtomDataStorage::Single(AtomData::SignedInteger(i32::from(value))),
+		})
+	}
+
+	fn remove_compilation(&mut self) {
+		self.remove_atom(&AtomIdent::Fourcc(*b"cpil"));
 	}
 }
 
@@ -363,65 +466,86 @@ impl From<Ilst> for Tag {
 	fn from(input: Ilst) -> Self {
 		let mut tag = Self::new(TagType::MP4ilst);
 
+		// The original atoms are kept, see `Tag#native-data`
+		let mut atoms = NativeElements::new();
 		for atom in input.atoms {
-			let Atom { ident, data } = atom;
-			let value = match data.take_first() {
-				AtomData::UTF8(text) | AtomData::UTF16(text) => ItemValue::Text(text),
-				AtomData::Picture(pic) => {
-					tag.pictures.push(pic);
-					continue;
-				},
-				// We have to special case track/disc numbers since they are stored together
-				AtomData::Unknown { code: 0, data } if data.len() >= 6 => {
-					if let AtomIdent::Fourcc(ref fourcc) = ident {
-						match fourcc {
-							b"trkn" => {
-								let current = u16::from_be_bytes([data[2], data[3]]);
-								let total = u16::from_be_bytes([data[4], data[5]]);
-
-								tag.insert_text(ItemKey::TrackNumber, current.to_string());
-								tag.insert_text(ItemKey::TrackTotal, total.to_string());
-							},
-							b"disk" => {
-								let current = u16::from_be_bytes([data[2], data[3]]);
-								let total = u16::from_be_bytes([data[4], data[5]]);
-
-								tag.insert_text(ItemKey::DiscNumber, current.to_string());
-								tag.insert_text(ItemKey::DiscTotal, total.to_string());
-							},
-							_ => {},
-						}
-					}
-
-					continue;
-				},
-				_ => continue,
-			};
-
-			let key = ItemKey::from_key(
-				TagType::MP4ilst,
-				&match ident {
-					AtomIdent::Fourcc(fourcc) => {
-						fourcc.iter().map(|b| *b as char).collect::<String>()
-					},
-					AtomIdent::Freeform { mean, name } => {
-						format!("----:{}:{}", mean, name)
-					},
-				},
-			);
+			// Pictures are already kept in the tag
+			if matches!(atom.data().next(), Some(AtomData::Picture(_))) {
+				convert_atom(atom, &mut tag);
+				continue;
+			}
 
-			tag.items.push(TagItem::new(key, value));
+			let items_start = tag.items.len();
+			convert_atom(atom.clone(), &mut tag);
+			atoms.record(atom, &tag, items_start);
 		}
 
+		tag.passthrough = Some(Passthrough::Ilst(atoms.finish(&tag)));
 		tag
 	}
 }
 
+fn convert_atom(atom: Atom, tag: &mut Tag) {
+	let Atom { ident, data } = atom;
+	let value = match data.take_first() {
+		AtomData::UTF8(text) | AtomData::UTF16(text) => ItemValue::Text(text),
+		AtomData::SignedInteger(int) if is_flag_atom(&ident) => ItemValue::Bool(int != 0),
+		AtomData::SignedInteger(int) => ItemValue::Integer(i64::from(int)),
+		AtomData::UnsignedInteger(uint) => ItemValue::Integer(i64::from(uint)),
+		AtomData::Picture(pic) => {
+			tag.pictures.push(pic);
+			return;
+		},
+		// We have to special case track/disc numbers since they are stored together
+		AtomData::Unknown { code: 0, data } if data.len() >= 6 => {
+			if let AtomIdent::Fourcc(ref fourcc) = ident {
+				match fourcc {
+					b"trkn" => {
+						let current = u16::from_be_bytes([data[2], data[3]]);
+						let total = u16::from_be_bytes([data[4], data[5]]);
+
+						tag.insert_text(ItemKey::TrackNumber, current.to_string());
+						tag.insert_text(ItemKey::TrackTotal, total.to_string());
+					},
+					b"disk" => {
+						let current = u16::from_be_bytes([data[2], data[3]]);
+						let total = u16::from_be_bytes([data[4], data[5]]);
+
+						tag.insert_text(ItemKey::DiscNumber, current.to_string());
+						tag.insert_text(ItemKey::DiscTotal, total.to_string());
+					},
+					_ => {},
+				}
+			}
+
+			return;
+		},
+		_ => return,
+	};
+
+	let key = ItemKey::from_key(
+		TagType::MP4ilst,
+		&match ident {
+			AtomIdent::Fourcc(fourcc) => fourcc.iter().map(|b| *b as char).collect::<String>(),
+			AtomIdent::Freeform { mean, name } => {
+				format!("----:{}:{}", mean, name)
+			},
+		},
+	);
+
+	tag.items.push(TagItem::new(key, value));
+}
+
 impl From<Tag> for Ilst {
-	fn from(input: Tag) -> Self {
-		fn convert_to_uint(space: &mut Option<u16>, cont: &str) {
-			if let Ok(num) = cont.parse::<u16>() {
-				*space = Some(num);
+	fn from(mut input: Tag) -> Self {
+		fn convert_to_uint(space: &mut Option<u16>, cont: &ItemValue) {
+			let num = match cont {
+				ItemValue::Integer(int) => u16::try_from(*int).ok(),
+				_ => cont.as_text().and_then(|text| text.parse::<u16>().ok()),
+			};
+
+			if num.is_some() {
+				*space = num;
 			}
 		}
 
@@ -443,7 +567,13 @@ impl From<Tag> for Ilst {
 			}
 		}
 
-		let mut ilst = Self::default();
+		// Atoms that are still accurately represented by the items are kept as-is, see `Tag#native-data`
+		let mut ilst = match input.passthrough.take() {
+			Some(Passthrough::Ilst(atoms)) => Self {
+				atoms: atoms.retain_unchanged(&mut input),
+			},
+			_ => Self::default(),
+		};
 
 		// Storage for integer pairs
 		let mut tracks: (Option<u16>, Option<u16>) = (None, None);
@@ -452,21 +582,24 @@ impl From<Tag> for Ilst {
 		for item in input.items {
 			let key = item.item_key;
 
-			if let Some(ident) = item_key_to_ident(&key).map(Into::into) {
+			if let Some(ident) = item_key_to_ident(&key).map(Into::<AtomIdent>::into) {
 				let data = match item.item_value {
-					ItemValue::Text(text) => text,
-					_ => continue,
+					ItemValue::Binary(_) | ItemValue::Locator(_) => continue,
+					value => value,
 				};
 
 				match key {
-					ItemKey::TrackNumber => convert_to_uint(&mut tracks.0, data.as_str()),
-					ItemKey::TrackTotal => convert_to_uint(&mut tracks.1, data.as_str()),
-					ItemKey::DiscNumber => convert_to_uint(&mut discs.0, data.as_str()),
-					ItemKey::DiscTotal => convert_to_uint(&mut discs.1, data.as_str()),
-					_ => ilst.atoms.push(Atom {
-						ident,
-						data: AtomDataStorage::Single(AtomData::UTF8(data)),
-					}),
+					ItemKey::TrackNumber => convert_to_uint(&mut tracks.0, &data),
+					ItemKey::TrackTotal => convert_to_uint(&mut tracks.1, &data),
+					ItemKey::DiscNumber => convert_to_uint(&mut discs.0, &data),
+					ItemKey::DiscTotal => convert_to_uint(&mut discs.1, &data),
+					_ => {
+						let data = item_value_to_atom_data(&ident, &data);
+						ilst.atoms.push(Atom {
+							ident,
+							data: AtomDataStorage::Single(data),
+						})
+					},
 				}
 			}
 		}
@@ -489,6 +622,36 @@ impl From<Tag> for Ilst {
 	}
 }
 
+// Atoms holding a single integer that is only ever 0 or 1
+const FLAG_ATOMS: [[u8; 4]; 4] = [*b"cpil", *b"pcst", *b"pgap", *b"hdvd"];
+
+// Atoms holding a single integer
+const INTEGER_ATOMS: [[u8; 4]; 7] = [
+	*b"tmpo", *b"rtng", *b"stik", *b"tves", *b"tvsn", *b"akID", *b"shwm",
+];
+
+fn is_flag_atom(ident: &AtomIdent) -> bool {
+	matches!(ident, AtomIdent::Fourcc(fourcc) if FLAG_ATOMS.contains(fourcc))
+}
+
+fn item_value_to_atom_data(ident: &AtomIdent, value: &ItemValue) -> AtomData {
+	let is_integer_atom =
+		matches!(ident, AtomIdent::Fourcc(fourcc) if INTEGER_ATOMS.contains(fourcc));
+
+	let int = match value {
+		ItemValue::Bool(flag) => Some(i64::from(*flag)),
+		ItemValue::Integer(int) => Some(*int),
+		ItemValue::Text(text) if is_integer_atom || is_flag_atom(ident) => text.parse().ok(),
+		_ => None,
+	};
+
+	match int.and_then(|int| i32::try_from(int).ok()) {
+		Some(int) if is_flag_atom(ident) => AtomData::SignedInteger(i32::from(int != 0)),
+		Some(int) if is_integer_atom => AtomData::SignedInteger(int),
+		_ => AtomData::UTF8(value.as_text().unwrap_or_default().into_owned()),
+	}
+}
+
 fn item_key_to_ident(key: &ItemKey) -> Option<AtomIdentRef<'_>> {
 	key.map_key(TagType::MP4ilst, true).and_then(|ident| {
 		if ident.starts_with("----") {
@@ -523,7 +686,9 @@ mod tests {
 	use crate::mp4::{AdvisoryRating, Atom, AtomData, AtomIdent, Ilst, Mp4File};
 	use crate::tag::utils::test_utils;
 	use crate::tag::utils::test_utils::read_path;
-	use crate::{Accessor, AudioFile, ItemKey, ParseOptions, Tag, TagExt, TagType};
+	use crate::{
+		Accessor, AudioFile, ItemKey, ItemValue, ParseOptions, Tag, TagExt, TagItem, TagType,
+	};
 	use std::io::{Cursor, Read, Seek, Write};
 
 	fn read_ilst(path: &str) -> Ilst {
@@ -857,4 +1022,182 @@ mod tests {
 
 		assert_eq!(file.ilst(), Some(&Ilst::default()));
 	}
+
+	#[test]
+	fn gnre_to_genre() {
+		// A `gnre` atom, holding the ID3v1 genre number for "Pop/Funk" (62) plus one
+		let mut ilst_bytes = Vec::new();
+		ilst_bytes.extend(26_u32.to_be_bytes());
+		ilst_bytes.extend(b"gnre");
+		ilst_bytes.extend(18_u32.to_be_bytes());
+		ilst_bytes.extend(b"data");
+		ilst_bytes.extend([0; 8]);
+		ilst_bytes.extend(63_u16.to_be_bytes());
+
+		let len = ilst_bytes.len() as u64;
+		let mut reader = AtomReader::new(Cursor::new(ilst_bytes)).unwrap();
+		let ilst = super::read::parse_ilst(&mut reader, len).unwrap();
+
+		assert!(ilst.atom(&AtomIdent::Fourcc(*b"gnre")).is_none());
+		assert_eq!(ilst.genre(), Some("Pop/Funk"));
+	}
+
+	#[test]
+	fn typed_atoms_round_trip() {
+		let mut ilst = Ilst::default();
+		ilst.insert_atom(Atom::new(
+			AtomIdent::Fourcc(*b"cpil"),
+			AtomData::SignedInteger(1),
+		));
+		ilst.insert_atom(Atom::new(
+			AtomIdent::Fourcc(*b"tmpo"),
+			AtomData::SignedInteger(300),
+		));
+		ilst.insert_atom(Atom::new(
+			AtomIdent::Fourcc(*b"pcst"),
+			AtomData::SignedInteger(0),
+		));
+
+		let tag: Tag = ilst.clone().into();
+
+		assert_eq!(
+			tag.get_item_ref(&ItemKey::FlagCompilation).unwrap().value(),
+			&ItemValue::Bool(true)
+		);
+		assert_eq!(
+			tag.get_item_ref(&ItemKey::FlagPodcast).unwrap().value(),
+			&ItemValue::Bool(false)
+		);
+		assert_eq!(
+			tag.get_item_ref(&ItemKey::BPM).unwrap().value(),
+			&ItemValue::Integer(300)
+		);
+
+		let converted: Ilst = tag.into();
+		assert_eq!(converted, ilst);
+
+		// And through a write
+		let mut writer = Vec::new();
+		converted.dump_to(&mut writer).unwrap();
+
+		let cursor = Cursor::new(&writer[8..]);
+		let mut reader = AtomReader::new(cursor).unwrap();
+		let re_read = super::read::parse_ilst(&mut reader, (writer.len() - 8) as u64).unwrap();
+
+		assert_eq!(re_read, ilst);
+	}
+
+	#[test]
+	fn integer_atom_widths() {
+		let mut ilst = Ilst::default();
+		for (fourcc, value) in [
+			(*b"tmpo", 120),
+			(*b"tves", 1),
+			(*b"tvsn", 2),
+			(*b"cpil", 1),
+			(*b"rtng", 0),
+			(*b"stik", 1),
+		] {
+			ilst.insert_atom(Atom::new(
+				AtomIdent::Fourcc(fourcc),
+				AtomData::SignedInteger(value),
+			));
+		}
+
+		let mut writer = Vec::new();
+		ilst.dump_to(&mut writer).unwrap();
+
+		for (fourcc, width) in [
+			(b"tmpo", 2),
+			(b"tves", 4),
+			(b"tvsn", 4),
+			(b"cpil", 1),
+			(b"rtng", 1),
+			(b"stik", 1),
+		] {
+			let pos = writer.windows(4).position(|w| w == fourcc).unwrap();
+
+			// Size (4) + "data" (4) + flags (4) + reserved (4)
+			let data_size = u32::from_be_bytes(writer[pos + 4..pos + 8].try_into().unwrap());
+			assert_eq!(data_size, 16 + width, "{}", String::from_utf8_lossy(fourcc));
+		}
+
+		let cursor = Cursor::new(&writer[8..]);
+		let mut reader = AtomReader::new(cursor).unwrap();
+		let re_read = super::read::parse_ilst(&mut reader, (writer.len() - 8) as u64).unwrap();
+
+		assert_eq!(re_read, ilst);
+	}
+
+	#[test]
+	fn text_to_integer_atoms() {
+		let mut tag = Tag::new(TagType::MP4ilst);
+		tag.insert_text(ItemKey::FlagCompilation, String::from("1"));
+		tag.insert_text(ItemKey::BPM, String::from("120"));
+		tag.insert_item(TagItem::new(ItemKey::TrackTitle, ItemValue::Integer(5)));
+
+		let ilst: Ilst = tag.into();
+
+		verify_atom(&ilst, *b"cpil", &AtomData::SignedInteger(1));
+		verify_atom(&ilst, *b"tmpo", &AtomData::SignedInteger(120));
+		verify_atom(&ilst, *b"\xa9nam", &AtomData::UTF8(String::from("5")));
+	}
+
+	#[test]
+	fn native_data_through_tag() {
+		let freeform = AtomIdent::Freeform {
+			mean: String::from("com.foo"),
+			name: String::from("BAR"),
+		};
+
+		let mut ilst = Ilst::default();
+		ilst.insert_atom(Atom::new(
+			AtomIdent::Fourcc(*b"\xa9nam"),
+			AtomData::UTF8(String::from("Foo title")),
+		));
+		ilst.insert_atom(Atom::new(
+			AtomIdent::Fourcc(*b"\xa9alb"),
+			AtomData::UTF16(String::from("Foo album")),
+		));
+		ilst.insert_atom(
+			Atom::from_collection(
+				AtomIdent::Fourcc(*b"\xa9ART"),
+				vec![
+					AtomData::UTF8(String::from("Foo artist")),
+					AtomData::UTF8(String::from("Bar artist")),
+				],
+			)
+			.unwrap(),
+		);
+		ilst.insert_atom(Atom::new(
+			freeform.clone(),
+			AtomData::Unknown {
+				code: 0,
+				data: vec![1, 2, 3],
+			},
+		));
+
+		// Unmodified, the tag should be identical
+		let tag: Tag = ilst.clone().into();
+		assert_eq!(Ilst::from(tag.clone()), ilst);
+
+		// Only the modified atom should change
+		let mut tag = tag;
+		tag.set_title(String::from("Bar title"));
+
+		let converted = Ilst::from(tag.clone());
+		assert_eq!(converted.title(), Some("Bar title"));
+		for ident in [
+			AtomIdent::Fourcc(*b"\xa9alb"),
+			AtomIdent::Fourcc(*b"\xa9ART"),
+			freeform.clone(),
+		] {
+			assert_eq!(converted.atom(&ident), ilst.atom(&ident));
+		}
+
+		// Without it, only the items are used
+		tag.clear_passthrough();
+		let converted = Ilst::from(tag);
+		assert!(converted.atom(&freeform).is_none());
+	}
 }
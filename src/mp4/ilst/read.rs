@@ -40,13 +40,14 @@ where
 					continue;
 				},
 				// Upgrade this to a \xa9gen atom
+				// The value is a 16-bit ID3v1 genre number, plus one
 				b"gnre" => {
 					if let Some(atom_data) = parse_data_inner(&mut ilst_reader, &atom)? {
 						let mut data = Vec::new();
 
 						for (flags, content) in atom_data {
 							if (flags == BE_SIGNED_INTEGER || flags == 0) && content.len() >= 2 {
-								let index = content[1] as usize;
+								let index = u16::from_be_bytes([content[0], content[1]]) as usize;
 								if index > 0 && index <= GENRES.len() {
 									data.push(AtomData::UTF8(String::from(GENRES[index - 1])));
 								}
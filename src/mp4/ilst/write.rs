@@ -326,7 +326,7 @@ where
 			AtomIdentRef::Freeform { mean, name } => write_freeform(mean, name, &mut writer)?,
 		}
 
-		write_atom_data(atom.data, &mut writer)?;
+		write_atom_data(&atom.ident, atom.data, &mut writer)?;
 
 		let end = writer.stream_position()?;
 
@@ -370,7 +370,11 @@ fn write_freeform(mean: &str, name: &str, writer: &mut Cursor<Vec<u8>>) -> Resul
 	Ok(())
 }
 
-fn write_atom_data<'a, I: 'a>(data: I, writer: &mut Cursor<Vec<u8>>) -> Result<()>
+fn write_atom_data<'a, I: 'a>(
+	ident: &AtomIdentRef<'_>,
+	data: I,
+	writer: &mut Cursor<Vec<u8>>,
+) -> Result<()>
 where
 	I: IntoIterator<Item = &'a AtomData>,
 {
@@ -379,7 +383,7 @@ where
 			AtomData::UTF8(text) => write_data(1, text.as_bytes(), writer)?,
 			AtomData::UTF16(text) => write_data(2, text.as_bytes(), writer)?,
 			AtomData::Picture(ref pic) => write_picture(pic, writer)?,
-			AtomData::SignedInteger(int) => write_signed_int(*int, writer)?,
+			AtomData::SignedInteger(int) => write_signed_int(*int, int_width(ident), writer)?,
 			AtomData::UnsignedInteger(uint) => write_unsigned_int(*uint, writer)?,
 			AtomData::Unknown { code, ref data } => write_data(*code, data, writer)?,
 		};
@@ -388,13 +392,41 @@ where
 	Ok(())
 }
 
-fn write_signed_int(int: i32, writer: &mut Cursor<Vec<u8>>) -> Result<()> {
-	let start_pos = int.trailing_zeros() as usize;
+// The number of bytes the well-known integer atoms are defined to hold
+fn int_width(ident: &AtomIdentRef<'_>) -> Option<usize> {
+	match ident {
+		AtomIdentRef::Fourcc(fourcc) => match fourcc {
+			b"tmpo" => Some(2),
+			b"tves" | b"tvsn" => Some(4),
+			b"cpil" | b"pcst" | b"pgap" | b"hdvd" | b"rtng" | b"stik" | b"akID" | b"shwm" => {
+				Some(1)
+			},
+			_ => None,
+		},
+		AtomIdentRef::Freeform { .. } => None,
+	}
+}
+
+fn write_signed_int(int: i32, width: Option<usize>, writer: &mut Cursor<Vec<u8>>) -> Result<()> {
+	// A single byte is read back as unsigned, anything wider as signed
+	let fits_u8 = (0..=i32::from(u8::MAX)).contains(&int);
+	let fits_i16 = i16::try_from(int).is_ok();
+
+	// Values that don't fit in an atom's defined width are written wider, rather than truncated
+	let start_pos = match width {
+		Some(1) if fits_u8 => 3,
+		Some(2) if fits_i16 => 2,
+		Some(4) => 0,
+		_ if fits_u8 && width.is_none() => 3,
+		_ if fits_i16 => 2,
+		_ => 0,
+	};
+
 	write_int(21, int.to_be_bytes(), start_pos, writer)
 }
 
 fn write_unsigned_int(uint: u32, writer: &mut Cursor<Vec<u8>>) -> Result<()> {
-	let start_pos = uint.trailing_zeros() as usize;
+	let start_pos = (uint.leading_zeros() / 8).min(3) as usize;
 	write_int(22, uint.to_be_bytes(), start_pos, writer)
 }
 
@@ -404,7 +436,8 @@ fn write_int(
 	mut start_pos: usize,
 	writer: &mut Cursor<Vec<u8>>,
 ) -> Result<()> {
-	if start_pos == 1 || start_pos == 4 {
+	// Integers are written as 1, 2, or 4 bytes
+	if start_pos == 1 {
 		start_pos = 0;
 	}
 	write_data(flags, &bytes[start_pos..], writer)
@@ -12,6 +12,7 @@ use byteorder::{BigEndian, ReadBytesExt};
 
 #[allow(missing_docs)]
 #[derive(Debug, Copy, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[non_exhaustive]
 /// An MP4 file's audio codec
 pub enum Mp4Codec {
@@ -30,6 +31,7 @@ impl Default for Mp4Codec {
 
 #[allow(missing_docs)]
 #[derive(Debug, Copy, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[rustfmt::skip]
 #[non_exhaustive]
 pub enum AudioObjectType {
@@ -140,6 +142,7 @@ impl TryFrom<u8> for AudioObjectType {
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[non_exhaustive]
 /// An MP4 file's audio properties
 pub struct Mp4Properties {
@@ -620,7 +623,8 @@ where
 	// Flags (3)
 	stsd.seek(SeekFrom::Current(4))?;
 
-	if dfla_atom.len - 12 < 18 {
+	// The STREAMINFO block header (4) + content (34)
+	if dfla_atom.len - 12 < 38 {
 		// The atom isn't long enough to hold a STREAMINFO block, also not worth an error.
 		return Ok(());
 	}
@@ -629,10 +633,9 @@ where
 	let flac_properties =
 		crate::flac::properties::read_properties(&mut &stream_info_block.content[..], 0, 0)?;
 
-	// Safe to unwrap, since these fields are guaranteed to be present
-	properties.sample_rate = flac_properties.sample_rate.unwrap();
-	properties.bit_depth = flac_properties.bit_depth;
-	properties.channels = flac_properties.channels.unwrap();
+	properties.sample_rate = flac_properties.sample_rate;
+	properties.bit_depth = Some(flac_properties.bit_depth);
+	properties.channels = flac_properties.channels;
 
 	Ok(())
 }
@@ -77,6 +77,10 @@ pub(super) enum HeaderCmpResult {
 	NotEqual,
 }
 
+// Used to compare the versions, layers, and sample rates of two frame headers.
+// If they aren't equal, something is broken.
+pub(super) const HEADER_MASK: u32 = 0xFFFE_0C00;
+
 pub(super) fn cmp_header<R>(
 	reader: &mut R,
 	first_header_len: u32,
@@ -85,10 +89,6 @@ pub(super) fn cmp_header<R>(
 where
 	R: Read + Seek,
 {
-	// Used to compare the versions, layers, and sample rates of two frame headers.
-	// If they aren't equal, something is broken.
-	const HEADER_MASK: u32 = 0xFFFE_0C00;
-
 	// Read the next header and see if they are the same
 	let res = reader.seek(SeekFrom::Current(i64::from(
 		first_header_len.saturating_sub(4),
@@ -109,6 +109,7 @@ where
 }
 
 #[derive(PartialEq, Eq, Copy, Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[allow(missing_docs)]
 /// MPEG Audio version
 pub enum MpegVersion {
@@ -124,6 +125,7 @@ impl Default for MpegVersion {
 }
 
 #[derive(Copy, Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[allow(missing_docs)]
 /// MPEG layer
 pub enum Layer {
@@ -139,6 +141,7 @@ impl Default for Layer {
 }
 
 #[derive(Copy, Clone, PartialEq, Eq, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[allow(missing_docs)]
 /// Channel mode
 pub enum ChannelMode {
@@ -156,6 +159,7 @@ impl Default for ChannelMode {
 }
 
 #[derive(Copy, Clone, PartialEq, Eq, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[allow(missing_docs, non_camel_case_types)]
 /// A rarely-used decoder hint that the file must be de-emphasized
 pub enum Emphasis {
@@ -173,6 +177,18 @@ impl Default for Emphasis {
 	}
 }
 
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+/// How the bitrate varies throughout an MPEG stream
+pub enum BitrateMode {
+	/// Constant bitrate, every frame has the same bitrate
+	Cbr,
+	/// Variable bitrate
+	Vbr,
+	/// Average bitrate, a VBR stream targeting a specific bitrate
+	Abr,
+}
+
 #[derive(Copy, Clone)]
 pub(crate) struct Header {
 	pub(crate) sample_rate: u32,
@@ -187,6 +203,7 @@ pub(crate) struct Header {
 	pub(crate) copyright: bool,
 	pub(crate) original: bool,
 	pub(crate) emphasis: Emphasis,
+	pub(crate) protected: bool,
 }
 
 impl Header {
@@ -226,10 +243,14 @@ impl Header {
 			copyright: false,
 			original: false,
 			emphasis: Emphasis::default(),
+			protected: false,
 		};
 
 		let layer_index = (header.layer as usize).saturating_sub(1);
 
+		// A 16-bit CRC follows the header if the protection bit is *unset*
+		header.protected = (data >> 16) & 1 == 0;
+
 		let bitrate_index = (data >> 12) & 0xF;
 		header.bitrate = BITRATES[version_index][layer_index][bitrate_index as usize];
 		if header.bitrate == 0 {
@@ -285,12 +306,21 @@ impl Header {
 	}
 }
 
-pub(super) struct XingHeader {
-	pub frames: u32,
-	pub size: u32,
+/// A VBR header, found in the first MPEG frame
+///
+/// These headers describe the entire stream, and are used to get accurate durations and bitrates,
+/// along with a table of contents for seeking.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[non_exhaustive]
+pub enum VbrHeader {
+	/// A Xing header (`Info` in CBR files), possibly followed by a [`LameHeader`]
+	Xing(XingHeader),
+	/// A VBRI header, written by the Fraunhofer encoder
+	Vbri(VbriHeader),
 }
 
-impl XingHeader {
+impl VbrHeader {
 	pub(super) fn read(reader: &mut &[u8], parse_mode: ParsingMode) -> Result<Option<Self>> {
 		let reader_len = reader.len();
 
@@ -303,10 +333,9 @@ impl XingHeader {
 					decode_err!(@BAIL MPEG, "Xing header has an invalid size (< 16)");
 				}
 
-				let mut flags = [0; 4];
-				reader.read_exact(&mut flags)?;
+				let flags = reader.read_u32::<BigEndian>()?;
 
-				if flags[3] & 0x03 != 0x03 {
+				if flags & 0x03 != 0x03 {
 					parse_mode_choice!(
 						parse_mode,
 						STRICT: decode_err!(@BAIL MPEG, "Xing header doesn't have required flags set (0x0001 and 0x0002)"),
@@ -314,33 +343,524 @@ impl XingHeader {
 					);
 				}
 
-				let frames = reader.read_u32::<BigEndian>()?;
-				let size = reader.read_u32::<BigEndian>()?;
+				let mut xing_header = XingHeader {
+					cbr: &header == b"Info",
+					..XingHeader::default()
+				};
+
+				if flags & 0x01 == 0x01 {
+					xing_header.frames = reader.read_u32::<BigEndian>()?;
+				}
+
+				if flags & 0x02 == 0x02 {
+					xing_header.size = reader.read_u32::<BigEndian>()?;
+				}
+
+				// Anything after a truncated field is treated as missing
+				if flags & 0x04 == 0x04 {
+					if reader.len() < 100 {
+						parse_mode_choice!(
+							parse_mode,
+							STRICT: decode_err!(@BAIL MPEG, "Xing header has a truncated TOC"),
+							RELAXED: return Ok(Some(Self::Xing(xing_header)))
+						);
+					}
+
+					let mut toc = [0; 100];
+					reader.read_exact(&mut toc)?;
+					xing_header.toc = Some(toc);
+				}
+
+				if flags & 0x08 == 0x08 {
+					if reader.len() < 4 {
+						parse_mode_choice!(
+							parse_mode,
+							STRICT: decode_err!(@BAIL MPEG, "Xing header has a truncated quality indicator"),
+							RELAXED: return Ok(Some(Self::Xing(xing_header)))
+						);
+					}
+
+					xing_header.quality = Some(reader.read_u32::<BigEndian>()?);
+				}
+
+				xing_header.lame = LameHeader::read(reader);
 
-				Ok(Some(Self { frames, size }))
+				Ok(Some(Self::Xing(xing_header)))
 			},
 			b"VBRI" => {
-				if reader_len < 32 {
-					decode_err!(@BAIL MPEG, "VBRI header has an invalid size (< 32)");
+				if reader_len < 26 {
+					decode_err!(@BAIL MPEG, "VBRI header has an invalid size (< 26)");
 				}
 
-				// Skip 6 bytes
-				// Version ID (2)
-				// Delay float (2)
-				// Quality indicator (2)
-				let _info = reader.read_uint::<BigEndian>(6)?;
-
+				let version = reader.read_u16::<BigEndian>()?;
+				let delay = reader.read_u16::<BigEndian>()?;
+				let quality = reader.read_u16::<BigEndian>()?;
 				let size = reader.read_u32::<BigEndian>()?;
 				let frames = reader.read_u32::<BigEndian>()?;
 
-				Ok(Some(Self { frames, size }))
+				let toc_entries = reader.read_u16::<BigEndian>()?;
+				let toc_scale = reader.read_u16::<BigEndian>()?;
+				let toc_entry_size = reader.read_u16::<BigEndian>()?;
+				let frames_per_toc_entry = reader.read_u16::<BigEndian>()?;
+
+				if !(1..=4).contains(&toc_entry_size) {
+					decode_err!(@BAIL MPEG, "VBRI header has an invalid TOC entry size");
+				}
+
+				if reader.len() < usize::from(toc_entries) * usize::from(toc_entry_size) {
+					decode_err!(@BAIL MPEG, "VBRI header has a truncated TOC");
+				}
+
+				let mut toc = Vec::with_capacity(usize::from(toc_entries));
+				for _ in 0..toc_entries {
+					let entry = reader.read_uint::<BigEndian>(usize::from(toc_entry_size))?;
+					toc.push((entry as u32).saturating_mul(u32::from(toc_scale)));
+				}
+
+				Ok(Some(Self::Vbri(VbriHeader {
+					version,
+					delay,
+					quality,
+					frames,
+					size,
+					toc,
+					frames_per_toc_entry,
+				})))
 			},
 			_ => Ok(None),
 		}
 	}
 
+	/// The number of frames in the stream
+	///
+	/// NOTE: This will be `0` if the header doesn't specify it
+	pub fn frames(&self) -> u32 {
+		match self {
+			Self::Xing(xing) => xing.frames,
+			Self::Vbri(vbri) => vbri.frames,
+		}
+	}
+
+	/// The size of the stream in bytes
+	///
+	/// NOTE: This will be `0` if the header doesn't specify it
+	pub fn size(&self) -> u32 {
+		match self {
+			Self::Xing(xing) => xing.size,
+			Self::Vbri(vbri) => vbri.size,
+		}
+	}
+
+	/// The [`LameHeader`], if one was found following a Xing header
+	pub fn lame_header(&self) -> Option<&LameHeader> {
+		match self {
+			Self::Xing(xing) => xing.lame.as_ref(),
+			Self::Vbri(_) => None,
+		}
+	}
+
 	pub(super) fn is_valid(&self) -> bool {
-		self.frames > 0 && self.size > 0
+		self.frames() > 0 && self.size() > 0
+	}
+}
+
+/// A Xing/Info header
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct XingHeader {
+	pub(crate) cbr: bool,
+	pub(crate) frames: u32,
+	pub(crate) size: u32,
+	#[cfg_attr(feature = "serde", serde(with = "serde_toc"))]
+	pub(crate) toc: Option<[u8; 100]>,
+	pub(crate) quality: Option<u32>,
+	pub(crate) lame: Option<LameHeader>,
+}
+
+impl XingHeader {
+	/// Whether the header is an `Info` header, which is written to CBR streams
+	pub fn is_cbr(&self) -> bool {
+		self.cbr
+	}
+
+	/// The number of frames in the stream
+	pub fn frames(&self) -> u32 {
+		self.frames
+	}
+
+	/// The size of the stream in bytes
+	pub fn size(&self) -> u32 {
+		self.size
+	}
+
+	/// The table of contents
+	///
+	/// Each entry `i` is the position of `i` percent of the duration, scaled to `0..=255` of the stream size.
+	pub fn toc(&self) -> Option<&[u8; 100]> {
+		self.toc.as_ref()
+	}
+
+	/// The quality indicator, from `0` (best) to `100` (worst)
+	pub fn quality(&self) -> Option<u32> {
+		self.quality
+	}
+
+	/// The LAME extension
+	pub fn lame_header(&self) -> Option<&LameHeader> {
+		self.lame.as_ref()
+	}
+}
+
+/// A VBRI header
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct VbriHeader {
+	pub(crate) version: u16,
+	pub(crate) delay: u16,
+	pub(crate) quality: u16,
+	pub(crate) frames: u32,
+	pub(crate) size: u32,
+	pub(crate) toc: Vec<u32>,
+	pub(crate) frames_per_toc_entry: u16,
+}
+
+impl VbriHeader {
+	/// The version of the header
+	pub fn version(&self) -> u16 {
+		self.version
+	}
+
+	/// The encoder delay, as stored in the header
+	pub fn delay(&self) -> u16 {
+		self.delay
+	}
+
+	/// The quality indicator
+	pub fn quality(&self) -> u16 {
+		self.quality
+	}
+
+	/// The number of frames in the stream
+	pub fn frames(&self) -> u32 {
+		self.frames
+	}
+
+	/// The size of the stream in bytes
+	pub fn size(&self) -> u32 {
+		self.size
+	}
+
+	/// The table of contents
+	///
+	/// Each entry is the size in bytes of the next [`VbriHeader::frames_per_toc_entry`] frames.
+	pub fn toc(&self) -> &[u32] {
+		&self.toc
+	}
+
+	/// The number of frames covered by each TOC entry
+	pub fn frames_per_toc_entry(&self) -> u16 {
+		self.frames_per_toc_entry
+	}
+}
+
+/// The VBR method used by LAME
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+#[allow(missing_docs)]
+#[non_exhaustive]
+pub enum LameVbrMethod {
+	Unknown,
+	Cbr,
+	Abr,
+	/// VBR, using the old (`vbr-rh`) algorithm
+	VbrRh,
+	/// VBR, using the `vbr-mtrh` algorithm
+	VbrMtrh,
+	/// VBR, using the `vbr-mt` algorithm
+	VbrMt,
+	Cbr2Pass,
+	Abr2Pass,
+	/// Any unassigned method
+	Reserved(u8),
+}
+
+impl LameVbrMethod {
+	fn from_u8(method: u8) -> Self {
+		match method {
+			0 => Self::Unknown,
+			1 => Self::Cbr,
+			2 => Self::Abr,
+			3 => Self::VbrRh,
+			4 => Self::VbrMtrh,
+			5 => Self::VbrMt,
+			8 => Self::Cbr2Pass,
+			9 => Self::Abr2Pass,
+			_ => Self::Reserved(method),
+		}
+	}
+}
+
+/// Who set a [`LameReplayGain`]
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[allow(missing_docs)]
+#[non_exhaustive]
+pub enum ReplayGainOriginator {
+	NotSet,
+	Artist,
+	User,
+	/// Set automatically by the encoder
+	Automatic,
+	/// Set by a simple RMS average
+	Rms,
+	/// Any unassigned originator
+	Reserved(u8),
+}
+
+impl ReplayGainOriginator {
+	fn from_u8(originator: u8) -> Self {
+		match originator {
+			0 => Self::NotSet,
+			1 => Self::Artist,
+			2 => Self::User,
+			3 => Self::Automatic,
+			4 => Self::Rms,
+			_ => Self::Reserved(originator),
+		}
+	}
+}
+
+/// A ReplayGain adjustment stored in a [`LameHeader`]
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct LameReplayGain {
+	pub(crate) originator: ReplayGainOriginator,
+	pub(crate) adjustment: i16,
+}
+
+impl LameReplayGain {
+	// The gain fields are made up of a name code (3 bits), originator code (3 bits),
+	// sign bit, and the absolute adjustment in 1/10 dB (9 bits)
+	fn read(field: u16, expected_name_code: u16) -> Option<Self> {
+		if field >> 13 != expected_name_code {
+			return None;
+		}
+
+		let magnitude = (field & 0x01FF) as i16;
+
+		Some(Self {
+			originator: ReplayGainOriginator::from_u8(((field >> 10) & 0x07) as u8),
+			adjustment: if field & 0x0200 == 0 {
+				magnitude
+			} else {
+				-magnitude
+			},
+		})
+	}
+
+	/// Who set the adjustment
+	pub fn originator(&self) -> ReplayGainOriginator {
+		self.originator
+	}
+
+	/// The gain adjustment in dB
+	pub fn gain(&self) -> f32 {
+		f32::from(self.adjustment) / 10.0
+	}
+}
+
+/// A LAME extension, following a [`XingHeader`]
+///
+/// Despite the name, this is also written by other encoders, such as FFmpeg (`Lavc`/`Lavf`).
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct LameHeader {
+	pub(crate) encoder: String,
+	pub(crate) revision: u8,
+	pub(crate) vbr_method: u8,
+	pub(crate) lowpass: u8,
+	pub(crate) peak: u32,
+	pub(crate) track_gain: Option<LameReplayGain>,
+	pub(crate) album_gain: Option<LameReplayGain>,
+	pub(crate) encoding_flags: u8,
+	pub(crate) ath_type: u8,
+	pub(crate) bitrate: u8,
+	pub(crate) encoder_delay: u16,
+	pub(crate) encoder_padding: u16,
+	pub(crate) preset: u16,
+	pub(crate) music_length: u32,
+	pub(crate) music_crc: u16,
+}
+
+impl LameHeader {
+	const SIZE: usize = 36;
+	const ENCODERS: [&'static [u8]; 5] = [b"LAME", b"Lavc", b"Lavf", b"L3.99", b"GOGO"];
+
+	fn read(reader: &mut &[u8]) -> Option<Self> {
+		if reader.len() < Self::SIZE {
+			return None;
+		}
+
+		let (lame, _) = reader.split_at(Self::SIZE);
+
+		let encoder = &lame[..9];
+		if !Self::ENCODERS
+			.iter()
+			.any(|prefix| encoder.starts_with(prefix))
+		{
+			return None;
+		}
+
+		// The encoder and delay/padding are the only fields that can't be read with byteorder
+		let encoder = String::from_utf8_lossy(encoder)
+			.trim_end_matches(['\0', ' '])
+			.to_string();
+		let delay_padding = u32::from_be_bytes([0, lame[21], lame[22], lame[23]]);
+
+		let mut reader = &lame[9..];
+		let (revision, vbr_method) = reader.read_u8().map(|b| (b >> 4, b & 0x0F)).ok()?;
+		let lowpass = reader.read_u8().ok()?;
+		let peak = reader.read_u32::<BigEndian>().ok()?;
+		let track_gain = LameReplayGain::read(reader.read_u16::<BigEndian>().ok()?, 1);
+		let album_gain = LameReplayGain::read(reader.read_u16::<BigEndian>().ok()?, 2);
+		let (encoding_flags, ath_type) = reader.read_u8().map(|b| (b >> 4, b & 0x0F)).ok()?;
+		let bitrate = reader.read_u8().ok()?;
+
+		// Skip the delay/padding (3), misc (1), and MP3Gain (1)
+		reader = &reader[5..];
+
+		let preset = reader.read_u16::<BigEndian>().ok()? & 0x07FF;
+		let music_length = reader.read_u32::<BigEndian>().ok()?;
+		let music_crc = reader.read_u16::<BigEndian>().ok()?;
+
+		Some(Self {
+			encoder,
+			revision,
+			vbr_method,
+			lowpass,
+			peak,
+			track_gain,
+			album_gain,
+			encoding_flags,
+			ath_type,
+			bitrate,
+			encoder_delay: (delay_padding >> 12) as u16,
+			encoder_padding: (delay_padding & 0x0FFF) as u16,
+			preset,
+			music_length,
+			music_crc,
+		})
+	}
+
+	/// The encoder string, such as `LAME3.100`
+	pub fn encoder(&self) -> &str {
+		&self.encoder
+	}
+
+	/// The revision of the LAME extension
+	pub fn revision(&self) -> u8 {
+		self.revision
+	}
+
+	/// The VBR method used
+	pub fn vbr_method(&self) -> LameVbrMethod {
+		LameVbrMethod::from_u8(self.vbr_method)
+	}
+
+	/// The lowpass filter frequency (Hz)
+	pub fn lowpass(&self) -> Option<u32> {
+		match self.lowpass {
+			0 => None,
+			lowpass => Some(u32::from(lowpass) * 100),
+		}
+	}
+
+	/// The peak signal amplitude, where `1.0` is full scale
+	pub fn peak(&self) -> Option<f32> {
+		match self.peak {
+			0 => None,
+			peak => Some(peak as f32 / (1 << 23) as f32),
+		}
+	}
+
+	/// The track (radio) ReplayGain adjustment
+	pub fn track_gain(&self) -> Option<LameReplayGain> {
+		self.track_gain
+	}
+
+	/// The album (audiophile) ReplayGain adjustment
+	pub fn album_gain(&self) -> Option<LameReplayGain> {
+		self.album_gain
+	}
+
+	/// The encoding flags (`--nspsytune`, `--nssafejoint`, `--nogap`)
+	pub fn encoding_flags(&self) -> u8 {
+		self.encoding_flags
+	}
+
+	/// The ATH type
+	pub fn ath_type(&self) -> u8 {
+		self.ath_type
+	}
+
+	/// The bitrate (kbps)
+	///
+	/// Depending on the [`LameVbrMethod`], this is the target bitrate (ABR), the bitrate (CBR),
+	/// or the minimum bitrate (VBR). A value of `255` means the bitrate is 255 kbps or higher.
+	pub fn bitrate(&self) -> u8 {
+		self.bitrate
+	}
+
+	/// The number of samples added to the start of the stream by the encoder
+	pub fn encoder_delay(&self) -> u16 {
+		self.encoder_delay
+	}
+
+	/// The number of samples added to the end of the stream by the encoder
+	pub fn encoder_padding(&self) -> u16 {
+		self.encoder_padding
+	}
+
+	/// The preset used, such as `320` for `--preset insane`
+	pub fn preset(&self) -> u16 {
+		self.preset
+	}
+
+	/// The length of the stream in bytes, including the frame holding this header
+	pub fn music_length(&self) -> u32 {
+		self.music_length
+	}
+
+	/// The CRC-16 of the audio data
+	pub fn music_crc(&self) -> u16 {
+		self.music_crc
+	}
+}
+
+// Serde only implements its traits for arrays of up to 32 elements
+#[cfg(feature = "serde")]
+mod serde_toc {
+	use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+	// The signature is required by `#[serde(with)]`
+	#[allow(clippy::ref_option)]
+	pub(super) fn serialize<S>(toc: &Option<[u8; 100]>, serializer: S) -> Result<S::Ok, S::Error>
+	where
+		S: Serializer,
+	{
+		toc.as_ref().map(|toc| &toc[..]).serialize(serializer)
+	}
+
+	pub(super) fn deserialize<'de, D>(deserializer: D) -> Result<Option<[u8; 100]>, D::Error>
+	where
+		D: Deserializer<'de>,
+	{
+		match Option::<Vec<u8>>::deserialize(deserializer)? {
+			Some(toc) => toc
+				.try_into()
+				.map(Some)
+				.map_err(|toc: Vec<u8>| serde::de::Error::invalid_length(toc.len(), &"100 bytes")),
+			None => Ok(None),
+		}
 	}
 }
 
@@ -379,4 +899,182 @@ mod tests {
 		let mut reader = Cursor::new(bytes);
 		test(&mut reader, Some(283));
 	}
+
+	#[test]
+	fn xing_lame_header() {
+		use super::{LameVbrMethod, VbrHeader};
+		use crate::mpeg::MPEGFile;
+		use crate::{AudioFile, ParseOptions};
+
+		let mut file = std::fs::File::open("tests/files/assets/minimal/full_test.mp3").unwrap();
+		let mpeg_file = MPEGFile::read_from(&mut file, ParseOptions::new()).unwrap();
+
+		let xing = match mpeg_file.properties().vbr_header() {
+			Some(VbrHeader::Xing(xing)) => xing,
+			header => panic!("Expected a Xing header, found: {header:?}"),
+		};
+
+		assert!(!xing.is_cbr());
+		assert_eq!(xing.frames(), 61);
+		assert_eq!(xing.size(), 11496);
+		assert_eq!(xing.quality(), Some(0));
+		assert!(xing.toc().is_some());
+
+		let lame = xing.lame_header().unwrap();
+		assert_eq!(lame.encoder(), "Lavc58.91");
+		assert_eq!(lame.vbr_method(), LameVbrMethod::Unknown);
+		assert_eq!(lame.lowpass(), None);
+		assert_eq!(lame.peak(), None);
+		assert_eq!(lame.track_gain(), None);
+		assert_eq!(lame.encoder_delay(), 576);
+		assert_eq!(lame.encoder_padding(), 1150);
+		assert_eq!(lame.music_length(), 11496);
+		assert_eq!(lame.music_crc(), 0x7EE9);
+	}
+
+	#[test]
+	fn lame_header_fields() {
+		use super::{LameVbrMethod, ReplayGainOriginator, VbrHeader};
+		use crate::probe::ParsingMode;
+
+		let mut xing = Vec::new();
+		xing.extend(b"Info");
+		xing.extend(0x03_u32.to_be_bytes());
+		xing.extend(100_u32.to_be_bytes());
+		xing.extend(41_800_u32.to_be_bytes());
+
+		xing.extend(b"LAME3.100");
+		// Revision 0, CBR
+		xing.push(0x01);
+		// Lowpass of 17kHz
+		xing.push(170);
+		// Peak of 0.5
+		xing.extend((1_u32 << 22).to_be_bytes());
+		// Track gain: automatic, -6.5dB
+		xing.extend(0b001_011_1_001000001_u16.to_be_bytes());
+		// Album gain: user, +2.0dB
+		xing.extend(0b010_010_0_000010100_u16.to_be_bytes());
+		// Flags and ATH type
+		xing.push(0x04);
+		// Bitrate
+		xing.push(128);
+		// Delay of 576, padding of 1000
+		xing.extend([0x24, 0x03, 0xE8]);
+		// Misc, MP3Gain
+		xing.extend([0, 0]);
+		// Preset
+		xing.extend(128_u16.to_be_bytes());
+		// Music length, music CRC, and info tag CRC
+		xing.extend(41_800_u32.to_be_bytes());
+		xing.extend(0x1234_u16.to_be_bytes());
+		xing.extend(0x5678_u16.to_be_bytes());
+
+		let header = VbrHeader::read(&mut &xing[..], ParsingMode::Strict)
+			.unwrap()
+			.unwrap();
+
+		let VbrHeader::Xing(ref xing) = header else {
+			panic!("Expected a Xing header");
+		};
+		assert!(xing.is_cbr());
+		assert_eq!(xing.toc(), None);
+		assert_eq!(xing.quality(), None);
+
+		let lame = header.lame_header().unwrap();
+		assert_eq!(lame.encoder(), "LAME3.100");
+		assert_eq!(lame.vbr_method(), LameVbrMethod::Cbr);
+		assert_eq!(lame.lowpass(), Some(17000));
+		assert_eq!(lame.peak(), Some(0.5));
+
+		let track_gain = lame.track_gain().unwrap();
+		assert_eq!(track_gain.originator(), ReplayGainOriginator::Automatic);
+		assert_eq!(track_gain.gain(), -6.5);
+
+		let album_gain = lame.album_gain().unwrap();
+		assert_eq!(album_gain.originator(), ReplayGainOriginator::User);
+		assert_eq!(album_gain.gain(), 2.0);
+
+		assert_eq!(lame.ath_type(), 4);
+		assert_eq!(lame.bitrate(), 128);
+		assert_eq!(lame.encoder_delay(), 576);
+		assert_eq!(lame.encoder_padding(), 1000);
+		assert_eq!(lame.preset(), 128);
+		assert_eq!(lame.music_length(), 41_800);
+		assert_eq!(lame.music_crc(), 0x1234);
+	}
+
+	#[test]
+	fn xing_truncated_toc() {
+		use super::VbrHeader;
+		use crate::probe::ParsingMode;
+
+		let mut xing = Vec::new();
+		xing.extend(b"Xing");
+		// Frames, size, TOC, and quality
+		xing.extend(0x0F_u32.to_be_bytes());
+		xing.extend(100_u32.to_be_bytes());
+		xing.extend(41_800_u32.to_be_bytes());
+		xing.extend([0; 50]);
+
+		assert!(VbrHeader::read(&mut &xing[..], ParsingMode::Strict).is_err());
+
+		let header = VbrHeader::read(&mut &xing[..], ParsingMode::Relaxed)
+			.unwrap()
+			.unwrap();
+
+		let VbrHeader::Xing(ref xing) = header else {
+			panic!("Expected a Xing header");
+		};
+		assert_eq!(xing.frames(), 100);
+		assert_eq!(xing.size(), 41_800);
+		assert_eq!(xing.toc(), None);
+		assert_eq!(xing.quality(), None);
+		assert!(xing.lame_header().is_none());
+	}
+
+	#[test]
+	fn vbri_header() {
+		use super::VbrHeader;
+		use crate::probe::ParsingMode;
+
+		let mut vbri = Vec::new();
+		vbri.extend(b"VBRI");
+		// Version, delay, quality
+		vbri.extend([0, 1, 0x15, 0x7C, 0, 75]);
+		// Size and frame count
+		vbri.extend(3000_u32.to_be_bytes());
+		vbri.extend(30_u32.to_be_bytes());
+		// TOC entries, scale, entry size, frames per entry
+		vbri.extend([0, 3, 0, 2, 0, 2, 0, 10]);
+		vbri.extend([0x01, 0xF4, 0x01, 0xF4, 0x01, 0xF4]);
+
+		let header = VbrHeader::read(&mut &vbri[..], ParsingMode::Strict)
+			.unwrap()
+			.unwrap();
+
+		let VbrHeader::Vbri(ref vbri) = header else {
+			panic!("Expected a VBRI header");
+		};
+		assert_eq!(vbri.version(), 1);
+		assert_eq!(vbri.delay(), 0x157C);
+		assert_eq!(vbri.quality(), 75);
+		assert_eq!(vbri.frames(), 30);
+		assert_eq!(vbri.size(), 3000);
+		assert_eq!(vbri.toc(), &[1000, 1000, 1000]);
+		assert_eq!(vbri.frames_per_toc_entry(), 10);
+
+		assert!(header.lame_header().is_none());
+
+		// Truncated TOC
+		assert!(VbrHeader::read(&mut &vbri_truncated()[..], ParsingMode::Strict).is_err());
+
+		fn vbri_truncated() -> Vec<u8> {
+			let mut vbri = Vec::new();
+			vbri.extend(b"VBRI");
+			vbri.extend([0; 14]);
+			vbri.extend([0, 3, 0, 1, 0, 2, 0, 10]);
+			vbri.extend([0x01, 0xF4]);
+			vbri
+		}
+	}
 }
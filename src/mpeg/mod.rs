@@ -3,18 +3,29 @@ mod constants;
 pub(crate) mod header;
 mod properties;
 mod read;
+mod verify;
 pub(crate) mod write;
 
-pub use header::{ChannelMode, Emphasis, Layer, MpegVersion};
+pub use header::{
+	BitrateMode, ChannelMode, Emphasis, LameHeader, LameReplayGain, LameVbrMethod, Layer,
+	MpegVersion, ReplayGainOriginator, VbrHeader, VbriHeader, XingHeader,
+};
 pub use properties::MPEGProperties;
+pub use verify::{StreamProblem, StreamReport};
 
 #[cfg(feature = "ape")]
 use crate::ape::tag::ApeTag;
+#[cfg(feature = "lyrics3")]
+use crate::id3::lyrics3::tag::Lyrics3Tag;
 #[cfg(feature = "id3v1")]
 use crate::id3::v1::tag::ID3v1Tag;
 #[cfg(feature = "id3v2")]
 use crate::id3::v2::tag::ID3v2Tag;
 
+use crate::error::Result;
+
+use std::io::{Read, Seek};
+
 use lofty_attr::LoftyFile;
 
 /// An MPEG file
@@ -29,6 +40,10 @@ pub struct MPEGFile {
 	#[cfg(feature = "id3v1")]
 	#[lofty(tag_type = "ID3v1")]
 	pub(crate) id3v1_tag: Option<ID3v1Tag>,
+	/// A Lyrics3v1/v2 tag
+	#[cfg(feature = "lyrics3")]
+	#[lofty(tag_type = "Lyrics3")]
+	pub(crate) lyrics3_tag: Option<Lyrics3Tag>,
 	/// An APEv1/v2 tag
 	#[cfg(feature = "ape")]
 	#[lofty(tag_type = "APE")]
@@ -36,3 +51,34 @@ pub struct MPEGFile {
 	/// The file's audio properties
 	pub(crate) properties: MPEGProperties,
 }
+
+impl MPEGFile {
+	/// Walk every frame of an MPEG stream, checking its integrity
+	///
+	/// This does not stop at the first problem, see [`StreamReport`] and [`StreamProblem`].
+	///
+	/// # Errors
+	///
+	/// * `reader` contains an invalid tag
+	/// * An IO error occurs
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use lofty::mpeg::MPEGFile;
+	///
+	/// # fn main() -> lofty::Result<()> {
+	/// # let path = "tests/files/assets/minimal/full_test.mp3";
+	/// let mut file = std::fs::File::open(path)?;
+	///
+	/// let report = MPEGFile::verify(&mut file)?;
+	/// assert!(report.is_valid());
+	/// # Ok(()) }
+	/// ```
+	pub fn verify<R>(reader: &mut R) -> Result<StreamReport>
+	where
+		R: Read + Seek,
+	{
+		verify::verify(reader)
+	}
+}
@@ -1,15 +1,21 @@
-use super::header::{ChannelMode, Emphasis, Header, Layer, MpegVersion, XingHeader};
+use super::header::{
+	BitrateMode, ChannelMode, Emphasis, Header, LameHeader, LameVbrMethod, Layer, MpegVersion,
+	VbrHeader,
+};
 use crate::error::Result;
-use crate::mpeg::header::{cmp_header, rev_search_for_frame_sync, HeaderCmpResult};
-use crate::probe::ParseOptions;
+use crate::mpeg::header::{
+	cmp_header, rev_search_for_frame_sync, search_for_frame_sync, HeaderCmpResult, HEADER_MASK,
+};
+use crate::probe::{ParseOptions, ParsingMode};
 use crate::properties::FileProperties;
 
-use std::io::{Read, Seek, SeekFrom};
+use std::io::{BufReader, Read, Seek, SeekFrom};
 use std::time::Duration;
 
 use byteorder::{BigEndian, ReadBytesExt};
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[non_exhaustive]
 /// An MPEG file's audio properties
 pub struct MPEGProperties {
@@ -25,6 +31,9 @@ pub struct MPEGProperties {
 	pub(crate) copyright: bool,
 	pub(crate) original: bool,
 	pub(crate) emphasis: Emphasis,
+	pub(crate) vbr_header: Option<VbrHeader>,
+	pub(crate) frame_count: Option<u32>,
+	pub(crate) bitrate_mode: Option<BitrateMode>,
 }
 
 impl From<MPEGProperties> for FileProperties {
@@ -100,6 +109,33 @@ impl MPEGProperties {
 	pub fn emphasis(&self) -> Emphasis {
 		self.emphasis
 	}
+
+	/// The Xing or VBRI header in the first frame, if one was found
+	pub fn vbr_header(&self) -> Option<&VbrHeader> {
+		self.vbr_header.as_ref()
+	}
+
+	/// The LAME extension of the Xing header, if one was found
+	///
+	/// This holds the encoder delay and padding needed for gapless playback.
+	pub fn lame_header(&self) -> Option<&LameHeader> {
+		self.vbr_header.as_ref().and_then(VbrHeader::lame_header)
+	}
+
+	/// The number of audio frames
+	///
+	/// This is taken from the Xing/VBRI header, or counted exactly when
+	/// [`ParseOptions::scan_mpeg_frames`] is enabled. Otherwise, it is unknown.
+	pub fn frame_count(&self) -> Option<u32> {
+		self.frame_count
+	}
+
+	/// Whether the stream is CBR, VBR, or ABR
+	///
+	/// Without [`ParseOptions::scan_mpeg_frames`], this is only known if the stream has a Xing/VBRI header.
+	pub fn bitrate_mode(&self) -> Option<BitrateMode> {
+		self.bitrate_mode
+	}
 }
 
 pub(super) fn read_properties<R>(
@@ -108,7 +144,7 @@ pub(super) fn read_properties<R>(
 	parse_options: ParseOptions,
 	first_frame: (Header, u64),
 	mut last_frame_offset: u64,
-	xing_header: Option<XingHeader>,
+	vbr_header: Option<VbrHeader>,
 	file_length: u64,
 ) -> Result<()>
 where
@@ -116,6 +152,7 @@ where
 {
 	let first_frame_header = first_frame.0;
 	let first_frame_offset = first_frame.1;
+	let stream_end = last_frame_offset;
 
 	properties.version = first_frame_header.version;
 	properties.layer = first_frame_header.layer;
@@ -131,15 +168,15 @@ where
 		2
 	};
 
-	match xing_header {
-		Some(xing_header) if first_frame_header.sample_rate > 0 && xing_header.is_valid() => {
+	match vbr_header {
+		Some(ref vbr_header) if first_frame_header.sample_rate > 0 && vbr_header.is_valid() => {
 			let frame_time =
 				u32::from(first_frame_header.samples) * 1000 / first_frame_header.sample_rate;
-			let length = u64::from(frame_time) * u64::from(xing_header.frames);
+			let length = u64::from(frame_time) * u64::from(vbr_header.frames());
 
 			properties.duration = Duration::from_millis(length);
 			properties.overall_bitrate = ((file_length * 8) / length) as u32;
-			properties.audio_bitrate = ((u64::from(xing_header.size) * 8) / length) as u32;
+			properties.audio_bitrate = ((u64::from(vbr_header.size()) * 8) / length) as u32;
 		},
 		_ if first_frame_header.bitrate > 0 => {
 			properties.audio_bitrate = first_frame_header.bitrate;
@@ -191,5 +228,130 @@ where
 		_ => {},
 	}
 
+	if let Some(ref vbr_header) = vbr_header {
+		if vbr_header.frames() > 0 {
+			properties.frame_count = Some(vbr_header.frames());
+		}
+
+		properties.bitrate_mode = Some(match vbr_header {
+			VbrHeader::Xing(xing) if xing.is_cbr() => BitrateMode::Cbr,
+			_ => lame_bitrate_mode(vbr_header.lame_header()).unwrap_or(BitrateMode::Vbr),
+		});
+	}
+
+	if parse_options.scan_mpeg_frames {
+		let scan = scan_frames(reader, first_frame_offset, stream_end, vbr_header.is_some())?;
+
+		let length = scan.samples * 1000 / u64::from(first_frame_header.sample_rate);
+		if length > 0 {
+			properties.duration = Duration::from_millis(length);
+			properties.overall_bitrate = ((file_length * 8) / length) as u32;
+			properties.audio_bitrate = ((scan.stream_len * 8) / length) as u32;
+			properties.frame_count = Some(scan.frames);
+			properties.bitrate_mode = Some(if scan.bitrate_varies {
+				match lame_bitrate_mode(vbr_header.as_ref().and_then(VbrHeader::lame_header)) {
+					Some(BitrateMode::Abr) => BitrateMode::Abr,
+					_ => BitrateMode::Vbr,
+				}
+			} else {
+				BitrateMode::Cbr
+			});
+		}
+	}
+
+	properties.vbr_header = vbr_header;
+
 	Ok(())
 }
+
+fn lame_bitrate_mode(lame_header: Option<&LameHeader>) -> Option<BitrateMode> {
+	match lame_header?.vbr_method() {
+		LameVbrMethod::Cbr | LameVbrMethod::Cbr2Pass => Some(BitrateMode::Cbr),
+		LameVbrMethod::Abr | LameVbrMethod::Abr2Pass => Some(BitrateMode::Abr),
+		LameVbrMethod::VbrRh | LameVbrMethod::VbrMtrh | LameVbrMethod::VbrMt => {
+			Some(BitrateMode::Vbr)
+		},
+		_ => None,
+	}
+}
+
+#[derive(Default)]
+struct FrameScan {
+	frames: u32,
+	samples: u64,
+	stream_len: u64,
+	bitrate_varies: bool,
+}
+
+// Walks every frame from `first_frame_offset` to `stream_end`, skipping any junk between frames.
+//
+// Only frames with the same version, layer, and sample rate as the first frame are counted, to avoid
+// false frame syncs in the junk. A truncated final frame is not counted.
+fn scan_frames<R>(
+	reader: &mut R,
+	first_frame_offset: u64,
+	stream_end: u64,
+	skip_first_frame: bool,
+) -> Result<FrameScan>
+where
+	R: Read + Seek,
+{
+	reader.seek(SeekFrom::Start(first_frame_offset))?;
+
+	// The frames are small, so reading them without a buffer would be very slow
+	let mut reader = BufReader::new(reader);
+
+	let first_frame_data = reader.read_u32::<BigEndian>()?;
+	reader.seek_relative(-4)?;
+
+	let mut scan = FrameScan::default();
+	let mut first_bitrate = None;
+	let mut pos = first_frame_offset;
+
+	while pos + 4 <= stream_end {
+		let frame_data = reader.read_u32::<BigEndian>()?;
+
+		let header = if frame_data & HEADER_MASK == first_frame_data & HEADER_MASK {
+			Header::read(frame_data, ParsingMode::Relaxed)?
+		} else {
+			None
+		};
+
+		match header {
+			Some(header) if pos + u64::from(header.len) <= stream_end => {
+				if pos != first_frame_offset || !skip_first_frame {
+					scan.frames += 1;
+					scan.samples += u64::from(header.samples);
+					scan.stream_len += u64::from(header.len);
+
+					match first_bitrate {
+						Some(bitrate) if bitrate != header.bitrate => scan.bitrate_varies = true,
+						None => first_bitrate = Some(header.bitrate),
+						_ => {},
+					}
+				}
+
+				pos += u64::from(header.len);
+				reader.seek_relative(i64::from(header.len) - 4)?;
+			},
+			// The final frame is truncated
+			Some(_) => break,
+			// Lost sync, search for the next frame starting at the next byte
+			None => {
+				reader.seek_relative(-3)?;
+				pos += 1;
+
+				match search_for_frame_sync(&mut (&mut reader).take(stream_end - pos))? {
+					Some(offset) => {
+						pos += offset;
+						// Seek back to the start of the frame sync
+						reader.seek_relative(-2)?;
+					},
+					None => break,
+				}
+			},
+		}
+	}
+
+	Ok(scan)
+}
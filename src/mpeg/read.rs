@@ -1,4 +1,4 @@
-use super::header::{cmp_header, search_for_frame_sync, Header, HeaderCmpResult, XingHeader};
+use super::header::{cmp_header, search_for_frame_sync, Header, HeaderCmpResult, VbrHeader};
 use super::{MPEGFile, MPEGProperties};
 use crate::ape::constants::APE_PREAMBLE;
 use crate::ape::header::read_ape_header;
@@ -8,7 +8,9 @@ use crate::error::Result;
 #[cfg(feature = "id3v2")]
 use crate::id3::v2::read::parse_id3v2;
 use crate::id3::v2::read_id3v2_header;
-use crate::id3::{find_id3v1, find_lyrics3v2, ID3FindResults};
+#[cfg(feature = "id3v2")]
+use crate::id3::v2::{Frame, FrameValue, ID3v2Tag, ID3v2TagLocation};
+use crate::id3::{find_appended_id3v2, find_id3v1, find_lyrics3, ID3FindResults};
 use crate::macros::{decode_err, err};
 use crate::probe::{ParseOptions, ParsingMode};
 
@@ -17,6 +19,64 @@ use std::io::{Read, Seek, SeekFrom};
 use byteorder::{BigEndian, ReadBytesExt};
 
 pub(super) fn read_from<R>(reader: &mut R, parse_options: ParseOptions) -> Result<MPEGFile>
+where
+	R: Read + Seek,
+{
+	let (mut file, first_frame, last_frame_offset) = read_tags(reader, parse_options)?;
+
+	file.properties = MPEGProperties::default();
+
+	if parse_options.read_properties {
+		let (first_frame_header, first_frame_offset) = match first_frame {
+			Some(first_frame) => first_frame,
+			// The search for sync bits was unsuccessful
+			None => decode_err!(@BAIL MPEG, "File contains an invalid frame"),
+		};
+
+		if first_frame_header.sample_rate == 0 {
+			decode_err!(@BAIL MPEG, "Sample rate is 0");
+		}
+
+		// Try to read a Xing/VBRI header, which can take up the rest of the first frame
+		let vbr_header_location = first_frame_offset + u64::from(first_frame_header.data_start);
+		reader.seek(SeekFrom::Start(vbr_header_location))?;
+
+		let vbr_header_len = first_frame_header
+			.len
+			.saturating_sub(first_frame_header.data_start)
+			.max(32);
+
+		let mut vbr_reader = Vec::new();
+		reader
+			.by_ref()
+			.take(u64::from(vbr_header_len))
+			.read_to_end(&mut vbr_reader)?;
+
+		let vbr_header = VbrHeader::read(&mut &vbr_reader[..], parse_options.parsing_mode)?;
+
+		let file_length = reader.seek(SeekFrom::End(0))?;
+
+		super::properties::read_properties(
+			&mut file.properties,
+			reader,
+			parse_options,
+			(first_frame_header, first_frame_offset),
+			last_frame_offset,
+			vbr_header,
+			file_length,
+		)?;
+	}
+
+	Ok(file)
+}
+
+// Reads all of the tags, and locates the audio stream
+//
+// This returns the first frame (if one was found) and its offset, along with the end of the stream
+pub(super) fn read_tags<R>(
+	reader: &mut R,
+	parse_options: ParseOptions,
+) -> Result<(MPEGFile, Option<(Header, u64)>, u64)>
 where
 	R: Read + Seek,
 {
@@ -25,6 +85,11 @@ where
 	let mut first_frame_offset = 0;
 	let mut first_frame_header = None;
 
+	// The offsets of the prepended ID3v2 tag, and an update tag found through a `SEEK` frame
+	let mut id3v2_offset = None;
+	#[allow(unused_mut)]
+	let mut id3v2_update_offset: Option<u64> = None;
+
 	// Skip any invalid padding
 	while reader.read_u8()? == 0 {}
 
@@ -37,14 +102,14 @@ where
 			// [I, D, 3, ver_major, ver_minor, flags, size (4 bytes)]
 			[b'I', b'D', b'3', ..] => {
 				// Seek back to read the tag in full
-				reader.seek(SeekFrom::Current(-4))?;
+				id3v2_offset = Some(reader.seek(SeekFrom::Current(-4))?);
 
 				let header = read_id3v2_header(reader)?;
 				let skip_footer = header.flags.footer;
 
 				#[cfg(feature = "id3v2")]
 				{
-					let id3v2 = parse_id3v2(reader, header)?;
+					let id3v2 = parse_id3v2(reader, header, parse_options.parsing_mode)?;
 					file.id3v2_tag = Some(id3v2);
 				}
 
@@ -53,6 +118,12 @@ where
 					reader.seek(SeekFrom::Current(10))?;
 				}
 
+				#[cfg(feature = "id3v2")]
+				{
+					id3v2_update_offset =
+						read_id3v2_seek_update(reader, &mut file, parse_options.parsing_mode)?;
+				}
+
 				continue;
 			},
 			[b'A', b'P', b'E', b'T'] => {
@@ -98,6 +169,25 @@ where
 		}
 	}
 
+	// An ID3v2.4 tag may also be appended to the file, located by its footer
+	let ID3FindResults(appended_id3v2_header, appended_id3v2_offset) = find_appended_id3v2(reader)?;
+
+	// If there is no audio, the prepended tag could be found again
+	let appended_id3v2_offset = appended_id3v2_header
+		.and(Some(appended_id3v2_offset))
+		.filter(|offset| Some(*offset) != id3v2_offset);
+
+	#[cfg(feature = "id3v2")]
+	if let Some(appended_id3v2_offset) =
+		appended_id3v2_offset.filter(|offset| Some(*offset) != id3v2_update_offset)
+	{
+		reader.seek(SeekFrom::Start(appended_id3v2_offset))?;
+
+		let header = read_id3v2_header(reader)?;
+		let id3v2 = parse_id3v2(reader, header, parse_options.parsing_mode)?;
+		merge_id3v2_update(&mut file, id3v2);
+	}
+
 	#[allow(unused_variables)]
 	let ID3FindResults(header, id3v1) = find_id3v1(reader, true)?;
 
@@ -106,7 +196,13 @@ where
 		file.id3v1_tag = id3v1;
 	}
 
-	let _ = find_lyrics3v2(reader)?;
+	#[allow(unused_variables)]
+	let ID3FindResults(header, lyrics3) = find_lyrics3(reader, true, parse_options.parsing_mode)?;
+
+	#[cfg(feature = "lyrics3")]
+	if header.is_some() {
+		file.lyrics3_tag = lyrics3;
+	}
 
 	reader.seek(SeekFrom::Current(-32))?;
 
@@ -134,45 +230,77 @@ where
 		},
 	}
 
-	let last_frame_offset = reader.stream_position()?;
-	file.properties = MPEGProperties::default();
+	let mut last_frame_offset = reader.stream_position()?;
 
-	if parse_options.read_properties {
-		let first_frame_header = match first_frame_header {
-			Some(header) => header,
-			// The search for sync bits was unsuccessful
-			None => decode_err!(@BAIL MPEG, "File contains an invalid frame"),
-		};
+	if let Some(appended_id3v2_offset) = appended_id3v2_offset {
+		last_frame_offset = std::cmp::min(last_frame_offset, appended_id3v2_offset);
+	}
+	let first_frame = first_frame_header.map(|header| (header, first_frame_offset));
 
-		if first_frame_header.sample_rate == 0 {
-			decode_err!(@BAIL MPEG, "Sample rate is 0");
-		}
+	Ok((file, first_frame, last_frame_offset))
+}
 
-		let first_frame_offset = first_frame_offset;
+// Follows a `SEEK` frame in a prepended ID3v2 tag, which points to an update tag further in the stream
+//
+// The reader's position is unchanged, and the offset of the update tag is returned if one was read
+#[cfg(feature = "id3v2")]
+fn read_id3v2_seek_update<R>(
+	reader: &mut R,
+	file: &mut MPEGFile,
+	parse_mode: ParsingMode,
+) -> Result<Option<u64>>
+where
+	R: Read + Seek,
+{
+	let tag = match file.id3v2_tag {
+		Some(ref mut tag) => tag,
+		None => return Ok(None),
+	};
+
+	let offset = match tag.get("SEEK").map(Frame::content) {
+		Some(FrameValue::Binary(content)) if content.len() >= 4 => {
+			u32::from_be_bytes([content[0], content[1], content[2], content[3]])
+		},
+		_ => return Ok(None),
+	};
 
-		// Try to read a Xing header
-		let xing_header_location = first_frame_offset + u64::from(first_frame_header.data_start);
-		reader.seek(SeekFrom::Start(xing_header_location))?;
+	// The frame only describes the layout of the file, it has no use once the tags are merged
+	tag.remove("SEEK");
 
-		let mut xing_reader = [0; 32];
-		reader.read_exact(&mut xing_reader)?;
+	// The offset is relative to the end of the tag
+	let tag_end = reader.stream_position()?;
+	let update_offset = tag_end + u64::from(offset);
 
-		let xing_header = XingHeader::read(&mut &xing_reader[..], parse_options.parsing_mode)?;
+	reader.seek(SeekFrom::Start(update_offset))?;
 
-		let file_length = reader.seek(SeekFrom::End(0))?;
+	let mut ret = None;
+	if let Ok(header) = read_id3v2_header(reader) {
+		let update = parse_id3v2(reader, header, parse_mode)?;
+		merge_id3v2_update(file, update);
 
-		super::properties::read_properties(
-			&mut file.properties,
-			reader,
-			parse_options,
-			(first_frame_header, first_frame_offset),
-			last_frame_offset,
-			xing_header,
-			file_length,
-		)?;
+		ret = Some(update_offset);
 	}
 
-	Ok(file)
+	reader.seek(SeekFrom::Start(tag_end))?;
+	Ok(ret)
+}
+
+// Frames in an update tag replace those of the same ID in the existing tag
+#[cfg(feature = "id3v2")]
+fn merge_id3v2_update(file: &mut MPEGFile, mut update: ID3v2Tag) {
+	match file.id3v2_tag {
+		Some(ref mut existing) => {
+			for frame in update {
+				existing.insert(frame);
+			}
+
+			existing.location = ID3v2TagLocation::PrependedAndAppended;
+		},
+		None => {
+			update.location = ID3v2TagLocation::Appended;
+			file.id3v2_tag = Some(update);
+		},
+	}
 }
 
 // Searches for the next frame, comparing it to the following one
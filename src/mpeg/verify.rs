@@ -0,0 +1,334 @@
+use super::header::{verify_frame_sync, Header, Layer, HEADER_MASK};
+use super::read::read_tags;
+use crate::error::Result;
+use crate::mpeg::ChannelMode;
+use crate::probe::{ParseOptions, ParsingMode};
+
+use std::io::{BufReader, Read, Seek};
+
+/// A problem found in an MPEG stream by [`MPEGFile::verify`](crate::mpeg::MPEGFile::verify)
+///
+/// All offsets are absolute positions in the file.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum StreamProblem {
+	/// The final frame extends past the end of the stream
+	TruncatedFrame {
+		/// The offset of the frame
+		offset: u64,
+		/// The length of the frame, according to its header
+		expected_len: u32,
+		/// The number of bytes available
+		available_len: u64,
+	},
+	/// A region containing frame syncs that don't belong to valid frames, such as corrupted frame headers
+	LostSync {
+		/// The offset of the region
+		offset: u64,
+		/// The length of the region
+		len: u64,
+	},
+	/// A region between frames that doesn't contain a frame sync
+	Junk {
+		/// The offset of the region
+		offset: u64,
+		/// The length of the region
+		len: u64,
+	},
+	/// The layer changed from one frame to the next
+	LayerChange {
+		/// The offset of the first frame with the new layer
+		offset: u64,
+		/// The previous layer
+		from: Layer,
+		/// The new layer
+		to: Layer,
+	},
+	/// The sample rate changed from one frame to the next
+	SampleRateChange {
+		/// The offset of the first frame with the new sample rate
+		offset: u64,
+		/// The previous sample rate
+		from: u32,
+		/// The new sample rate
+		to: u32,
+	},
+	/// The CRC of a protected frame doesn't match its contents
+	CrcMismatch {
+		/// The offset of the frame
+		offset: u64,
+		/// The CRC stored in the frame
+		expected: u16,
+		/// The CRC calculated from the frame's contents
+		actual: u16,
+	},
+}
+
+/// The result of [`MPEGFile::verify`](crate::mpeg::MPEGFile::verify)
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct StreamReport {
+	pub(crate) stream_start: u64,
+	pub(crate) stream_end: u64,
+	pub(crate) frames: u32,
+	pub(crate) crc_checked_frames: u32,
+	pub(crate) problems: Vec<StreamProblem>,
+}
+
+impl StreamReport {
+	/// The offset of the first frame
+	pub fn stream_start(&self) -> u64 {
+		self.stream_start
+	}
+
+	/// The offset of the end of the stream, before any trailing tags
+	pub fn stream_end(&self) -> u64 {
+		self.stream_end
+	}
+
+	/// The number of complete frames found
+	pub fn frames(&self) -> u32 {
+		self.frames
+	}
+
+	/// The number of protected frames that had their CRC checked
+	///
+	/// NOTE: The CRCs of Layer II frames are not checked.
+	pub fn crc_checked_frames(&self) -> u32 {
+		self.crc_checked_frames
+	}
+
+	/// Every problem found, in the order they appear in the stream
+	pub fn problems(&self) -> &[StreamProblem] {
+		&self.problems
+	}
+
+	/// Whether the stream has frames, and no problems were found
+	pub fn is_valid(&self) -> bool {
+		self.frames > 0 && self.problems.is_empty()
+	}
+}
+
+pub(super) fn verify<R>(reader: &mut R) -> Result<StreamReport>
+where
+	R: Read + Seek,
+{
+	let parse_options = ParseOptions::new()
+		.read_properties(false)
+		.parsing_mode(ParsingMode::Relaxed);
+
+	let (_, first_frame, stream_end) = read_tags(reader, parse_options)?;
+
+	let mut report = StreamReport {
+		stream_end,
+		..StreamReport::default()
+	};
+
+	let (mut current_header, stream_start) = match first_frame {
+		Some(first_frame) => first_frame,
+		None => return Ok(report),
+	};
+
+	report.stream_start = stream_start;
+
+	reader.seek(std::io::SeekFrom::Start(stream_start))?;
+	let mut reader = StreamReader {
+		reader: BufReader::new(reader),
+		pos: stream_start,
+	};
+
+	let mut current_header_data = reader.read_u32_at(stream_start)?;
+
+	// The start of a region without valid frames, and whether it contains any frame syncs
+	let mut invalid_region: Option<(u64, bool)> = None;
+
+	let mut pos = stream_start;
+	while pos + 4 <= stream_end {
+		let header_data = reader.read_u32_at(pos)?;
+		let frame_sync = verify_frame_sync([(header_data >> 24) as u8, (header_data >> 16) as u8]);
+
+		let mut header = None;
+		if frame_sync {
+			header = Header::read(header_data, ParsingMode::Relaxed)?;
+		}
+
+		// A frame with a different format is only accepted if the following frame shares it,
+		// otherwise it is likely a false frame sync
+		if let Some(ref new_header) = header {
+			if header_data & HEADER_MASK != current_header_data & HEADER_MASK {
+				let next_frame = pos + u64::from(new_header.len);
+				let confirmed = next_frame + 4 <= stream_end && {
+					let next_header_data = reader.read_u32_at(next_frame)?;
+					next_header_data & HEADER_MASK == header_data & HEADER_MASK
+						&& Header::read(next_header_data, ParsingMode::Relaxed)?.is_some()
+				};
+
+				if !confirmed {
+					header = None;
+				}
+			}
+		}
+
+		let header = match header {
+			Some(header) => header,
+			None => {
+				let region = invalid_region.get_or_insert((pos, false));
+				region.1 |= frame_sync;
+
+				pos += 1;
+				continue;
+			},
+		};
+
+		if let Some(region) = invalid_region.take() {
+			report.problems.push(invalid_region_problem(region, pos));
+		}
+
+		if header.layer != current_header.layer {
+			report.problems.push(StreamProblem::LayerChange {
+				offset: pos,
+				from: current_header.layer,
+				to: header.layer,
+			});
+		}
+
+		if header.sample_rate != current_header.sample_rate {
+			report.problems.push(StreamProblem::SampleRateChange {
+				offset: pos,
+				from: current_header.sample_rate,
+				to: header.sample_rate,
+			});
+		}
+
+		current_header = header;
+		current_header_data = header_data;
+
+		let frame_end = pos + u64::from(header.len);
+		if frame_end > stream_end {
+			report.problems.push(StreamProblem::TruncatedFrame {
+				offset: pos,
+				expected_len: header.len,
+				available_len: stream_end - pos,
+			});
+
+			pos = stream_end;
+			break;
+		}
+
+		if header.protected {
+			if let Some(protected_len) = crc_protected_len(&header) {
+				let mut frame = vec![0; 6 + protected_len];
+				reader.read_exact_at(pos, &mut frame)?;
+
+				let expected = u16::from_be_bytes([frame[4], frame[5]]);
+				let actual = crc16(frame[2..4].iter().chain(&frame[6..]));
+
+				if expected != actual {
+					report.problems.push(StreamProblem::CrcMismatch {
+						offset: pos,
+						expected,
+						actual,
+					});
+				}
+
+				report.crc_checked_frames += 1;
+			}
+		}
+
+		report.frames += 1;
+		pos = frame_end;
+	}
+
+	// Anything left over is too small to be a frame
+	if pos < stream_end && invalid_region.is_none() {
+		invalid_region = Some((pos, false));
+	}
+
+	if let Some(region) = invalid_region {
+		report
+			.problems
+			.push(invalid_region_problem(region, stream_end));
+	}
+
+	Ok(report)
+}
+
+fn invalid_region_problem((offset, has_frame_sync): (u64, bool), end: u64) -> StreamProblem {
+	let len = end - offset;
+	if has_frame_sync {
+		StreamProblem::LostSync { offset, len }
+	} else {
+		StreamProblem::Junk { offset, len }
+	}
+}
+
+// The number of bytes following the CRC that are covered by it
+//
+// Layer II is not supported, as its bit allocation depends on tables for each bitrate and sample rate
+fn crc_protected_len(header: &Header) -> Option<usize> {
+	match header.layer {
+		// The side information
+		Layer::Layer3 => Some((header.data_start - 4) as usize),
+		// The bit allocation, 4 bits for each of the 32 subbands in each channel.
+		// In joint stereo, the subbands from the bound upwards are shared between the channels.
+		Layer::Layer1 => match header.channel_mode {
+			ChannelMode::SingleChannel => Some(16),
+			ChannelMode::JointStereo => {
+				let bound = 4 * (usize::from(header.mode_extension.unwrap_or(0)) + 1);
+				Some((4 * (32 + bound)) / 8)
+			},
+			_ => Some(32),
+		},
+		Layer::Layer2 => None,
+	}
+}
+
+// CRC-16 with the polynomial 0x8005 and an initial value of 0xFFFF
+fn crc16<'a>(data: impl IntoIterator<Item = &'a u8>) -> u16 {
+	let mut crc = 0xFFFF_u16;
+
+	for byte in data {
+		crc ^= u16::from(*byte) << 8;
+
+		for _ in 0..8 {
+			crc = if crc & 0x8000 == 0 {
+				crc << 1
+			} else {
+				(crc << 1) ^ 0x8005
+			};
+		}
+	}
+
+	crc
+}
+
+// A buffered reader that allows for cheap seeks to nearby positions
+struct StreamReader<R> {
+	reader: BufReader<R>,
+	pos: u64,
+}
+
+impl<R: Read + Seek> StreamReader<R> {
+	fn read_exact_at(&mut self, pos: u64, buf: &mut [u8]) -> std::io::Result<()> {
+		self.reader.seek_relative(pos as i64 - self.pos as i64)?;
+		self.reader.read_exact(buf)?;
+
+		self.pos = pos + buf.len() as u64;
+		Ok(())
+	}
+
+	fn read_u32_at(&mut self, pos: u64) -> std::io::Result<u32> {
+		let mut buf = [0; 4];
+		self.read_exact_at(pos, &mut buf)?;
+
+		Ok(u32::from_be_bytes(buf))
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	#[test]
+	fn crc16() {
+		// CRC-16/CMS check value
+		assert_eq!(super::crc16(b"123456789"), 0xAEE7);
+	}
+}
@@ -1,6 +1,8 @@
 #[cfg(feature = "ape")]
 use crate::ape;
 use crate::error::Result;
+#[cfg(feature = "lyrics3")]
+use crate::id3::lyrics3;
 #[cfg(feature = "id3v1")]
 use crate::id3::v1;
 #[cfg(feature = "id3v2")]
@@ -8,6 +10,8 @@ use crate::id3::v2;
 use crate::macros::err;
 #[allow(unused_imports)]
 use crate::tag::{Tag, TagType};
+#[cfg(feature = "id3v2")]
+use crate::traits::TagExt;
 
 use std::fs::File;
 
@@ -22,6 +26,11 @@ pub(crate) fn write_to(data: &mut File, tag: &Tag) -> Result<()> {
 		.write_to(data),
 		#[cfg(feature = "id3v1")]
 		TagType::ID3v1 => Into::<v1::tag::Id3v1TagRef<'_>>::into(tag).write_to(data),
+		#[cfg(feature = "lyrics3")]
+		TagType::Lyrics3 => Into::<lyrics3::tag::Lyrics3TagRef<'_>>::into(tag).write_to(data),
+		// Native data has to be written through the original format, see `Tag#native-data`
+		#[cfg(feature = "id3v2")]
+		TagType::ID3v2 if tag.has_passthrough() => v2::ID3v2Tag::from(tag.clone()).save_to(data),
 		#[cfg(feature = "id3v2")]
 		TagType::ID3v2 => v2::tag::Id3v2TagRef {
 			flags: v2::ID3v2TagFlags::default(),
@@ -12,9 +12,9 @@ pub(crate) mod vorbis;
 use crate::error::Result;
 use crate::macros::decode_err;
 
-use std::io::{Read, Seek};
+use std::io::{Read, Seek, SeekFrom};
 
-use ogg_pager::Page;
+use ogg_pager::{Page, CONTAINS_FIRST_PAGE_OF_BITSTREAM};
 
 // Exports
 
@@ -56,3 +56,95 @@ where
 
 	Ok(last_page)
 }
+
+// The duration (in milliseconds) of a possibly chained stream, from the granule positions of its pages
+//
+// Each link in a chain starts with a beginning-of-stream page holding the codec's identification header,
+// which `link_sample_rate` gets the sample rate from. Beginning-of-stream pages that it rejects belong to
+// other multiplexed streams, and are ignored.
+//
+// The duration of a link is the difference between the granule positions of its first and last pages.
+// Pages with a granule position of -1 (no packets end on the page) are skipped.
+//
+// This will return `None` if no link has a usable granule position.
+fn chained_stream_duration<R>(
+	data: &mut R,
+	first_page: &Page,
+	sample_rate: u32,
+	link_sample_rate: fn(&[u8]) -> Option<u32>,
+) -> Result<Option<f64>>
+where
+	R: Read + Seek,
+{
+	struct Link {
+		serial: u32,
+		sample_rate: u32,
+		granules: Option<(u64, u64)>,
+	}
+
+	impl Link {
+		fn new(page: &Page, sample_rate: u32) -> Self {
+			let mut link = Self {
+				serial: page.serial,
+				sample_rate,
+				granules: None,
+			};
+
+			link.push(page);
+			link
+		}
+
+		fn push(&mut self, page: &Page) {
+			if page.abgp == u64::MAX {
+				return;
+			}
+
+			match self.granules {
+				Some((_, ref mut last)) => *last = page.abgp,
+				None => self.granules = Some((page.abgp, page.abgp)),
+			}
+		}
+
+		fn duration(&self) -> Option<f64> {
+			let (first, last) = self.granules?;
+			let samples = last.checked_sub(first)?;
+
+			if self.sample_rate == 0 {
+				return None;
+			}
+
+			Some((samples as f64) * 1000.0 / f64::from(self.sample_rate))
+		}
+	}
+
+	let mut total_duration = None;
+	let mut link = Link::new(first_page, sample_rate);
+
+	while let Ok(page) = Page::read(data, true) {
+		if page.header_type() & CONTAINS_FIRST_PAGE_OF_BITSTREAM != 0 {
+			// We need the content to check for the identification header
+			data.seek(SeekFrom::Start(page.start))?;
+			let page = Page::read(data, false)?;
+
+			if let Some(sample_rate) = link_sample_rate(page.content()) {
+				if let Some(duration) = link.duration() {
+					*total_duration.get_or_insert(0.0) += duration;
+				}
+
+				link = Link::new(&page, sample_rate);
+			}
+
+			continue;
+		}
+
+		if page.serial == link.serial {
+			link.push(&page);
+		}
+	}
+
+	if let Some(duration) = link.duration() {
+		*total_duration.get_or_insert(0.0) += duration;
+	}
+
+	Ok(total_duration)
+}
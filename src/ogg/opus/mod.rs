@@ -4,9 +4,10 @@ use super::find_last_page;
 #[cfg(feature = "vorbis_comments")]
 use super::tag::VorbisComments;
 use crate::error::Result;
-use crate::file::AudioFile;
+use crate::file::{AudioFile, FileType, TaggedFile};
 use crate::ogg::constants::{OPUSHEAD, OPUSTAGS};
 use crate::probe::ParseOptions;
+use crate::properties::FileProperties;
 use crate::tag::TagType;
 use properties::OpusProperties;
 
@@ -17,6 +18,7 @@ use lofty_attr::LoftyFile;
 /// An OGG Opus file
 #[derive(LoftyFile)]
 #[lofty(no_audiofile_impl)]
+#[lofty(no_into_taggedfile_impl)]
 pub struct OpusFile {
 	/// The vorbis comments contained in the file
 	///
@@ -57,3 +59,22 @@ impl AudioFile for OpusFile {
 		tag_type == TagType::VorbisComments
 	}
 }
+
+impl From<OpusFile> for TaggedFile {
+	#[allow(unused_mut)]
+	fn from(input: OpusFile) -> Self {
+		let mut tags = Vec::new();
+
+		#[cfg(feature = "vorbis_comments")]
+		{
+			let mut tag: crate::tag::Tag = input.vorbis_comments_tag.into();
+
+			// Opus files store their gains as R128 gains, which are converted to ReplayGain.
+			// See `ogg::write` for the reverse.
+			super::tag::r128_to_replay_gain_items(&mut tag);
+			tags.push(tag);
+		}
+
+		TaggedFile::new(FileType::Opus, FileProperties::from(input.properties), tags)
+	}
+}
@@ -9,7 +9,8 @@ use std::time::Duration;
 use byteorder::{LittleEndian, ReadBytesExt};
 use ogg_pager::Page;
 
-#[derive(Debug, Copy, Clone, PartialEq, Eq, Default)]
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[non_exhaustive]
 /// An Opus file's audio properties
 pub struct OpusProperties {
@@ -19,6 +20,12 @@ pub struct OpusProperties {
 	pub(crate) channels: u8,
 	pub(crate) version: u8,
 	pub(crate) input_sample_rate: u32,
+	pub(crate) pre_skip: u16,
+	pub(crate) output_gain: i16,
+	pub(crate) channel_mapping_family: u8,
+	pub(crate) stream_count: u8,
+	pub(crate) coupled_stream_count: u8,
+	pub(crate) channel_mapping: Vec<u8>,
 }
 
 impl From<OpusProperties> for FileProperties {
@@ -64,6 +71,48 @@ impl OpusProperties {
 	pub fn input_sample_rate(&self) -> u32 {
 		self.input_sample_rate
 	}
+
+	/// The number of samples (at 48kHz) to discard from the start of the decoder's output
+	pub fn pre_skip(&self) -> u16 {
+		self.pre_skip
+	}
+
+	/// The gain to apply to the decoder's output, in Q7.8 fixed point dB
+	///
+	/// To get the gain in dB, divide this by 256.
+	pub fn output_gain(&self) -> i16 {
+		self.output_gain
+	}
+
+	/// The channel mapping family
+	///
+	/// * `0` - Mono or stereo, in a single stream
+	/// * `1` - Up to 8 channels, in the Vorbis channel order
+	/// * `255` - Up to 255 channels, with no defined meaning
+	pub fn channel_mapping_family(&self) -> u8 {
+		self.channel_mapping_family
+	}
+
+	/// The number of Opus streams in each packet
+	pub fn stream_count(&self) -> u8 {
+		self.stream_count
+	}
+
+	/// The number of streams that are coupled (stereo)
+	pub fn coupled_stream_count(&self) -> u8 {
+		self.coupled_stream_count
+	}
+
+	/// The channel mapping table, mapping each output channel to a decoded channel
+	///
+	/// For channel mapping family `0`, this is the implied table (`[0]` for mono, `[0, 1]` for stereo).
+	pub fn channel_mapping(&self) -> &[u8] {
+		if self.channel_mapping_family == 0 {
+			return &[0, 1][..usize::from(self.channels.min(2))];
+		}
+
+		&self.channel_mapping
+	}
 }
 
 pub(in crate::ogg) fn read_properties<R>(data: &mut R, first_page: &Page) -> Result<OpusProperties>
@@ -89,12 +138,13 @@ where
 	properties.channels = first_page_content.read_u8()?;
 
 	let pre_skip = first_page_content.read_u16::<LittleEndian>()?;
+	properties.pre_skip = pre_skip;
 
 	properties.input_sample_rate = first_page_content.read_u32::<LittleEndian>()?;
-
-	let _output_gain = first_page_content.read_u16::<LittleEndian>()?;
+	properties.output_gain = first_page_content.read_i16::<LittleEndian>()?;
 
 	let channel_mapping_family = first_page_content.read_u8()?;
+	properties.channel_mapping_family = channel_mapping_family;
 
 	// https://datatracker.ietf.org/doc/html/rfc7845.html#section-5.1.1
 	if (channel_mapping_family == 0 && properties.channels > 2)
@@ -103,6 +153,27 @@ where
 		decode_err!(@BAIL Opus, "Invalid channel count for mapping family");
 	}
 
+	if channel_mapping_family == 0 {
+		// The mapping table is omitted, and implied to be a single (coupled, if stereo) stream
+		properties.stream_count = 1;
+		properties.coupled_stream_count = properties.channels.saturating_sub(1);
+	} else {
+		properties.stream_count = first_page_content.read_u8()?;
+		properties.coupled_stream_count = first_page_content.read_u8()?;
+
+		if properties.stream_count == 0 || properties.coupled_stream_count > properties.stream_count
+		{
+			decode_err!(@BAIL Opus, "Invalid stream count in channel mapping table");
+		}
+
+		let mut channel_mapping = vec![0; usize::from(properties.channels)];
+		first_page_content
+			.read_exact(&mut channel_mapping)
+			.map_err(|_| decode_err!(Opus, "Channel mapping table is too short"))?;
+
+		properties.channel_mapping = channel_mapping;
+	}
+
 	// Subtract the identification and metadata packet length from the total
 	let audio_size = stream_len - data.stream_position()?;
 
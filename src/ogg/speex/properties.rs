@@ -1,6 +1,7 @@
 use crate::error::Result;
 use crate::macros::decode_err;
-use crate::ogg::find_last_page;
+use crate::ogg::chained_stream_duration;
+use crate::ogg::constants::SPEEXHEADER;
 use crate::properties::FileProperties;
 
 use std::io::{Read, Seek, SeekFrom};
@@ -10,6 +11,7 @@ use byteorder::{LittleEndian, ReadBytesExt};
 use ogg_pager::Page;
 
 #[derive(Debug, Copy, Clone, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[non_exhaustive]
 /// A Speex file's audio properties
 pub struct SpeexProperties {
@@ -88,8 +90,6 @@ pub(in crate::ogg) fn read_properties<R>(data: &mut R, first_page: &Page) -> Res
 where
 	R: Read + Seek,
 {
-	let first_page_abgp = first_page.abgp;
-
 	if first_page.content().len() < 80 {
 		decode_err!(@BAIL Speex, "Header packet too small");
 	}
@@ -128,21 +128,38 @@ where
 
 	properties.vbr = first_page_content.read_u32::<LittleEndian>()? == 1;
 
-	let last_page = find_last_page(data)?;
-	let last_page_abgp = last_page.abgp;
+	let audio_start = data.stream_position()?;
+	let length = chained_stream_duration(data, first_page, properties.sample_rate, |content| {
+		match content.strip_prefix(SPEEXHEADER) {
+			// Skip the version string (20), version (4), and header size (4)
+			Some(header) if header.len() >= 32 => Some(u32::from_le_bytes([
+				header[28], header[29], header[30], header[31],
+			])),
+			_ => None,
+		}
+	})?;
 
 	let file_length = data.seek(SeekFrom::End(0))?;
+	let audio_size = file_length.saturating_sub(audio_start);
 
-	if let Some(frame_count) = last_page_abgp.checked_sub(first_page_abgp) {
-		if properties.sample_rate > 0 {
-			let length = ((frame_count as f64) * 1000.0) / f64::from(properties.sample_rate) + 0.5;
-			properties.duration = Duration::from_millis(length as u64);
+	match length {
+		Some(length) if length >= 1.0 => {
+			properties.duration = Duration::from_millis((length + 0.5) as u64);
 
 			properties.overall_bitrate = ((file_length as f64) * 8.0 / length) as u32;
-
-			// TODO: Calculate with the stream length, and make this the fallback
+			properties.audio_bitrate = ((audio_size as f64) * 8.0 / length) as u32;
+		},
+		// Without any usable granule positions, the nominal bitrate is all we have
+		_ if properties.nominal_bitrate > 0 => {
+			let length = (audio_size as f64) * 8.0 * 1000.0 / f64::from(properties.nominal_bitrate);
+			properties.duration = Duration::from_millis((length + 0.5) as u64);
+
+			if length >= 1.0 {
+				properties.overall_bitrate = ((file_length as f64) * 8.0 / length) as u32;
+			}
 			properties.audio_bitrate = (properties.nominal_bitrate as u64 / 1000) as u32;
-		}
+		},
+		_ => {},
 	}
 
 	Ok(properties)
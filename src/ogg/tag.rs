@@ -6,6 +6,9 @@ use crate::ogg::write::OGGFormat;
 use crate::picture::{Picture, PictureInformation, PictureType};
 use crate::probe::Probe;
 use crate::tag::item::{ItemKey, ItemValue, TagItem};
+use crate::tag::rating::Rating;
+use crate::tag::timestamp::{year_from_date, Timestamp};
+use crate::tag::utils;
 use crate::tag::{Tag, TagType};
 use crate::traits::{Accessor, TagExt};
 
@@ -30,7 +33,24 @@ macro_rules! impl_accessor {
 				}
 			)+
 		}
-	}
+	};
+	(@TYPED $($name:ident<$ty:ty> => $key:literal, $parse:path, $format:path;)+) => {
+		paste::paste! {
+			$(
+				fn $name(&self) -> Option<$ty> {
+					self.get($key).and_then($parse)
+				}
+
+				fn [<set_ $name>](&mut self, value: $ty) {
+					self.insert(String::from($key), $format(value), true)
+				}
+
+				fn [<remove_ $name>](&mut self) {
+					let _ = self.remove($key);
+				}
+			)+
+		}
+	};
 }
 
 /// Vorbis comments
@@ -42,6 +62,7 @@ macro_rules! impl_accessor {
 /// * [`FileType::Speex`](crate::FileType::Speex)
 /// * [`FileType::Vorbis`](crate::FileType::Vorbis)
 #[derive(Default, PartialEq, Eq, Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct VorbisComments {
 	/// An identifier for the encoding software
 	pub(crate) vendor: String,
@@ -266,11 +287,32 @@ impl VorbisComments {
 
 impl Accessor for VorbisComments {
 	impl_accessor!(
-		artist  => "ARTIST";
-		title   => "TITLE";
-		album   => "ALBUM";
-		genre   => "GENRE";
-		comment => "COMMENT";
+		artist                  => "ARTIST";
+		title                   => "TITLE";
+		album                   => "ALBUM";
+		genre                   => "GENRE";
+		comment                 => "COMMENT";
+		album_artist            => "ALBUMARTIST";
+		composer                => "COMPOSER";
+		lyrics                  => "LYRICS";
+		title_sort_order        => "TITLESORT";
+		artist_sort_order       => "ARTISTSORT";
+		album_sort_order        => "ALBUMSORT";
+		album_artist_sort_order => "ALBUMARTISTSORT";
+		composer_sort_order     => "COMPOSERSORT";
+		isrc                    => "ISRC";
+		label                   => "LABEL";
+		catalog_number          => "CATALOGNUMBER";
+	);
+
+	impl_accessor!(
+		@TYPED
+		bpm<u32>                    => "BPM", utils::parse_number, utils::format_number;
+		replay_gain_track_gain<f32> => "REPLAYGAIN_TRACK_GAIN", utils::parse_replay_gain, utils::format_replay_gain;
+		replay_gain_track_peak<f32> => "REPLAYGAIN_TRACK_PEAK", utils::parse_replay_gain_peak, utils::format_replay_gain_peak;
+		replay_gain_album_gain<f32> => "REPLAYGAIN_ALBUM_GAIN", utils::parse_replay_gain, utils::format_replay_gain;
+		replay_gain_album_peak<f32> => "REPLAYGAIN_ALBUM_PEAK", utils::parse_replay_gain_peak, utils::format_replay_gain_peak;
+		compilation<bool>           => "COMPILATION", utils::parse_flag, utils::format_flag;
 	);
 
 	fn track(&self) -> Option<u32> {
@@ -349,7 +391,7 @@ impl Accessor for VorbisComments {
 
 	fn year(&self) -> Option<u32> {
 		if let Some(item) = self.get("YEAR").map_or_else(|| self.get("DATE"), Some) {
-			return item.chars().take(4).collect::<String>().parse::<u32>().ok();
+			return year_from_date(item);
 		}
 
 		None
@@ -367,6 +409,42 @@ impl Accessor for VorbisComments {
 		let _ = self.remove("DATE");
 		let _ = self.remove("YEAR");
 	}
+
+	fn date(&self) -> Option<Timestamp> {
+		self.get("DATE").and_then(utils::parse_date)
+	}
+
+	fn set_date(&mut self, value: Timestamp) {
+		self.insert(String::from("DATE"), utils::format_date(value), true);
+		let _ = self.remove("YEAR");
+	}
+
+	fn rating(&self) -> Option<Rating> {
+		// FMPS_RATING is more precise, so it takes priority
+		if let Some(rating) = self.get("FMPS_RATING").and_then(Rating::from_fmps) {
+			return Some(rating);
+		}
+
+		self.get("RATING").and_then(utils::parse_rating)
+	}
+
+	fn set_rating(&mut self, value: Rating) {
+		self.insert(String::from("RATING"), utils::format_rating(value), true);
+
+		// Only keep FMPS_RATING up to date if it was already in use
+		if self.get("FMPS_RATING").is_some() {
+			self.insert(String::from("FMPS_RATING"), value.as_fmps(), true);
+		}
+	}
+
+	fn remove_rating(&mut self) {
+		let _ = self.remove("RATING");
+		let _ = self.remove("FMPS_RATING");
+	}
+
+	fn remove_date(&mut self) {
+		let _ = self.remove("DATE");
+	}
 }
 
 impl TagExt for VorbisComments {
@@ -435,6 +513,89 @@ impl TagExt for VorbisComments {
 	}
 }
 
+// Opus files store their loudness in `R128_{TRACK, ALBUM}_GAIN` comments, rather than ReplayGain.
+// These are Q7.8 fixed point dB values relative to -23 LUFS, while ReplayGain is relative to -18 LUFS.
+const R128_GAIN_KEYS: [(&str, ItemKey); 2] = [
+	("R128_TRACK_GAIN", ItemKey::ReplayGainTrackGain),
+	("R128_ALBUM_GAIN", ItemKey::ReplayGainAlbumGain),
+];
+const R128_REPLAY_GAIN_OFFSET: f64 = 5.0;
+
+fn is_r128_item(item: &TagItem, r128_key: &str) -> bool {
+	matches!(item.key(), ItemKey::Unknown(key) if key.eq_ignore_ascii_case(r128_key))
+}
+
+// Converts an R128 gain (e.g. "-1280") to a ReplayGain gain (e.g. "0.000 dB")
+//
+// R128 gains are in steps of 1/256 dB, so 3 decimal places are needed to convert back to the same value.
+pub(crate) fn r128_to_replay_gain(r128: &str) -> Option<String> {
+	let r128 = r128.trim().parse::<i16>().ok()?;
+	let gain = f64::from(r128) / 256.0 + R128_REPLAY_GAIN_OFFSET;
+
+	Some(format!("{gain:.3} dB"))
+}
+
+// Converts a ReplayGain gain (e.g. "-6.50 dB") to an R128 gain (e.g. "-2944")
+//
+// The gain is parsed the same as `Accessor::replay_gain_track_gain`, see `utils::parse_replay_gain`.
+pub(crate) fn replay_gain_to_r128(gain: &str) -> Option<String> {
+	let gain = f64::from(utils::parse_replay_gain(gain)?);
+
+	let r128 = ((gain - R128_REPLAY_GAIN_OFFSET) * 256.0)
+		.round()
+		.clamp(f64::from(i16::MIN), f64::from(i16::MAX)) as i16;
+
+	Some(r128.to_string())
+}
+
+// Replaces the R128 gain items with ReplayGain items, for reading from Opus files
+//
+// Gains that already have a ReplayGain item are left alone.
+pub(crate) fn r128_to_replay_gain_items(tag: &mut Tag) {
+	for (r128_key, replay_gain_key) in &R128_GAIN_KEYS {
+		if tag.get_item_ref(replay_gain_key).is_some() {
+			continue;
+		}
+
+		if let Some(item) = tag
+			.items
+			.iter_mut()
+			.find(|item| is_r128_item(item, r128_key))
+		{
+			if let Some(gain) = item.value().text().and_then(r128_to_replay_gain) {
+				*item = TagItem::new(replay_gain_key.clone(), ItemValue::Text(gain));
+			}
+		}
+	}
+}
+
+// Replaces the ReplayGain gain items with R128 items, for writing to Opus files
+//
+// Gains that already have an R128 item are left alone.
+pub(crate) fn replay_gain_to_r128_items(tag: &Tag) -> Tag {
+	let mut tag = tag.clone();
+
+	for (r128_key, replay_gain_key) in &R128_GAIN_KEYS {
+		if tag.items.iter().any(|item| is_r128_item(item, r128_key)) {
+			continue;
+		}
+
+		let r128 = tag
+			.get_string(replay_gain_key)
+			.and_then(replay_gain_to_r128);
+
+		if let Some(r128) = r128 {
+			tag.remove_key(replay_gain_key);
+			tag.items.push(TagItem::new(
+				ItemKey::Unknown(String::from(*r128_key)),
+				ItemValue::Text(r128),
+			));
+		}
+	}
+
+	tag
+}
+
 impl From<VorbisComments> for Tag {
 	fn from(input: VorbisComments) -> Self {
 		let mut tag = Tag::new(TagType::VorbisComments);
@@ -446,6 +607,20 @@ impl From<VorbisComments> for Tag {
 			));
 		}
 
+		// FMPS_RATING is converted to a rating out of 100, unless the tag already has a RATING
+		if tag.get_item_ref(&ItemKey::Popularimeter).is_none() {
+			if let Some(item) = tag.items.iter_mut().find(
+				|item| matches!(item.key(), ItemKey::Unknown(key) if key.eq_ignore_ascii_case("FMPS_RATING")),
+			) {
+				if let Some(rating) = item.value().text().and_then(Rating::from_fmps) {
+					*item = TagItem::new(
+						ItemKey::Popularimeter,
+						ItemValue::Integer(i64::from(rating.value())),
+					);
+				}
+			}
+		}
+
 		// We need to preserve the vendor string
 		if !tag
 			.items
@@ -468,6 +643,8 @@ impl From<VorbisComments> for Tag {
 
 impl From<Tag> for VorbisComments {
 	fn from(mut input: Tag) -> Self {
+		input.convert_typed_items_to_text();
+
 		let mut vorbis_comments = Self::default();
 
 		if let Some(TagItem {
@@ -643,6 +820,112 @@ mod tests {
 		assert_eq!(vorbis_comments.get("GENRE"), Some("Classical"));
 	}
 
+	#[test]
+	fn r128_gain_conversion() {
+		use super::{
+			r128_to_replay_gain, r128_to_replay_gain_items, replay_gain_to_r128,
+			replay_gain_to_r128_items,
+		};
+		use crate::ItemKey;
+
+		assert_eq!(r128_to_replay_gain("-1280").as_deref(), Some("0.000 dB"));
+		assert_eq!(r128_to_replay_gain("256").as_deref(), Some("6.000 dB"));
+		assert_eq!(r128_to_replay_gain("-2943").as_deref(), Some("-6.496 dB"));
+		assert_eq!(r128_to_replay_gain("-6.5 dB"), None);
+
+		// Every R128 gain converts back to the same value
+		for r128 in i16::MIN..=i16::MAX {
+			let gain = r128_to_replay_gain(&r128.to_string()).unwrap();
+			assert_eq!(replay_gain_to_r128(&gain), Some(r128.to_string()));
+		}
+
+		assert_eq!(replay_gain_to_r128("0.00 dB").as_deref(), Some("-1280"));
+		assert_eq!(replay_gain_to_r128("-6.50 dB").as_deref(), Some("-2944"));
+		assert_eq!(replay_gain_to_r128("+6 db").as_deref(), Some("256"));
+		assert_eq!(replay_gain_to_r128("1000 dB").as_deref(), Some("32767"));
+		assert_eq!(replay_gain_to_r128("loud"), None);
+
+		// The unit is handled by `utils::parse_replay_gain`
+		for gain in ["-6.5", " -6.5dB ", "-6.5 DB"] {
+			assert_eq!(replay_gain_to_r128(gain).as_deref(), Some("-2944"));
+		}
+		assert_eq!(replay_gain_to_r128("inf dB"), None);
+		assert_eq!(replay_gain_to_r128("dB"), None);
+
+		let mut vorbis_comments = VorbisComments::default();
+		vorbis_comments.insert(
+			String::from("R128_TRACK_GAIN"),
+			String::from("-2944"),
+			false,
+		);
+		vorbis_comments.insert(String::from("R128_ALBUM_GAIN"), String::from("0"), false);
+		vorbis_comments.insert(
+			String::from("REPLAYGAIN_ALBUM_GAIN"),
+			String::from("-1.00 dB"),
+			false,
+		);
+
+		// Only Opus files use R128 gains
+		let mut tag: Tag = vorbis_comments.into();
+		assert!(tag.get_string(&ItemKey::ReplayGainTrackGain).is_none());
+
+		r128_to_replay_gain_items(&mut tag);
+
+		// Existing ReplayGain items take precedence
+		assert_eq!(
+			tag.get_string(&ItemKey::ReplayGainTrackGain),
+			Some("-6.500 dB")
+		);
+		assert_eq!(
+			tag.get_string(&ItemKey::ReplayGainAlbumGain),
+			Some("-1.00 dB")
+		);
+		assert_eq!(
+			tag.get_string(&ItemKey::Unknown(String::from("R128_ALBUM_GAIN"))),
+			Some("0")
+		);
+
+		let tag = replay_gain_to_r128_items(&tag);
+		assert!(tag.get_string(&ItemKey::ReplayGainTrackGain).is_none());
+		assert_eq!(
+			tag.get_string(&ItemKey::Unknown(String::from("R128_TRACK_GAIN"))),
+			Some("-2944")
+		);
+		assert_eq!(
+			tag.get_string(&ItemKey::ReplayGainAlbumGain),
+			Some("-1.00 dB")
+		);
+	}
+
+	#[test]
+	fn fmps_rating() {
+		use crate::{Accessor, ItemKey, ItemValue, Rating};
+
+		let mut vorbis_comments = VorbisComments::default();
+		vorbis_comments.insert(String::from("FMPS_RATING"), String::from("0.6"), false);
+		assert_eq!(vorbis_comments.rating(), Some(Rating::new(60)));
+
+		// FMPS_RATING is only updated if it already exists
+		vorbis_comments.set_rating(Rating::new(80));
+		assert_eq!(vorbis_comments.get("RATING"), Some("80"));
+		assert_eq!(vorbis_comments.get("FMPS_RATING"), Some("0.8"));
+
+		let tag: Tag = vorbis_comments.clone().into();
+		assert_eq!(tag.rating(), Some(Rating::new(80)));
+
+		let _ = vorbis_comments.remove("RATING");
+		let tag: Tag = vorbis_comments.clone().into();
+		assert_eq!(
+			tag.get_item_ref(&ItemKey::Popularimeter)
+				.map(crate::TagItem::value),
+			Some(&ItemValue::Integer(80))
+		);
+
+		vorbis_comments.remove_rating();
+		assert_eq!(vorbis_comments.get("FMPS_RATING"), None);
+		assert_eq!(vorbis_comments.rating(), None);
+	}
+
 	#[test]
 	fn zero_sized_vorbis_comments() {
 		let tag_bytes = std::fs::read("tests/tags/assets/zero.vorbis").unwrap();
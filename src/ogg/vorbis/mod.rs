@@ -2,7 +2,7 @@ pub(super) mod properties;
 #[cfg(feature = "vorbis_comments")]
 pub(in crate::ogg) mod write;
 
-use super::find_last_page;
+use super::chained_stream_duration;
 #[cfg(feature = "vorbis_comments")]
 use super::tag::VorbisComments;
 use crate::error::Result;
@@ -1,5 +1,6 @@
-use super::find_last_page;
+use super::chained_stream_duration;
 use crate::error::Result;
+use crate::ogg::constants::VORBIS_IDENT_HEAD;
 use crate::properties::FileProperties;
 
 use std::io::{Read, Seek, SeekFrom};
@@ -9,6 +10,7 @@ use byteorder::{LittleEndian, ReadBytesExt};
 use ogg_pager::Page;
 
 #[derive(Copy, Clone, Debug, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[non_exhaustive]
 /// An OGG Vorbis file's audio properties
 pub struct VorbisProperties {
@@ -90,8 +92,6 @@ pub(in crate::ogg) fn read_properties<R>(
 where
 	R: Read + Seek,
 {
-	let first_page_abgp = first_page.abgp;
-
 	let mut properties = VorbisProperties::default();
 
 	// Skip identification header
@@ -106,21 +106,38 @@ where
 	properties.bitrate_nominal = first_page_content.read_i32::<LittleEndian>()?;
 	properties.bitrate_minimum = first_page_content.read_i32::<LittleEndian>()?;
 
-	let last_page = find_last_page(data)?;
-	let last_page_abgp = last_page.abgp;
+	let audio_start = data.stream_position()?;
+	let length = chained_stream_duration(data, first_page, properties.sample_rate, |content| {
+		match content.strip_prefix(VORBIS_IDENT_HEAD) {
+			// Skip the version (4) and channel count (1)
+			Some(header) if header.len() >= 9 => Some(u32::from_le_bytes([
+				header[5], header[6], header[7], header[8],
+			])),
+			_ => None,
+		}
+	})?;
 
 	let file_length = data.seek(SeekFrom::End(0))?;
-
-	if let Some(frame_count) = last_page_abgp.checked_sub(first_page_abgp) {
-		if properties.sample_rate > 0 {
-			let length = frame_count * 1000 / u64::from(properties.sample_rate);
-			properties.duration = Duration::from_millis(length);
-
-			properties.overall_bitrate = ((file_length * 8) / length) as u32;
-
-			// TODO: Calculate with the stream length, and make this the fallback
+	let audio_size = file_length.saturating_sub(audio_start);
+
+	match length {
+		Some(length) if length >= 1.0 => {
+			properties.duration = Duration::from_millis(length as u64);
+
+			properties.overall_bitrate = ((file_length as f64) * 8.0 / length) as u32;
+			properties.audio_bitrate = ((audio_size as f64) * 8.0 / length) as u32;
+		},
+		// Without any usable granule positions, the nominal bitrate is all we have
+		_ if properties.bitrate_nominal > 0 => {
+			let length = (audio_size as f64) * 8.0 * 1000.0 / f64::from(properties.bitrate_nominal);
+			properties.duration = Duration::from_millis(length as u64);
+
+			if length >= 1.0 {
+				properties.overall_bitrate = ((file_length as f64) * 8.0 / length) as u32;
+			}
 			properties.audio_bitrate = (properties.bitrate_nominal as u64 / 1000) as u32;
-		}
+		},
+		_ => {},
 	}
 
 	Ok(properties)
@@ -4,10 +4,11 @@ use crate::file::FileType;
 use crate::flac;
 use crate::macros::{err, try_vec};
 use crate::ogg::constants::{OPUSTAGS, VORBIS_COMMENT_HEAD};
-use crate::ogg::tag::{create_vorbis_comments_ref, VorbisCommentsRef};
+use crate::ogg::tag::{create_vorbis_comments_ref, replay_gain_to_r128_items, VorbisCommentsRef};
 use crate::picture::PictureInformation;
 use crate::tag::{Tag, TagType};
 
+use std::borrow::Cow;
 use std::convert::TryFrom;
 use std::fs::File;
 use std::io::{Cursor, Read, Seek, SeekFrom, Write};
@@ -37,7 +38,14 @@ pub(crate) fn write_to(file: &mut File, tag: &Tag, file_type: FileType) -> Resul
 	match tag.tag_type() {
 		#[cfg(feature = "vorbis_comments")]
 		TagType::VorbisComments => {
-			let (vendor, items, pictures) = create_vorbis_comments_ref(tag);
+			// Opus files use R128 gains rather than ReplayGain
+			let tag = if file_type == FileType::Opus {
+				Cow::Owned(replay_gain_to_r128_items(tag))
+			} else {
+				Cow::Borrowed(tag)
+			};
+
+			let (vendor, items, pictures) = create_vorbis_comments_ref(&tag);
 
 			let mut comments_ref = VorbisCommentsRef {
 				vendor,
@@ -13,12 +13,16 @@ use std::io::Cursor;
 use std::io::Read;
 #[cfg(feature = "id3v2")]
 use std::io::Write;
-#[cfg(any(feature = "vorbis_comments", feature = "ape"))]
+#[cfg(any(
+	feature = "vorbis_comments",
+	feature = "ape",
+	feature = "id3v2_restrictions"
+))]
 use std::io::{Seek, SeekFrom};
 
 #[cfg(feature = "id3v2")]
 use crate::util::text::TextEncoding;
-#[cfg(any(feature = "vorbis_comments"))]
+#[cfg(any(feature = "vorbis_comments", feature = "id3v2_restrictions"))]
 use byteorder::BigEndian;
 #[cfg(any(feature = "vorbis_comments", feature = "id3v2", feature = "ape"))]
 use byteorder::ReadBytesExt;
@@ -52,6 +56,7 @@ pub const APE_PICTURE_TYPES: [&str; 21] = [
 ];
 
 #[derive(Debug, Clone, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[non_exhaustive]
 /// Mime types for pictures.
 pub enum MimeType {
@@ -117,6 +122,7 @@ impl MimeType {
 /// The picture type, according to ID3v2 APIC
 #[allow(missing_docs)]
 #[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[non_exhaustive]
 pub enum PictureType {
 	Other,
@@ -146,7 +152,6 @@ pub enum PictureType {
 impl PictureType {
 	// ID3/OGG specific methods
 
-	#[cfg(any(feature = "id3v2", feature = "vorbis_comments"))]
 	/// Get a u8 from a `PictureType` according to ID3v2 APIC
 	pub fn as_u8(&self) -> u8 {
 		match self {
@@ -175,7 +180,6 @@ impl PictureType {
 		}
 	}
 
-	#[cfg(any(feature = "id3v2", feature = "vorbis_comments"))]
 	/// Get a `PictureType` from a u8 according to ID3v2 APIC
 	pub fn from_u8(byte: u8) -> Self {
 		match byte {
@@ -265,8 +269,9 @@ impl PictureType {
 	}
 }
 
-#[cfg(feature = "vorbis_comments")]
+#[cfg(any(feature = "vorbis_comments", feature = "id3v2_restrictions"))]
 #[derive(Debug, Copy, Clone, Eq, PartialEq, Hash, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 /// Information about a [`Picture`]
 ///
 /// This information is necessary for FLAC's `METADATA_BLOCK_PICTURE`.
@@ -282,7 +287,7 @@ pub struct PictureInformation {
 	pub num_colors: u32,
 }
 
-#[cfg(feature = "vorbis_comments")]
+#[cfg(any(feature = "vorbis_comments", feature = "id3v2_restrictions"))]
 impl PictureInformation {
 	/// Attempt to extract [`PictureInformation`] from a [`Picture`]
 	///
@@ -916,3 +921,169 @@ impl Picture {
 		}
 	}
 }
+
+// Picture data is base64 encoded in human-readable formats, such as JSON
+#[cfg(feature = "serde")]
+impl serde::Serialize for Picture {
+	fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+	where
+		S: serde::Serializer,
+	{
+		serialize_picture(self, true, serializer)
+	}
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for Picture {
+	fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+	where
+		D: serde::Deserializer<'de>,
+	{
+		#[derive(serde::Deserialize)]
+		#[serde(rename = "Picture")]
+		struct PictureFields {
+			pic_type: PictureType,
+			mime_type: MimeType,
+			description: Option<String>,
+			#[serde(default, deserialize_with = "deserialize_picture_data")]
+			data: Vec<u8>,
+		}
+
+		let fields = PictureFields::deserialize(deserializer)?;
+		Ok(Picture {
+			pic_type: fields.pic_type,
+			mime_type: fields.mime_type,
+			description: fields.description.map(Cow::Owned),
+			data: Cow::Owned(fields.data),
+		})
+	}
+}
+
+/// Serializes a [`Picture`] or [`Tag`](crate::Tag) without any picture data
+///
+/// This is useful when only the information about the pictures is needed, as the
+/// data can make up the majority of a tag's size. Deserializing the output will
+/// create pictures with empty data.
+///
+/// # Examples
+///
+/// ```rust
+/// use lofty::{MimeType, Picture, PictureType, WithoutPictureData};
+///
+/// let picture = Picture::new_unchecked(PictureType::CoverFront, MimeType::Png, None, vec![0; 1024]);
+///
+/// let without_data = serde_json::to_string(&WithoutPictureData(&picture)).unwrap();
+/// assert_eq!(
+/// 	without_data,
+/// 	r#"{"pic_type":"CoverFront","mime_type":"Png","description":null}"#
+/// );
+/// ```
+#[cfg(feature = "serde")]
+pub struct WithoutPictureData<'a, T: ?Sized>(pub &'a T);
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for WithoutPictureData<'_, Picture> {
+	fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+	where
+		S: serde::Serializer,
+	{
+		serialize_picture(self.0, false, serializer)
+	}
+}
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for WithoutPictureData<'_, [Picture]> {
+	fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+	where
+		S: serde::Serializer,
+	{
+		serializer.collect_seq(self.0.iter().map(WithoutPictureData))
+	}
+}
+
+#[cfg(feature = "serde")]
+fn serialize_picture<S>(
+	picture: &Picture,
+	include_data: bool,
+	serializer: S,
+) -> std::result::Result<S::Ok, S::Error>
+where
+	S: serde::Serializer,
+{
+	use serde::ser::SerializeStruct;
+
+	let mut state = serializer.serialize_struct("Picture", 3 + usize::from(include_data))?;
+	state.serialize_field("pic_type", &picture.pic_type)?;
+	state.serialize_field("mime_type", &picture.mime_type)?;
+	state.serialize_field("description", &picture.description)?;
+
+	if include_data {
+		struct PictureData<'a>(&'a [u8]);
+
+		impl serde::Serialize for PictureData<'_> {
+			fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+			where
+				S: serde::Serializer,
+			{
+				if serializer.is_human_readable() {
+					serializer.serialize_str(&base64::encode(self.0))
+				} else {
+					serializer.serialize_bytes(self.0)
+				}
+			}
+		}
+
+		state.serialize_field("data", &PictureData(&picture.data))?;
+	}
+
+	state.end()
+}
+
+// Accepts either a base64 string or raw bytes
+#[cfg(feature = "serde")]
+fn deserialize_picture_data<'de, D>(deserializer: D) -> std::result::Result<Vec<u8>, D::Error>
+where
+	D: serde::Deserializer<'de>,
+{
+	use serde::de::{Error, SeqAccess, Visitor};
+
+	struct PictureDataVisitor;
+
+	impl<'de> Visitor<'de> for PictureDataVisitor {
+		type Value = Vec<u8>;
+
+		fn expecting(&self, formatter: &mut Formatter<'_>) -> std::fmt::Result {
+			formatter.write_str("a base64 encoded string or bytes")
+		}
+
+		fn visit_str<E: Error>(self, v: &str) -> std::result::Result<Self::Value, E> {
+			base64::decode(v).map_err(E::custom)
+		}
+
+		fn visit_bytes<E: Error>(self, v: &[u8]) -> std::result::Result<Self::Value, E> {
+			Ok(v.to_vec())
+		}
+
+		fn visit_byte_buf<E: Error>(self, v: Vec<u8>) -> std::result::Result<Self::Value, E> {
+			Ok(v)
+		}
+
+		fn visit_seq<A: SeqAccess<'de>>(
+			self,
+			mut seq: A,
+		) -> std::result::Result<Self::Value, A::Error> {
+			let mut data = Vec::with_capacity(seq.size_hint().unwrap_or(0));
+			while let Some(byte) = seq.next_element()? {
+				data.push(byte);
+			}
+
+			Ok(data)
+		}
+	}
+
+	if deserializer.is_human_readable() {
+		deserializer.deserialize_any(PictureDataVisitor)
+	} else {
+		deserializer.deserialize_byte_buf(PictureDataVisitor)
+	}
+}
@@ -25,6 +25,7 @@ pub struct ParseOptions {
 	pub(crate) read_properties: bool,
 	pub(crate) use_custom_resolvers: bool,
 	pub(crate) parsing_mode: ParsingMode,
+	pub(crate) scan_mpeg_frames: bool,
 }
 
 impl Default for ParseOptions {
@@ -37,6 +38,7 @@ impl Default for ParseOptions {
 	/// 	read_properties: true,
 	/// 	use_custom_resolvers: true,
 	/// 	parsing_mode: ParsingMode::Strict,
+	/// 	scan_mpeg_frames: false,
 	/// }
 	/// ```
 	fn default() -> Self {
@@ -44,6 +46,7 @@ impl Default for ParseOptions {
 			read_properties: true,
 			use_custom_resolvers: true,
 			parsing_mode: ParsingMode::Strict,
+			scan_mpeg_frames: false,
 		}
 	}
 }
@@ -110,6 +113,27 @@ impl ParseOptions {
 		self.parsing_mode = parsing_mode;
 		*self
 	}
+
+	/// Whether or not to walk every frame of an MPEG stream when reading its properties
+	///
+	/// By default, the duration and bitrate are estimated from the Xing/VBRI header, or the first frame's bitrate.
+	/// This is inaccurate for VBR streams without a Xing header, and streams with junk between frames.
+	/// Scanning gives exact values, at the cost of reading the entire stream.
+	///
+	/// See also: [`MPEGProperties::frame_count`](crate::mpeg::MPEGProperties::frame_count)
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use lofty::ParseOptions;
+	///
+	/// // By default, `scan_mpeg_frames` is disabled. Here, we want an exact duration.
+	/// let parsing_options = ParseOptions::new().scan_mpeg_frames(true);
+	/// ```
+	pub fn scan_mpeg_frames(&mut self, scan_mpeg_frames: bool) -> Self {
+		self.scan_mpeg_frames = scan_mpeg_frames;
+		*self
+	}
 }
 
 /// The parsing strictness mode
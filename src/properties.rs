@@ -1,6 +1,7 @@
 use std::time::Duration;
 
 #[derive(Debug, PartialEq, Eq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[non_exhaustive]
 /// Various *immutable* audio properties
 pub struct FileProperties {
@@ -79,31 +80,40 @@ impl FileProperties {
 #[cfg(test)]
 mod tests {
 	use crate::ape::{ApeFile, ApeProperties};
-	use crate::flac::FlacFile;
-	use crate::iff::{AiffFile, WavFile, WavFormat, WavProperties};
+	use crate::flac::{FlacFile, FlacProperties};
+	use crate::iff::{
+		AiffCompressionType, AiffFile, AiffProperties, ChannelMask, Speaker, WavFile, WavFormat,
+		WavProperties,
+	};
 	use crate::mp4::{AudioObjectType, Mp4Codec, Mp4File, Mp4Properties};
-	use crate::mpeg::{ChannelMode, Emphasis, Layer, MPEGFile, MPEGProperties, MpegVersion};
+	use crate::mpeg::{
+		BitrateMode, ChannelMode, Emphasis, Layer, MPEGFile, MPEGProperties, MpegVersion,
+	};
 	use crate::ogg::{
 		OpusFile, OpusProperties, SpeexFile, SpeexProperties, VorbisFile, VorbisProperties,
 	};
-	use crate::probe::ParseOptions;
-	use crate::wavpack::{WavPackFile, WavPackProperties};
+	use crate::probe::{ParseOptions, ParsingMode};
+	use crate::wavpack::{WavPackCompressionMode, WavPackFile, WavPackProperties};
 	use crate::{AudioFile, FileProperties};
 
 	use std::fs::File;
+	use std::io::Cursor;
 	use std::time::Duration;
 
 	// These values are taken from FFmpeg's ffprobe
 	// There is a chance they will be +/- 1, anything greater (for real world files)
 	// is an issue.
 
-	const AIFF_PROPERTIES: FileProperties = FileProperties {
+	const AIFF_PROPERTIES: AiffProperties = AiffProperties {
 		duration: Duration::from_millis(1428),
-		overall_bitrate: Some(1542),
-		audio_bitrate: Some(1536),
-		sample_rate: Some(48000),
-		bit_depth: Some(16),
-		channels: Some(2),
+		overall_bitrate: 1542,
+		audio_bitrate: 1536,
+		sample_rate: 48000,
+		sample_size: 16,
+		channels: 2,
+		compression_type: None,
+		compression_name: None,
+		format_version: None,
 	};
 
 	const APE_PROPERTIES: ApeProperties = ApeProperties {
@@ -116,13 +126,19 @@ mod tests {
 		channels: 2,
 	};
 
-	const FLAC_PROPERTIES: FileProperties = FileProperties {
+	const FLAC_PROPERTIES: FlacProperties = FlacProperties {
 		duration: Duration::from_millis(1428),
-		overall_bitrate: Some(321),
-		audio_bitrate: Some(275),
-		sample_rate: Some(48000),
-		bit_depth: Some(16),
-		channels: Some(2),
+		overall_bitrate: 321,
+		audio_bitrate: 275,
+		sample_rate: 48000,
+		bit_depth: 16,
+		channels: 2,
+		min_block_size: 4608,
+		max_block_size: 4608,
+		min_frame_size: 783,
+		max_frame_size: 4744,
+		total_samples: 68546,
+		signature: 0x7BC2_B737_E1CD_611F_D891_C718_DF68_4BD3,
 	};
 
 	const MP1_PROPERTIES: MPEGProperties = MPEGProperties {
@@ -138,6 +154,9 @@ mod tests {
 		sample_rate: 32000,
 		channels: 2,
 		emphasis: Emphasis::None,
+		vbr_header: None,
+		frame_count: None,
+		bitrate_mode: None,
 	};
 
 	const MP2_PROPERTIES: MPEGProperties = MPEGProperties {
@@ -153,6 +172,9 @@ mod tests {
 		sample_rate: 48000,
 		channels: 2,
 		emphasis: Emphasis::None,
+		vbr_header: None,
+		frame_count: None,
+		bitrate_mode: None,
 	};
 
 	const MP3_PROPERTIES: MPEGProperties = MPEGProperties {
@@ -168,6 +190,9 @@ mod tests {
 		sample_rate: 48000,
 		channels: 2,
 		emphasis: Emphasis::None,
+		vbr_header: None,
+		frame_count: Some(61),
+		bitrate_mode: Some(BitrateMode::Vbr),
 	};
 
 	const MP4_AAC_PROPERTIES: Mp4Properties = Mp4Properties {
@@ -221,6 +246,12 @@ mod tests {
 		channels: 2,
 		version: 1,
 		input_sample_rate: 48000,
+		pre_skip: 312,
+		output_gain: 0,
+		channel_mapping_family: 0,
+		stream_count: 1,
+		coupled_stream_count: 1,
+		channel_mapping: Vec::new(),
 	};
 
 	const SPEEX_PROPERTIES: SpeexProperties = SpeexProperties {
@@ -231,14 +262,14 @@ mod tests {
 		channels: 2,
 		vbr: false,
 		overall_bitrate: 32,
-		audio_bitrate: 29,
+		audio_bitrate: 31,
 		nominal_bitrate: 29600,
 	};
 
 	const VORBIS_PROPERTIES: VorbisProperties = VorbisProperties {
 		duration: Duration::from_millis(1450),
 		overall_bitrate: 96,
-		audio_bitrate: 112,
+		audio_bitrate: 74,
 		sample_rate: 48000,
 		channels: 2,
 		version: 0,
@@ -255,6 +286,88 @@ mod tests {
 		sample_rate: 48000,
 		bit_depth: 16,
 		channels: 2,
+		valid_bits_per_sample: None,
+		channel_mask: None,
+		subformat: None,
+	};
+
+	// 5.1, 24 valid bits in a 32-bit container
+	const WAV_EXTENSIBLE_PCM_PROPERTIES: WavProperties = WavProperties {
+		format: WavFormat::PCM,
+		duration: Duration::from_millis(100),
+		overall_bitrate: 1541,
+		audio_bitrate: 1536,
+		sample_rate: 8000,
+		bit_depth: 24,
+		channels: 6,
+		valid_bits_per_sample: Some(24),
+		channel_mask: Some(ChannelMask(0x3F)),
+		subformat: Some([
+			0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x10, 0x00, 0x80, 0x00, 0x00, 0xAA, 0x00, 0x38,
+			0x9B, 0x71,
+		]),
+	};
+
+	const WAV_EXTENSIBLE_FLOAT_PROPERTIES: WavProperties = WavProperties {
+		format: WavFormat::IEEE_FLOAT,
+		duration: Duration::from_millis(100),
+		overall_bitrate: 517,
+		audio_bitrate: 512,
+		sample_rate: 8000,
+		bit_depth: 32,
+		channels: 2,
+		valid_bits_per_sample: Some(32),
+		channel_mask: Some(ChannelMask(0x3)),
+		subformat: Some([
+			0x03, 0x00, 0x00, 0x00, 0x00, 0x00, 0x10, 0x00, 0x80, 0x00, 0x00, 0xAA, 0x00, 0x38,
+			0x9B, 0x71,
+		]),
+	};
+
+	// KSDATAFORMAT_SUBTYPE_AMBISONIC_B_FORMAT_PCM, with no "fact" chunk
+	const WAV_EXTENSIBLE_AMBISONIC_PROPERTIES: WavProperties = WavProperties {
+		format: WavFormat::Other(0xFFFE),
+		duration: Duration::from_millis(100),
+		overall_bitrate: 517,
+		audio_bitrate: 512,
+		sample_rate: 8000,
+		bit_depth: 16,
+		channels: 4,
+		valid_bits_per_sample: Some(16),
+		channel_mask: Some(ChannelMask(0)),
+		subformat: Some([
+			0x01, 0x00, 0x00, 0x00, 0x21, 0x07, 0xD3, 0x11, 0x86, 0x44, 0xC8, 0xC1, 0xCA, 0x00,
+			0x00, 0x00,
+		]),
+	};
+
+	// 4 bits per sample, with 200ms of samples in the "fact" chunk.
+	// The stream length would suggest 400ms if it were treated as 4-bit PCM.
+	const WAV_IMA_ADPCM_PROPERTIES: WavProperties = WavProperties {
+		format: WavFormat::IMA_ADPCM,
+		duration: Duration::from_millis(200),
+		overall_bitrate: 66,
+		audio_bitrate: 64,
+		sample_rate: 8000,
+		bit_depth: 4,
+		channels: 1,
+		valid_bits_per_sample: None,
+		channel_mask: None,
+		subformat: None,
+	};
+
+	// No "fact" chunk, the duration comes from the stream length
+	const WAV_ALAW_PROPERTIES: WavProperties = WavProperties {
+		format: WavFormat::ALAW,
+		duration: Duration::from_millis(100),
+		overall_bitrate: 67,
+		audio_bitrate: 64,
+		sample_rate: 8000,
+		bit_depth: 8,
+		channels: 1,
+		valid_bits_per_sample: None,
+		channel_mask: None,
+		subformat: None,
 	};
 
 	const WAVPACK_PROPERTIES: WavPackProperties = WavPackProperties {
@@ -266,6 +379,108 @@ mod tests {
 		channels: 2,
 		bit_depth: 16,
 		lossless: true,
+		float: false,
+		dsd: false,
+		correction_file: false,
+		channel_mask: None,
+		signature: None,
+		compression_mode: None,
+		extra_mode: None,
+	};
+
+	// 16-bit stereo, hybrid, high mode, with a correction file
+	const WAVPACK_HYBRID_PROPERTIES: WavPackProperties = WavPackProperties {
+		version: 1040,
+		duration: Duration::from_millis(100),
+		overall_bitrate: 24,
+		audio_bitrate: 24,
+		sample_rate: 44100,
+		channels: 2,
+		bit_depth: 16,
+		lossless: false,
+		float: false,
+		dsd: false,
+		correction_file: true,
+		channel_mask: None,
+		signature: None,
+		compression_mode: Some(WavPackCompressionMode::High),
+		extra_mode: None,
+	};
+
+	// 32-bit float mono, fast mode, extra processing level 4
+	const WAVPACK_FLOAT_PROPERTIES: WavPackProperties = WavPackProperties {
+		version: 1040,
+		duration: Duration::from_millis(100),
+		overall_bitrate: 24,
+		audio_bitrate: 24,
+		sample_rate: 48000,
+		channels: 1,
+		bit_depth: 32,
+		lossless: true,
+		float: true,
+		dsd: false,
+		correction_file: false,
+		channel_mask: None,
+		signature: None,
+		compression_mode: Some(WavPackCompressionMode::Fast),
+		extra_mode: Some(4),
+	};
+
+	// DSD64, the sample rate is 44100 Hz shifted by the DSD sub-block
+	const WAVPACK_DSD_PROPERTIES: WavPackProperties = WavPackProperties {
+		version: 1040,
+		duration: Duration::from_millis(100),
+		overall_bitrate: 24,
+		audio_bitrate: 24,
+		sample_rate: 2_822_400,
+		channels: 2,
+		bit_depth: 8,
+		lossless: true,
+		float: false,
+		dsd: true,
+		correction_file: false,
+		channel_mask: None,
+		signature: None,
+		compression_mode: None,
+		extra_mode: None,
+	};
+
+	// A non-standard sample rate, with the MD5 signature in a trailing metadata block
+	const WAVPACK_MD5_PROPERTIES: WavPackProperties = WavPackProperties {
+		version: 1040,
+		duration: Duration::from_millis(100),
+		overall_bitrate: 28,
+		audio_bitrate: 28,
+		sample_rate: 22000,
+		channels: 2,
+		bit_depth: 16,
+		lossless: true,
+		float: false,
+		dsd: false,
+		correction_file: false,
+		channel_mask: None,
+		signature: Some(0xD41D_8CD9_8F00_B204_E980_0998_ECF8_427E),
+		compression_mode: None,
+		extra_mode: None,
+	};
+
+	// 5.1, stored as 3 stereo blocks
+	const WAVPACK_MULTICHANNEL_PROPERTIES: WavPackProperties = WavPackProperties {
+		version: 1040,
+		duration: Duration::from_millis(100),
+		overall_bitrate: 70,
+		audio_bitrate: 70,
+		sample_rate: 44100,
+		channels: 6,
+		bit_depth: 16,
+		lossless: true,
+		float: false,
+		dsd: false,
+		correction_file: false,
+		channel_mask: Some(ChannelMask(0x3F)),
+		signature: None,
+		compression_mode: None,
+		extra_mode: None,
 	};
 
 	fn get_properties<T>(path: &str) -> T::Properties
@@ -288,6 +503,113 @@ mod tests {
 		);
 	}
 
+	#[test]
+	fn aiff_sowt_properties() {
+		assert_eq!(
+			get_properties::<AiffFile>("tests/files/assets/minimal/aiff_compression_sowt.aiff"),
+			AiffProperties {
+				duration: Duration::from_millis(100),
+				overall_bitrate: 262,
+				audio_bitrate: 257,
+				sample_rate: 8000,
+				sample_size: 16,
+				channels: 2,
+				compression_type: Some(AiffCompressionType::SOWT),
+				compression_name: Some(String::new()),
+				format_version: Some(0xA280_5140),
+			}
+		);
+	}
+
+	#[test]
+	fn aiff_fl32_properties() {
+		let properties =
+			get_properties::<AiffFile>("tests/files/assets/minimal/aiff_compression_fl32.aiff");
+
+		// Some encoders leave the sample size at 0 for floating point audio
+		assert_eq!(properties.bit_depth(), 32);
+		assert_eq!(
+			properties,
+			AiffProperties {
+				duration: Duration::from_millis(100),
+				overall_bitrate: 262,
+				audio_bitrate: 257,
+				sample_rate: 8000,
+				sample_size: 0,
+				channels: 1,
+				compression_type: Some(AiffCompressionType::FL32),
+				compression_name: Some(String::from("32-bit floating point")),
+				format_version: None,
+			}
+		);
+	}
+
+	#[test]
+	fn aiff_ima4_properties() {
+		let properties =
+			get_properties::<AiffFile>("tests/files/assets/minimal/aiff_compression_ima4.aiff");
+
+		// The COMM chunk counts 34 byte packets of 64 samples for `ima4`
+		assert_eq!(properties.bit_depth(), 4);
+		assert_eq!(
+			properties,
+			AiffProperties {
+				duration: Duration::from_millis(200),
+				overall_bitrate: 37,
+				audio_bitrate: 34,
+				sample_rate: 8000,
+				sample_size: 16,
+				channels: 1,
+				compression_type: Some(AiffCompressionType::IMA4),
+				compression_name: Some(String::from("IMA 4:1")),
+				format_version: None,
+			}
+		);
+	}
+
+	#[test]
+	fn aiff_ulaw_properties() {
+		let properties =
+			get_properties::<AiffFile>("tests/files/assets/minimal/aiff_compression_ulaw.aiff");
+
+		assert_eq!(properties.bit_depth(), 8);
+		assert_eq!(
+			properties,
+			AiffProperties {
+				duration: Duration::from_millis(100),
+				overall_bitrate: 69,
+				audio_bitrate: 65,
+				sample_rate: 8000,
+				sample_size: 16,
+				channels: 1,
+				compression_type: Some(AiffCompressionType::ULAW),
+				compression_name: Some(String::from("ulaw 2:1")),
+				format_version: None,
+			}
+		);
+	}
+
+	#[test]
+	fn aiff_unknown_compression_properties() {
+		let properties =
+			get_properties::<AiffFile>("tests/files/assets/minimal/aiff_compression_qdm2.aiff");
+
+		assert_eq!(
+			properties
+				.compression_type()
+				.map(AiffCompressionType::fourcc),
+			Some(*b"QDM2")
+		);
+		assert_eq!(properties.compression_name(), Some("QDesign Music 2"));
+	}
+
+	#[test]
+	fn aiff_missing_compression_type() {
+		// An AIFF-C "COMM" chunk without a compression type
+		let mut f = File::open("tests/files/assets/minimal/aiff_compression_missing.aiff").unwrap();
+		assert!(AiffFile::read_from(&mut f, ParseOptions::default()).is_err());
+	}
+
 	#[test]
 	fn ape_properties() {
 		assert_eq!(
@@ -322,10 +644,11 @@ mod tests {
 
 	#[test]
 	fn mp3_properties() {
-		assert_eq!(
-			get_properties::<MPEGFile>("tests/files/assets/minimal/full_test.mp3"),
-			MP3_PROPERTIES
-		)
+		let mut properties = get_properties::<MPEGFile>("tests/files/assets/minimal/full_test.mp3");
+
+		// The Xing header is checked separately
+		assert!(properties.vbr_header.take().is_some());
+		assert_eq!(properties, MP3_PROPERTIES)
 	}
 
 	#[test]
@@ -362,10 +685,10 @@ mod tests {
 
 	#[test]
 	fn opus_properties() {
-		assert_eq!(
-			get_properties::<OpusFile>("tests/files/assets/minimal/full_test.opus"),
-			OPUS_PROPERTIES
-		)
+		let properties = get_properties::<OpusFile>("tests/files/assets/minimal/full_test.opus");
+
+		assert_eq!(properties.channel_mapping(), &[0, 1]);
+		assert_eq!(properties, OPUS_PROPERTIES)
 	}
 
 	#[test]
@@ -384,6 +707,74 @@ mod tests {
 		)
 	}
 
+	// The offsets of every page in an OGG stream
+	fn ogg_page_offsets(data: &[u8]) -> Vec<usize> {
+		data.windows(4)
+			.enumerate()
+			.filter_map(|(offset, window)| (window == b"OggS").then_some(offset))
+			.collect()
+	}
+
+	#[test]
+	fn vorbis_properties_chained() {
+		let mut data = std::fs::read("tests/files/assets/minimal/full_test.ogg").unwrap();
+		data.extend_from_within(..);
+
+		let properties = VorbisFile::read_from(&mut Cursor::new(data), ParseOptions::default())
+			.unwrap()
+			.properties;
+
+		assert_eq!(properties.duration(), Duration::from_millis(2901));
+	}
+
+	#[test]
+	fn vorbis_properties_unknown_granule() {
+		let mut data = std::fs::read("tests/files/assets/minimal/full_test.ogg").unwrap();
+
+		// A granule position of -1 on the last page should fall back to the one before it
+		let last_page = *ogg_page_offsets(&data).last().unwrap();
+		data[last_page + 6..last_page + 14].copy_from_slice(&u64::MAX.to_le_bytes());
+
+		let properties = VorbisFile::read_from(&mut Cursor::new(data), ParseOptions::default())
+			.unwrap()
+			.properties;
+
+		assert!(properties.duration() > Duration::ZERO);
+		assert!(properties.duration() < VORBIS_PROPERTIES.duration);
+	}
+
+	#[test]
+	fn vorbis_properties_nominal_bitrate_fallback() {
+		let mut data = std::fs::read("tests/files/assets/minimal/full_test.ogg").unwrap();
+
+		// With no usable granule positions, only the nominal bitrate is left
+		for offset in ogg_page_offsets(&data).into_iter().skip(1) {
+			data[offset + 6..offset + 14].copy_from_slice(&u64::MAX.to_le_bytes());
+		}
+
+		let properties = VorbisFile::read_from(&mut Cursor::new(data), ParseOptions::default())
+			.unwrap()
+			.properties;
+
+		assert_eq!(
+			properties.audio_bitrate(),
+			(VORBIS_PROPERTIES.bitrate_nominal / 1000) as u32
+		);
+		assert!(properties.duration() < VORBIS_PROPERTIES.duration);
+	}
+
+	#[test]
+	fn speex_properties_chained() {
+		let mut data = std::fs::read("tests/files/assets/minimal/full_test.spx").unwrap();
+		data.extend_from_within(..);
+
+		let properties = SpeexFile::read_from(&mut Cursor::new(data), ParseOptions::default())
+			.unwrap()
+			.properties;
+
+		assert_eq!(properties.duration(), Duration::from_millis(2938));
+	}
+
 	#[test]
 	fn wav_properties() {
 		assert_eq!(
@@ -392,6 +783,70 @@ mod tests {
 		)
 	}
 
+	#[test]
+	fn wav_extensible_pcm_properties() {
+		let properties =
+			get_properties::<WavFile>("tests/files/assets/minimal/wav_format_extensible_pcm.wav");
+
+		assert_eq!(
+			properties
+				.channel_mask()
+				.unwrap()
+				.speakers()
+				.collect::<Vec<_>>(),
+			[
+				Speaker::FrontLeft,
+				Speaker::FrontRight,
+				Speaker::FrontCenter,
+				Speaker::LowFrequency,
+				Speaker::BackLeft,
+				Speaker::BackRight,
+			]
+		);
+		assert_eq!(properties, WAV_EXTENSIBLE_PCM_PROPERTIES)
+	}
+
+	#[test]
+	fn wav_extensible_float_properties() {
+		assert_eq!(
+			get_properties::<WavFile>("tests/files/assets/minimal/wav_format_extensible_float.wav"),
+			WAV_EXTENSIBLE_FLOAT_PROPERTIES
+		)
+	}
+
+	#[test]
+	fn wav_extensible_unknown_subformat_properties() {
+		assert_eq!(
+			get_properties::<WavFile>(
+				"tests/files/assets/minimal/wav_format_extensible_ambisonic.wav"
+			),
+			WAV_EXTENSIBLE_AMBISONIC_PROPERTIES
+		)
+	}
+
+	#[test]
+	fn wav_ima_adpcm_properties() {
+		assert_eq!(
+			get_properties::<WavFile>("tests/files/assets/minimal/wav_format_ima_adpcm.wav"),
+			WAV_IMA_ADPCM_PROPERTIES
+		)
+	}
+
+	#[test]
+	fn wav_alaw_properties() {
+		assert_eq!(
+			get_properties::<WavFile>("tests/files/assets/minimal/wav_format_alaw.wav"),
+			WAV_ALAW_PROPERTIES
+		)
+	}
+
+	#[test]
+	fn wav_compressed_without_fact() {
+		// Compressed formats need a "fact" chunk to know the sample count
+		let mut f = File::open("tests/files/assets/minimal/wav_format_gsm610_no_fact.wav").unwrap();
+		assert!(WavFile::read_from(&mut f, ParseOptions::default()).is_err());
+	}
+
 	#[test]
 	fn wavpack_properties() {
 		assert_eq!(
@@ -399,4 +854,82 @@ mod tests {
 			WAVPACK_PROPERTIES
 		)
 	}
+
+	#[test]
+	fn wavpack_hybrid_properties() {
+		let properties =
+			get_properties::<WavPackFile>("tests/files/assets/minimal/wavpack_hybrid.wv");
+
+		assert!(properties.is_hybrid());
+		assert_eq!(properties, WAVPACK_HYBRID_PROPERTIES)
+	}
+
+	#[test]
+	fn wavpack_float_properties() {
+		assert_eq!(
+			get_properties::<WavPackFile>("tests/files/assets/minimal/wavpack_float.wv"),
+			WAVPACK_FLOAT_PROPERTIES
+		)
+	}
+
+	#[test]
+	fn wavpack_dsd_properties() {
+		assert_eq!(
+			get_properties::<WavPackFile>("tests/files/assets/minimal/wavpack_dsd.wv"),
+			WAVPACK_DSD_PROPERTIES
+		)
+	}
+
+	#[test]
+	fn wavpack_md5_properties() {
+		assert_eq!(
+			get_properties::<WavPackFile>("tests/files/assets/minimal/wavpack_md5.wv"),
+			WAVPACK_MD5_PROPERTIES
+		)
+	}
+
+	#[test]
+	fn wavpack_multichannel_properties() {
+		let properties =
+			get_properties::<WavPackFile>("tests/files/assets/minimal/wavpack_multichannel.wv");
+
+		assert!(properties
+			.channel_mask()
+			.unwrap()
+			.contains(Speaker::LowFrequency));
+		assert_eq!(properties, WAVPACK_MULTICHANNEL_PROPERTIES)
+	}
+
+	#[test]
+	fn wavpack_malformed_sub_block() {
+		// The channel info sub-block claims to be larger than its block
+		let path = "tests/files/assets/minimal/wavpack_malformed_sub_block.wv";
+
+		let mut f = File::open(path).unwrap();
+		assert!(WavPackFile::read_from(&mut f, ParseOptions::default()).is_err());
+
+		// Everything before the malformed sub-block is kept
+		let mut f = File::open(path).unwrap();
+		let properties = WavPackFile::read_from(
+			&mut f,
+			ParseOptions::new().parsing_mode(ParsingMode::Relaxed),
+		)
+		.unwrap()
+		.properties;
+
+		assert_eq!(properties.sample_rate(), 22000);
+		assert_eq!(properties.channels(), 2);
+		assert_eq!(properties.channel_mask(), None);
+		assert_eq!(properties.signature(), WAVPACK_MD5_PROPERTIES.signature);
+	}
+
+	#[test]
+	fn wavpack_trailing_junk() {
+		// Junk after the first frame is the end of the stream, even when strict
+		let properties =
+			get_properties::<WavPackFile>("tests/files/assets/minimal/wavpack_trailing_junk.wv");
+
+		assert_eq!(properties.sample_rate(), 22000);
+		assert_eq!(properties.signature(), WAVPACK_MD5_PROPERTIES.signature);
+	}
 }
@@ -1,5 +1,7 @@
+use crate::tag::timestamp::Timestamp;
 use crate::tag::TagType;
 
+use std::borrow::Cow;
 use std::collections::HashMap;
 
 macro_rules! first_key {
@@ -85,6 +87,7 @@ gen_map!(
 	"ALBUMARTISTSORT" 			   => AlbumArtistSortOrder,
 	"TITLESORT"		  			   => TrackTitleSortOrder,
 	"ARTISTSORT"	  			   => TrackArtistSortOrder,
+	"COMPOSERSORT"				   => ComposerSortOrder,
 	"Album Artist" | "ALBUMARTIST" => AlbumArtist,
 	"Artist"					   => TrackArtist,
 	"Arranger"					   => Arranger,
@@ -95,6 +98,9 @@ gen_map!(
 	"Lyricist"					   => Lyricist,
 	"DjMixer"					   => MixDj,
 	"Mixer"						   => MixEngineer,
+	// Credits are all stored as "name (role)", see `ID3v2Tag::involved_people`
+	"Performer"					   => InvolvedPeople,
+	"Performer"					   => MusicianCredits,
 	"Performer"					   => Performer,
 	"Producer"					   => Producer,
 	"Label"						   => Label,
@@ -103,11 +109,27 @@ gen_map!(
 	"Disc"						   => DiscTotal,
 	"Track"						   => TrackNumber,
 	"Track"						   => TrackTotal,
-	"Year"						   => Year,
+	"Year"						   => RecordingDate,
 	"ISRC"						   => ISRC,
 	"Barcode"					   => Barcode,
 	"CatalogNumber"				   => CatalogNumber,
+	"MUSICBRAINZ_TRACKID"		   => MusicBrainzRecordingId,
+	"MUSICBRAINZ_RELEASETRACKID"   => MusicBrainzTrackId,
+	"MUSICBRAINZ_ALBUMID"		   => MusicBrainzReleaseId,
+	"MUSICBRAINZ_RELEASEGROUPID"   => MusicBrainzReleaseGroupId,
+	"MUSICBRAINZ_ARTISTID"		   => MusicBrainzArtistId,
+	"MUSICBRAINZ_ALBUMARTISTID"	   => MusicBrainzReleaseArtistId,
+	"MUSICBRAINZ_WORKID"		   => MusicBrainzWorkId,
+	"MUSICBRAINZ_ALBUMSTATUS"	   => MusicBrainzReleaseStatus,
+	"MUSICBRAINZ_ALBUMTYPE"		   => MusicBrainzReleaseType,
+	"RELEASECOUNTRY"			   => MusicBrainzReleaseCountry,
+	"ACOUSTID_ID"				   => AcoustId,
+	"ACOUSTID_FINGERPRINT"		   => AcoustIdFingerprint,
+	"DISCOGS_RELEASE_ID"		   => DiscogsReleaseId,
+	"DISCOGS_MASTER_RELEASE_ID"	   => DiscogsMasterReleaseId,
+	"DISCOGS_ARTIST_ID"			   => DiscogsArtistId,
 	"Compilation"				   => FlagCompilation,
+	"Rating"					   => Popularimeter,
 	"Media"						   => OriginalMediaType,
 	"EncodedBy"					   => EncodedBy,
 	"REPLAYGAIN_ALBUM_GAIN"        => ReplayGainAlbumGain,
@@ -116,6 +138,7 @@ gen_map!(
 	"REPLAYGAIN_TRACK_PEAK"        => ReplayGainTrackPeak,
 	"Genre"						   => Genre,
 	"Mood"						   => Mood,
+	"BPM"						   => BPM,
 	"Copyright"					   => CopyrightMessage,
 	"Comment"					   => Comment,
 	"language"					   => Language,
@@ -123,6 +146,18 @@ gen_map!(
 	"Lyrics"					   => Lyrics
 );
 
+gen_map!(
+	#[cfg(feature = "lyrics3")]
+	LYRICS3_MAP;
+
+	"ETT" => TrackTitle,
+	"EAR" => TrackArtist,
+	"EAL" => AlbumTitle,
+	"LYR" => Lyrics,
+	"AUT" => Lyricist,
+	"INF" => Comment
+);
+
 gen_map! (
 	#[cfg(feature = "id3v2")]
 	ID3V2_MAP;
@@ -148,7 +183,6 @@ gen_map! (
 	"TIPL"			        => InvolvedPeople,
 	"TEXT"			        => Lyricist,
 	"TMCL"			        => MusicianCredits,
-	"IPRO"			        => Producer,
 	"TPUB"			        => Publisher,
 	"TPUB"			        => Label,
 	"TRSN"			        => InternetRadioStationName,
@@ -162,6 +196,22 @@ gen_map! (
 	"TDRC"			        => RecordingDate,
 	"TDOR"			        => OriginalReleaseDate,
 	"TSRC"			        => ISRC,
+	"CATALOGNUMBER"	        => CatalogNumber,
+	"UFID"			        => MusicBrainzRecordingId,
+	"MusicBrainz Release Track Id"		=> MusicBrainzTrackId,
+	"MusicBrainz Album Id"				=> MusicBrainzReleaseId,
+	"MusicBrainz Release Group Id"		=> MusicBrainzReleaseGroupId,
+	"MusicBrainz Artist Id"				=> MusicBrainzArtistId,
+	"MusicBrainz Album Artist Id"		=> MusicBrainzReleaseArtistId,
+	"MusicBrainz Work Id"				=> MusicBrainzWorkId,
+	"MusicBrainz Album Status"			=> MusicBrainzReleaseStatus,
+	"MusicBrainz Album Type"			=> MusicBrainzReleaseType,
+	"MusicBrainz Album Release Country" => MusicBrainzReleaseCountry,
+	"Acoustid Id"						=> AcoustId,
+	"Acoustid Fingerprint"				=> AcoustIdFingerprint,
+	"DISCOGS_RELEASE_ID"				=> DiscogsReleaseId,
+	"DISCOGS_MASTER_RELEASE_ID"			=> DiscogsMasterReleaseId,
+	"DISCOGS_ARTIST_ID"					=> DiscogsArtistId,
 	"MVNM"			        => Movement,
 	"MVIN"			        => MovementIndex,
 	"TCMP"			        => FlagCompilation,
@@ -235,18 +285,37 @@ gen_map! (
 	"disk"									=> DiscTotal,
 	"trkn"									=> TrackNumber,
 	"trkn"									=> TrackTotal,
-	"rate"									=> Popularimeter,
+	"----:com.apple.iTunes:rate" | "rate"	=> Popularimeter,
 	"rtng"									=> ParentalAdvisory,
 	"\u{a9}day"								=> RecordingDate,
 	"----:com.apple.iTunes:ISRC"			=> ISRC,
 	"----:com.apple.iTunes:BARCODE"			=> Barcode,
 	"----:com.apple.iTunes:CATALOGNUMBER"	=> CatalogNumber,
+	"----:com.apple.iTunes:MusicBrainz Track Id"				=> MusicBrainzRecordingId,
+	"----:com.apple.iTunes:MusicBrainz Release Track Id"		=> MusicBrainzTrackId,
+	"----:com.apple.iTunes:MusicBrainz Album Id"				=> MusicBrainzReleaseId,
+	"----:com.apple.iTunes:MusicBrainz Release Group Id"		=> MusicBrainzReleaseGroupId,
+	"----:com.apple.iTunes:MusicBrainz Artist Id"				=> MusicBrainzArtistId,
+	"----:com.apple.iTunes:MusicBrainz Album Artist Id"			=> MusicBrainzReleaseArtistId,
+	"----:com.apple.iTunes:MusicBrainz Work Id"					=> MusicBrainzWorkId,
+	"----:com.apple.iTunes:MusicBrainz Album Status"			=> MusicBrainzReleaseStatus,
+	"----:com.apple.iTunes:MusicBrainz Album Type"				=> MusicBrainzReleaseType,
+	"----:com.apple.iTunes:MusicBrainz Album Release Country"	=> MusicBrainzReleaseCountry,
+	"----:com.apple.iTunes:Acoustid Id"							=> AcoustId,
+	"----:com.apple.iTunes:Acoustid Fingerprint"				=> AcoustIdFingerprint,
+	"----:com.apple.iTunes:DISCOGS_RELEASE_ID"					=> DiscogsReleaseId,
+	"----:com.apple.iTunes:DISCOGS_MASTER_RELEASE_ID"			=> DiscogsMasterReleaseId,
+	"----:com.apple.iTunes:DISCOGS_ARTIST_ID"					=> DiscogsArtistId,
 	"cpil"									=> FlagCompilation,
 	"pcst"									=> FlagPodcast,
 	"----:com.apple.iTunes:MEDIA"			=> OriginalMediaType,
 	"\u{a9}enc"								=> EncodedBy,
 	"\u{a9}too"								=> EncoderSoftware,
 	"\u{a9}gen"								=> Genre,
+	"----:com.apple.iTunes:replaygain_album_gain"	=> ReplayGainAlbumGain,
+	"----:com.apple.iTunes:replaygain_album_peak"	=> ReplayGainAlbumPeak,
+	"----:com.apple.iTunes:replaygain_track_gain"	=> ReplayGainTrackGain,
+	"----:com.apple.iTunes:replaygain_track_peak"	=> ReplayGainTrackPeak,
 	"----:com.apple.iTunes:MOOD"			=> Mood,
 	"tmpo"									=> BPM,
 	"cprt"									=> CopyrightMessage,
@@ -300,6 +369,7 @@ gen_map!(
 	"ALBUMARTISTSORT" 			   => AlbumArtistSortOrder,
 	"TITLESORT" 	  			   => TrackTitleSortOrder,
 	"ARTISTSORT"	  			   => TrackArtistSortOrder,
+	"COMPOSERSORT"				   => ComposerSortOrder,
 	"ALBUMARTIST"	  			   => AlbumArtist,
 	"ARTIST"		  			   => TrackArtist,
 	"ARRANGER"		  			   => Arranger,
@@ -310,6 +380,9 @@ gen_map!(
 	"LYRICIST"					   => Lyricist,
 	"DJMIXER"					   => MixDj,
 	"MIXER"						   => MixEngineer,
+	// Credits are all stored as "name (role)", see `ID3v2Tag::involved_people`
+	"PERFORMER"					   => InvolvedPeople,
+	"PERFORMER"					   => MusicianCredits,
 	"PERFORMER"					   => Performer,
 	"PRODUCER"					   => Producer,
 	"PUBLISHER"					   => Publisher,
@@ -325,6 +398,21 @@ gen_map!(
 	"ORIGINALDATE" 				   => OriginalReleaseDate,
 	"ISRC" 						   => ISRC,
 	"CATALOGNUMBER" 			   => CatalogNumber,
+	"MUSICBRAINZ_TRACKID"		   => MusicBrainzRecordingId,
+	"MUSICBRAINZ_RELEASETRACKID"   => MusicBrainzTrackId,
+	"MUSICBRAINZ_ALBUMID"		   => MusicBrainzReleaseId,
+	"MUSICBRAINZ_RELEASEGROUPID"   => MusicBrainzReleaseGroupId,
+	"MUSICBRAINZ_ARTISTID"		   => MusicBrainzArtistId,
+	"MUSICBRAINZ_ALBUMARTISTID"	   => MusicBrainzReleaseArtistId,
+	"MUSICBRAINZ_WORKID"		   => MusicBrainzWorkId,
+	"RELEASESTATUS" | "MUSICBRAINZ_ALBUMSTATUS" => MusicBrainzReleaseStatus,
+	"RELEASETYPE" | "MUSICBRAINZ_ALBUMTYPE"	   => MusicBrainzReleaseType,
+	"RELEASECOUNTRY"			   => MusicBrainzReleaseCountry,
+	"ACOUSTID_ID"				   => AcoustId,
+	"ACOUSTID_FINGERPRINT"		   => AcoustIdFingerprint,
+	"DISCOGS_RELEASE_ID"		   => DiscogsReleaseId,
+	"DISCOGS_MASTER_RELEASE_ID"	   => DiscogsMasterReleaseId,
+	"DISCOGS_ARTIST_ID"			   => DiscogsArtistId,
 	"COMPILATION" 				   => FlagCompilation,
 	"MEDIA" 					   => OriginalMediaType,
 	"ENCODED-BY" 				   => EncodedBy,
@@ -358,6 +446,7 @@ macro_rules! gen_item_keys {
 		]
 	) => {
 		#[derive(PartialEq, Clone, Debug, Eq, Hash)]
+		#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 		#[allow(missing_docs)]
 		#[non_exhaustive]
 		/// A generic representation of a tag's key
@@ -423,6 +512,9 @@ gen_item_keys!(
 		#[cfg(feature = "id3v2")]
 		[TagType::ID3v2, ID3V2_MAP],
 
+		#[cfg(feature = "lyrics3")]
+		[TagType::Lyrics3, LYRICS3_MAP],
+
 		#[cfg(feature = "mp4_ilst")]
 		[TagType::MP4ilst, ILST_MAP],
 
@@ -496,6 +588,23 @@ gen_item_keys!(
 		Movement,
 		MovementIndex,
 
+		// MusicBrainz, AcoustID, and Discogs identifiers
+		MusicBrainzRecordingId,
+		MusicBrainzTrackId,
+		MusicBrainzReleaseId,
+		MusicBrainzReleaseGroupId,
+		MusicBrainzArtistId,
+		MusicBrainzReleaseArtistId,
+		MusicBrainzWorkId,
+		MusicBrainzReleaseStatus,
+		MusicBrainzReleaseType,
+		MusicBrainzReleaseCountry,
+		AcoustId,
+		AcoustIdFingerprint,
+		DiscogsReleaseId,
+		DiscogsMasterReleaseId,
+		DiscogsArtistId,
+
 		// Flags
 		FlagCompilation,
 		FlagPodcast,
@@ -556,6 +665,8 @@ gen_item_keys!(
 );
 
 #[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[non_exhaustive]
 /// Represents a tag item's value
 pub enum ItemValue {
 	/// Any UTF-8 encoded text
@@ -567,6 +678,21 @@ pub enum ItemValue {
 	Locator(String),
 	/// Binary information
 	Binary(Vec<u8>),
+	/// An integer
+	///
+	/// This is stored natively in `MP4` tags (`tmpo`, `rtng`, etc.), and written as
+	/// a decimal string in other tags
+	Integer(i64),
+	/// A boolean flag
+	///
+	/// This is stored natively in `MP4` tags (`cpil`, `pcst`, etc.), and written as
+	/// `"1"` or `"0"` in other tags
+	Bool(bool),
+	/// A date and/or time
+	///
+	/// This is written as a timestamp frame (`TDRC`, `TDOR`, etc.) in `ID3v2` tags, and in
+	/// the format described in [`Timestamp`] in other tags
+	Date(Timestamp),
 }
 
 impl ItemValue {
@@ -594,6 +720,57 @@ impl ItemValue {
 		}
 	}
 
+	/// Returns the value if the variant is `Integer`
+	pub fn integer(&self) -> Option<i64> {
+		match self {
+			Self::Integer(int) => Some(*int),
+			_ => None,
+		}
+	}
+
+	/// Returns the value if the variant is `Bool`
+	pub fn boolean(&self) -> Option<bool> {
+		match self {
+			Self::Bool(flag) => Some(*flag),
+			_ => None,
+		}
+	}
+
+	/// Returns the value if the variant is `Date`
+	pub fn date(&self) -> Option<Timestamp> {
+		match self {
+			Self::Date(date) => Some(*date),
+			_ => None,
+		}
+	}
+
+	/// Returns the textual representation of the value
+	///
+	/// This is the value itself for `Text` and `Locator`, and the string the typed
+	/// variants are written as in text-based formats. `Binary` values have none.
+	pub fn as_text(&self) -> Option<Cow<'_, str>> {
+		match self {
+			Self::Text(text) | Self::Locator(text) => Some(Cow::Borrowed(text)),
+			Self::Integer(int) => Some(Cow::Owned(int.to_string())),
+			Self::Bool(flag) => Some(Cow::Borrowed(if *flag { "1" } else { "0" })),
+			Self::Date(date) => Some(Cow::Owned(date.to_string())),
+			Self::Binary(_) => None,
+		}
+	}
+
+	pub(crate) fn is_typed(&self) -> bool {
+		matches!(self, Self::Integer(_) | Self::Bool(_) | Self::Date(_))
+	}
+
+	// Converts the typed variants to `Text`, leaving everything else as-is
+	pub(crate) fn into_text_value(self) -> Self {
+		if self.is_typed() {
+			return Self::Text(self.as_text().unwrap_or_default().into_owned());
+		}
+
+		self
+	}
+
 	/// Consumes the `ItemValue`, returning a `String` if the variant is `Text` or `Locator`
 	pub fn into_string(self) -> Option<String> {
 		match self {
@@ -612,7 +789,7 @@ impl ItemValue {
 }
 
 pub(crate) enum ItemValueRef<'a> {
-	Text(&'a str),
+	Text(Cow<'a, str>),
 	Locator(&'a str),
 	Binary(&'a [u8]),
 }
@@ -620,14 +797,26 @@ pub(crate) enum ItemValueRef<'a> {
 impl<'a> Into<ItemValueRef<'a>> for &'a ItemValue {
 	fn into(self) -> ItemValueRef<'a> {
 		match self {
-			ItemValue::Text(text) => ItemValueRef::Text(text),
+			ItemValue::Text(text) => ItemValueRef::Text(Cow::Borrowed(text)),
 			ItemValue::Locator(locator) => ItemValueRef::Locator(locator),
 			ItemValue::Binary(binary) => ItemValueRef::Binary(binary),
+			typed => ItemValueRef::Text(typed.as_text().unwrap_or_default()),
+		}
+	}
+}
+
+impl<'a> From<ItemValueRef<'a>> for ItemValue {
+	fn from(value: ItemValueRef<'a>) -> Self {
+		match value {
+			ItemValueRef::Text(text) => ItemValue::Text(text.into_owned()),
+			ItemValueRef::Locator(locator) => ItemValue::Locator(locator.to_string()),
+			ItemValueRef::Binary(binary) => ItemValue::Binary(binary.to_vec()),
 		}
 	}
 }
 
 #[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 /// Represents a tag item (key/value)
 pub struct TagItem {
 	pub(crate) item_key: ItemKey,
@@ -694,6 +883,12 @@ impl TagItem {
 			return VALID_ITEMKEYS.contains(&self.item_key);
 		}
 
+		// Credits are stored together in the `TIPL` and `TMCL` frames
+		#[cfg(feature = "id3v2")]
+		if tag_type == TagType::ID3v2 && crate::id3::v2::tag::is_credit_key(&self.item_key) {
+			return true;
+		}
+
 		self.item_key.map_key(tag_type, false).is_some()
 	}
 }
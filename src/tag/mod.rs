@@ -1,14 +1,23 @@
 pub(crate) mod item;
+pub(crate) mod passthrough;
+pub(crate) mod rating;
+pub(crate) mod timestamp;
 pub(crate) mod utils;
 
+use crate::diff::{self, DiffOptions, TagDiff};
 use crate::error::{LoftyError, Result};
 use crate::file::FileType;
 use crate::macros::err;
+use crate::merge::{self, MergeOptions, MergeReport};
 use crate::picture::{Picture, PictureType};
 use crate::probe::Probe;
 use crate::traits::{Accessor, TagExt};
 use item::{ItemKey, ItemValue, TagItem};
+use passthrough::Passthrough;
+use rating::Rating;
+use timestamp::{year_from_date, Timestamp};
 
+use std::borrow::Cow;
 use std::fs::{File, OpenOptions};
 use std::io::Write;
 use std::path::Path;
@@ -43,6 +52,7 @@ macro_rules! impl_accessor {
 }
 
 #[derive(Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 /// Represents a parsed tag
 ///
 /// This is a tag that is loosely bound to a specific [`TagType`].
@@ -56,6 +66,18 @@ macro_rules! impl_accessor {
 ///
 /// Any conversion will, of course, be lossy to a varying degree.
 ///
+/// ## Native data
+///
+/// When converting from an [`ID3v2Tag`](crate::id3::v2::ID3v2Tag) or [`Ilst`](crate::mp4::Ilst),
+/// the original frames/atoms are kept alongside the items. When the tag is converted back into (or written as)
+/// the same format, any frames/atoms whose items were not modified are written as-is. This keeps data that has
+/// no generic equivalent, such as frame flags, text encodings, unknown frames, and atom data types.
+///
+/// Pictures are the exception, as they are already stored in the tag. Picture frames/atoms are always
+/// created from [`Tag::pictures`].
+///
+/// This data can be discarded with [`Tag::clear_passthrough`].
+///
 /// ## Usage
 ///
 /// Accessing common items
@@ -103,6 +125,27 @@ pub struct Tag {
 	tag_type: TagType,
 	pub(crate) pictures: Vec<Picture>,
 	pub(crate) items: Vec<TagItem>,
+	#[cfg_attr(feature = "serde", serde(skip))]
+	pub(crate) passthrough: Option<Passthrough>,
+}
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for crate::picture::WithoutPictureData<'_, Tag> {
+	fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+	where
+		S: serde::Serializer,
+	{
+		use serde::ser::SerializeStruct;
+
+		let mut state = serializer.serialize_struct("Tag", 3)?;
+		state.serialize_field("tag_type", &self.0.tag_type)?;
+		state.serialize_field(
+			"pictures",
+			&crate::picture::WithoutPictureData(self.0.pictures.as_slice()),
+		)?;
+		state.serialize_field("items", &self.0.items)?;
+		state.end()
+	}
 }
 
 impl IntoIterator for Tag {
@@ -116,11 +159,22 @@ impl IntoIterator for Tag {
 
 impl Accessor for Tag {
 	impl_accessor!(
-		TrackArtist => artist,
-		TrackTitle  => title,
-		AlbumTitle  => album,
-		Genre       => genre,
-		Comment     => comment
+		TrackArtist          => artist,
+		TrackTitle           => title,
+		AlbumTitle           => album,
+		Genre                => genre,
+		Comment              => comment,
+		AlbumArtist          => album_artist,
+		Composer             => composer,
+		Lyrics               => lyrics,
+		TrackTitleSortOrder  => title_sort_order,
+		TrackArtistSortOrder => artist_sort_order,
+		AlbumTitleSortOrder  => album_sort_order,
+		AlbumArtistSortOrder => album_artist_sort_order,
+		ComposerSortOrder    => composer_sort_order,
+		ISRC                 => isrc,
+		Label                => label,
+		CatalogNumber        => catalog_number
 	);
 
 	fn track(&self) -> Option<u32> {
@@ -192,25 +246,158 @@ impl Accessor for Tag {
 			.get_string(&ItemKey::Year)
 			.map_or_else(|| self.get_string(&ItemKey::RecordingDate), Some)
 		{
-			return item.chars().take(4).collect::<String>().parse::<u32>().ok();
+			return year_from_date(item);
 		}
 
-		None
+		self.get_date(&ItemKey::RecordingDate)
+			.map(|date| u32::from(date.year))
 	}
 
 	fn set_year(&mut self, value: u32) {
-		if let Some(item) = self.get_string(&ItemKey::RecordingDate) {
-			if item.len() >= 4 {
-				let (_, remaining) = item.split_at(4);
-				self.insert_text(ItemKey::RecordingDate, format!("{}{}", value, remaining));
-			}
+		// Keep the rest of the date intact if there is one
+		let timestamp = self.get_date(&ItemKey::RecordingDate);
+
+		match (timestamp, u16::try_from(value)) {
+			(Some(mut timestamp), Ok(year)) if year <= 9999 => {
+				timestamp.year = year;
+				self.insert_text(ItemKey::RecordingDate, timestamp.to_string());
+			},
+			_ => {
+				self.insert_text(ItemKey::RecordingDate, value.to_string());
+			},
 		}
+
+		self.remove_key(&ItemKey::Year);
 	}
 
 	fn remove_year(&mut self) {
 		self.remove_key(&ItemKey::Year);
 		self.remove_key(&ItemKey::RecordingDate);
 	}
+
+	fn date(&self) -> Option<Timestamp> {
+		self.get_date(&ItemKey::RecordingDate)
+	}
+
+	fn set_date(&mut self, value: Timestamp) {
+		self.insert_item(TagItem::new(ItemKey::RecordingDate, ItemValue::Date(value)));
+		self.remove_key(&ItemKey::Year);
+	}
+
+	fn remove_date(&mut self) {
+		self.remove_key(&ItemKey::RecordingDate);
+	}
+
+	fn bpm(&self) -> Option<u32> {
+		self.get_integer(&ItemKey::BPM)
+			.and_then(|bpm| u32::try_from(bpm).ok())
+	}
+
+	fn set_bpm(&mut self, value: u32) {
+		self.insert_item(TagItem::new(
+			ItemKey::BPM,
+			ItemValue::Integer(i64::from(value)),
+		));
+	}
+
+	fn remove_bpm(&mut self) {
+		self.remove_key(&ItemKey::BPM);
+	}
+
+	fn replay_gain_track_gain(&self) -> Option<f32> {
+		self.get_string(&ItemKey::ReplayGainTrackGain)
+			.and_then(utils::parse_replay_gain)
+	}
+
+	fn set_replay_gain_track_gain(&mut self, value: f32) {
+		self.insert_text(
+			ItemKey::ReplayGainTrackGain,
+			utils::format_replay_gain(value),
+		);
+	}
+
+	fn remove_replay_gain_track_gain(&mut self) {
+		self.remove_key(&ItemKey::ReplayGainTrackGain);
+	}
+
+	fn replay_gain_track_peak(&self) -> Option<f32> {
+		self.get_string(&ItemKey::ReplayGainTrackPeak)
+			.and_then(utils::parse_replay_gain_peak)
+	}
+
+	fn set_replay_gain_track_peak(&mut self, value: f32) {
+		self.insert_text(
+			ItemKey::ReplayGainTrackPeak,
+			utils::format_replay_gain_peak(value),
+		);
+	}
+
+	fn remove_replay_gain_track_peak(&mut self) {
+		self.remove_key(&ItemKey::ReplayGainTrackPeak);
+	}
+
+	fn replay_gain_album_gain(&self) -> Option<f32> {
+		self.get_string(&ItemKey::ReplayGainAlbumGain)
+			.and_then(utils::parse_replay_gain)
+	}
+
+	fn set_replay_gain_album_gain(&mut self, value: f32) {
+		self.insert_text(
+			ItemKey::ReplayGainAlbumGain,
+			utils::format_replay_gain(value),
+		);
+	}
+
+	fn remove_replay_gain_album_gain(&mut self) {
+		self.remove_key(&ItemKey::ReplayGainAlbumGain);
+	}
+
+	fn replay_gain_album_peak(&self) -> Option<f32> {
+		self.get_string(&ItemKey::ReplayGainAlbumPeak)
+			.and_then(utils::parse_replay_gain_peak)
+	}
+
+	fn set_replay_gain_album_peak(&mut self, value: f32) {
+		self.insert_text(
+			ItemKey::ReplayGainAlbumPeak,
+			utils::format_replay_gain_peak(value),
+		);
+	}
+
+	fn remove_replay_gain_album_peak(&mut self) {
+		self.remove_key(&ItemKey::ReplayGainAlbumPeak);
+	}
+
+	fn compilation(&self) -> Option<bool> {
+		self.get_bool(&ItemKey::FlagCompilation)
+	}
+
+	fn set_compilation(&mut self, value: bool) {
+		self.insert_item(TagItem::new(
+			ItemKey::FlagCompilation,
+			ItemValue::Bool(value),
+		));
+	}
+
+	fn remove_compilation(&mut self) {
+		self.remove_key(&ItemKey::FlagCompilation);
+	}
+
+	fn rating(&self) -> Option<Rating> {
+		self.get_integer(&ItemKey::Popularimeter)
+			.map(|rating| Rating::new(rating.clamp(0, 100) as u8))
+	}
+
+	fn set_rating(&mut self, value: Rating) {
+		self.insert_item(TagItem::new(
+			ItemKey::Popularimeter,
+			ItemValue::Integer(i64::from(value.value())),
+		));
+	}
+
+	fn remove_rating(&mut self) {
+		self.remove_key(&ItemKey::Popularimeter);
+	}
 }
 
 impl Tag {
@@ -220,6 +407,7 @@ impl Tag {
 			tag_type,
 			pictures: Vec::new(),
 			items: Vec::new(),
+			passthrough: None,
 		}
 	}
 
@@ -229,6 +417,87 @@ impl Tag {
 		self.tag_type = tag_type
 	}
 
+	/// Compare the tag to another, returning what changed between them
+	///
+	/// The tags are compared by their [`ItemKey`]s, so they can be of different [`TagType`]s.
+	/// See [`diff`](crate::diff) for more information.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use lofty::diff::{Change, DiffOptions};
+	/// use lofty::{Accessor, ItemKey, Tag, TagType};
+	///
+	/// let mut old = Tag::new(TagType::ID3v2);
+	/// old.set_title(String::from("Foo title"));
+	///
+	/// let mut new = old.clone();
+	/// new.set_artist(String::from("Foo artist"));
+	///
+	/// let diff = old.diff(&new, DiffOptions::new());
+	/// assert_eq!(diff.items().len(), 1);
+	/// assert_eq!(diff.items()[0].key, ItemKey::TrackArtist);
+	/// assert!(matches!(diff.items()[0].change, Change::Added(_)));
+	/// ```
+	pub fn diff(&self, other: &Tag, options: DiffOptions) -> TagDiff {
+		diff::diff_tags(self, other, options)
+	}
+
+	/// Merge the items and pictures of another tag into this one
+	///
+	/// Unlike [`Tag::re_map`], items without a mapping in this tag's [`TagType`] can be kept in
+	/// custom fields. Anything that could not be carried over is returned in the [`MergeReport`].
+	/// See [`merge`](crate::merge) for more information.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use lofty::merge::{MergeOptions, MergePolicy};
+	/// use lofty::{Accessor, Tag, TagType};
+	///
+	/// let mut vorbis_comments = Tag::new(TagType::VorbisComments);
+	/// vorbis_comments.set_title(String::from("Foo title"));
+	/// vorbis_comments.set_artist(String::from("Foo artist"));
+	///
+	/// let mut id3v2 = Tag::new(TagType::ID3v2);
+	/// id3v2.set_title(String::from("Bar title"));
+	///
+	/// let report = id3v2.merge(
+	/// 	&vorbis_comments,
+	/// 	MergeOptions::new().policy(MergePolicy::FillMissing),
+	/// );
+	///
+	/// assert!(report.is_empty());
+	/// assert_eq!(id3v2.title(), Some("Bar title"));
+	/// assert_eq!(id3v2.artist(), Some("Foo artist"));
+	/// ```
+	pub fn merge(&mut self, other: &Tag, options: MergeOptions) -> MergeReport {
+		merge::merge_tags(self, other, options)
+	}
+
+	/// Copy the items and pictures of this tag into another
+	///
+	/// This is the same as calling [`Tag::merge`] on `target`.
+	pub fn copy_into(&self, target: &mut Tag, options: MergeOptions) -> MergeReport {
+		merge::merge_tags(target, self, options)
+	}
+
+	/// Whether the tag carries native data from the tag it was converted from
+	///
+	/// See [Native data](Tag#native-data)
+	pub fn has_passthrough(&self) -> bool {
+		self.passthrough.is_some()
+	}
+
+	/// Discard the native data from the tag it was converted from
+	///
+	/// After this, converting back into the original format will only use the tag's items and pictures.
+	///
+	/// See [Native data](Tag#native-data)
+	pub fn clear_passthrough(&mut self) {
+		self.passthrough = None;
+	}
+
 	/// Returns the [`TagType`]
 	pub fn tag_type(&self) -> TagType {
 		self.tag_type
@@ -263,6 +532,44 @@ impl Tag {
 		None
 	}
 
+	/// Get an integer value from an [`ItemKey`]
+	///
+	/// This will use an [`ItemValue::Integer`] if present, otherwise it will attempt to
+	/// parse an [`ItemValue::Text`].
+	pub fn get_integer(&self, item_key: &ItemKey) -> Option<i64> {
+		match self.get_item_ref(item_key).map(TagItem::value)? {
+			ItemValue::Integer(int) => Some(*int),
+			ItemValue::Text(text) => text.trim().parse().ok(),
+			_ => None,
+		}
+	}
+
+	/// Get a boolean value from an [`ItemKey`]
+	///
+	/// This will use an [`ItemValue::Bool`] if present. Otherwise, a non-zero [`ItemValue::Integer`]
+	/// is `true`, and an [`ItemValue::Text`] of `"1"`, `"true"`, or `"yes"` (`"0"`, `"false"`, or `"no"`)
+	/// is `true` (`false`), ignoring case.
+	pub fn get_bool(&self, item_key: &ItemKey) -> Option<bool> {
+		match self.get_item_ref(item_key).map(TagItem::value)? {
+			ItemValue::Bool(flag) => Some(*flag),
+			ItemValue::Integer(int) => Some(*int != 0),
+			ItemValue::Text(text) => utils::parse_flag(text),
+			_ => None,
+		}
+	}
+
+	/// Get a [`Timestamp`] from an [`ItemKey`]
+	///
+	/// This will use an [`ItemValue::Date`] if present, otherwise it will attempt to
+	/// parse an [`ItemValue::Text`].
+	pub fn get_date(&self, item_key: &ItemKey) -> Option<Timestamp> {
+		match self.get_item_ref(item_key).map(TagItem::value)? {
+			ItemValue::Date(date) => Some(*date),
+			ItemValue::Text(text) => text.parse().ok(),
+			_ => None,
+		}
+	}
+
 	/// Gets a byte slice from an [`ItemKey`]
 	///
 	/// Use `convert` to convert [`ItemValue::Text`] and [`ItemValue::Locator`] to byte slices
@@ -415,6 +722,26 @@ impl Tag {
 		self.items.retain(f)
 	}
 
+	// Formats without typed storage write the typed values as text
+	pub(crate) fn typed_items_as_text(&self) -> Cow<'_, Self> {
+		if !self.items.iter().any(|i| i.item_value.is_typed()) {
+			return Cow::Borrowed(self);
+		}
+
+		let mut tag = self.clone();
+		tag.convert_typed_items_to_text();
+		Cow::Owned(tag)
+	}
+
+	pub(crate) fn convert_typed_items_to_text(&mut self) {
+		for item in &mut self.items {
+			if item.item_value.is_typed() {
+				let value = std::mem::replace(&mut item.item_value, ItemValue::Bool(false));
+				item.item_value = value.into_text_value();
+			}
+		}
+	}
+
 	/// Returns the stored [`Picture`]s as a slice
 	pub fn pictures(&self) -> &[Picture] {
 		&self.pictures
@@ -569,11 +896,13 @@ impl TagExt for Tag {
 	fn clear(&mut self) {
 		self.items.clear();
 		self.pictures.clear();
+		self.passthrough = None;
 	}
 }
 
 /// The tag's format
 #[derive(Copy, Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[non_exhaustive]
 pub enum TagType {
 	/// This covers both APEv1 and APEv2 as it doesn't matter much
@@ -582,6 +911,8 @@ pub enum TagType {
 	ID3v1,
 	/// This covers all ID3v2 versions since they all get upgraded to ID3v2.4
 	ID3v2,
+	/// This covers both Lyrics3v1 and Lyrics3v2, as Lyrics3v1 tags get upgraded to Lyrics3v2
+	Lyrics3,
 	/// Represents an MP4 ilst atom
 	MP4ilst,
 	/// Represents vorbis comments
@@ -678,4 +1009,172 @@ mod tests {
 		tag.set_title(String::new());
 		assert_eq!(tag.title(), None);
 	}
+
+	#[test]
+	fn musicbrainz_ids_between_formats() {
+		use crate::ape::ApeTag;
+		use crate::mp4::Ilst;
+		use crate::ogg::VorbisComments;
+		use crate::ItemKey;
+
+		let mut tag = Tag::new(TagType::VorbisComments);
+		tag.insert_text(
+			ItemKey::MusicBrainzRecordingId,
+			String::from("3bbd2f0c-7d0e-4f41-9f35-0d3f1b0e8c8a"),
+		);
+		tag.insert_text(
+			ItemKey::MusicBrainzReleaseGroupId,
+			String::from("0b2a5f11-5bc1-4a4d-8b9b-3c1d8e4f1a2b"),
+		);
+		tag.insert_text(ItemKey::MusicBrainzReleaseStatus, String::from("official"));
+		tag.insert_text(ItemKey::DiscogsReleaseId, String::from("249504"));
+
+		let vorbis_comments: VorbisComments = tag.clone().into();
+		assert_eq!(
+			vorbis_comments.get("MUSICBRAINZ_TRACKID"),
+			Some("3bbd2f0c-7d0e-4f41-9f35-0d3f1b0e8c8a")
+		);
+		assert_eq!(vorbis_comments.get("RELEASESTATUS"), Some("official"));
+
+		let ape: ApeTag = Tag::from(vorbis_comments).into();
+		assert_eq!(
+			ape.get_key("MUSICBRAINZ_RELEASEGROUPID")
+				.and_then(|item| item.value().text()),
+			Some("0b2a5f11-5bc1-4a4d-8b9b-3c1d8e4f1a2b")
+		);
+		assert_eq!(
+			ape.get_key("MUSICBRAINZ_ALBUMSTATUS")
+				.and_then(|item| item.value().text()),
+			Some("official")
+		);
+
+		let ilst: Ilst = Tag::from(ape).into();
+		let mut tag_from_ilst: Tag = ilst.into();
+		tag_from_ilst.re_map(TagType::VorbisComments);
+
+		assert_eq!(tag_from_ilst.items(), tag.items());
+	}
+
+	#[test]
+	fn typed_values_between_formats() {
+		use crate::id3::v2::{FrameValue, ID3v2Tag};
+		use crate::ogg::VorbisComments;
+		use crate::{ItemKey, ItemValue, TagItem, Timestamp};
+
+		let date: Timestamp = "2022-09-25T14:30".parse().unwrap();
+
+		let mut tag = Tag::new(TagType::MP4ilst);
+		tag.insert_item(TagItem::new(
+			ItemKey::FlagCompilation,
+			ItemValue::Bool(true),
+		));
+		tag.insert_item(TagItem::new(ItemKey::BPM, ItemValue::Integer(128)));
+		tag.insert_item(TagItem::new(ItemKey::RecordingDate, ItemValue::Date(date)));
+
+		let vorbis_comments: VorbisComments = tag.clone().into();
+		assert_eq!(vorbis_comments.get("COMPILATION"), Some("1"));
+		assert_eq!(vorbis_comments.get("BPM"), Some("128"));
+		assert_eq!(vorbis_comments.get("DATE"), Some("2022-09-25T14:30"));
+
+		// Text-based formats can still be read back as typed values
+		let tag_from_vorbis: Tag = vorbis_comments.into();
+		assert_eq!(
+			tag_from_vorbis.get_bool(&ItemKey::FlagCompilation),
+			Some(true)
+		);
+		assert_eq!(tag_from_vorbis.get_integer(&ItemKey::BPM), Some(128));
+		assert_eq!(
+			tag_from_vorbis.get_date(&ItemKey::RecordingDate),
+			Some(date)
+		);
+		assert_eq!(tag_from_vorbis.year(), Some(2022));
+
+		let id3v2: ID3v2Tag = tag.into();
+		assert!(matches!(
+			id3v2.get("TBPM").map(crate::id3::v2::Frame::content),
+			Some(FrameValue::Text { value, .. }) if value == "128"
+		));
+		assert!(matches!(
+			id3v2.get("TDRC").map(crate::id3::v2::Frame::content),
+			Some(FrameValue::Timestamp(frame)) if frame.timestamp == date
+		));
+	}
+
+	#[test]
+	fn extended_accessors_between_formats() {
+		use crate::ape::ApeTag;
+		use crate::id3::v2::ID3v2Tag;
+		use crate::mp4::Ilst;
+		use crate::ogg::VorbisComments;
+		use crate::{Rating, Timestamp};
+
+		fn verify<T: Accessor>(tag: &T, date: Timestamp) {
+			assert_eq!(tag.album_artist(), Some("Foo album artist"));
+			assert_eq!(tag.composer(), Some("Bar composer"));
+			assert_eq!(tag.lyrics(), Some("Baz lyrics"));
+			assert_eq!(tag.title_sort_order(), Some("Title, The"));
+			assert_eq!(tag.artist_sort_order(), Some("Artist, The"));
+			assert_eq!(tag.album_sort_order(), Some("Album, The"));
+			assert_eq!(tag.album_artist_sort_order(), Some("Album Artist, The"));
+			assert_eq!(tag.composer_sort_order(), Some("Composer, The"));
+			assert_eq!(tag.isrc(), Some("USRC17607839"));
+			assert_eq!(tag.label(), Some("Qux label"));
+			assert_eq!(tag.catalog_number(), Some("CAT-001"));
+			assert_eq!(tag.date(), Some(date));
+			assert_eq!(tag.bpm(), Some(128));
+			assert_eq!(tag.replay_gain_track_gain(), Some(-6.5));
+			assert_eq!(tag.replay_gain_track_peak(), Some(0.988_547));
+			assert_eq!(tag.replay_gain_album_gain(), Some(-7.25));
+			assert_eq!(tag.replay_gain_album_peak(), Some(1.0));
+			assert_eq!(tag.compilation(), Some(true));
+			assert_eq!(tag.rating(), Some(Rating::from_stars(4)));
+		}
+
+		let date: Timestamp = "2022-09-25T14:30".parse().unwrap();
+
+		let mut tag = Tag::new(TagType::VorbisComments);
+		tag.set_album_artist(String::from("Foo album artist"));
+		tag.set_composer(String::from("Bar composer"));
+		tag.set_lyrics(String::from("Baz lyrics"));
+		tag.set_title_sort_order(String::from("Title, The"));
+		tag.set_artist_sort_order(String::from("Artist, The"));
+		tag.set_album_sort_order(String::from("Album, The"));
+		tag.set_album_artist_sort_order(String::from("Album Artist, The"));
+		tag.set_composer_sort_order(String::from("Composer, The"));
+		tag.set_isrc(String::from("USRC17607839"));
+		tag.set_label(String::from("Qux label"));
+		tag.set_catalog_number(String::from("CAT-001"));
+		tag.set_date(date);
+		tag.set_bpm(128);
+		tag.set_replay_gain_track_gain(-6.5);
+		tag.set_replay_gain_track_peak(0.988_547);
+		tag.set_replay_gain_album_gain(-7.25);
+		tag.set_replay_gain_album_peak(1.0);
+		tag.set_compilation(true);
+		tag.set_rating(Rating::new(80));
+
+		verify(&tag, date);
+		assert_eq!(tag.year(), Some(2022));
+
+		let id3v2: ID3v2Tag = tag.clone().into();
+		verify(&id3v2, date);
+		verify(&Tag::from(id3v2), date);
+
+		let vorbis_comments: VorbisComments = tag.clone().into();
+		verify(&vorbis_comments, date);
+		verify(&Tag::from(vorbis_comments), date);
+
+		let ape: ApeTag = tag.clone().into();
+		verify(&ape, date);
+		verify(&Tag::from(ape), date);
+
+		let ilst: Ilst = tag.clone().into();
+		verify(&ilst, date);
+		verify(&Tag::from(ilst), date);
+
+		tag.remove_date();
+		tag.remove_compilation();
+		assert_eq!(tag.date(), None);
+		assert_eq!(tag.compilation(), None);
+	}
 }
@@ -0,0 +1,126 @@
+//! Native data carried by a [`Tag`] converted from a format-specific tag
+//!
+//! Converting a tag into a [`Tag`] can lose information, such as ID3v2 frame flags and encodings,
+//! or frames with no [`ItemKey`] at all. To avoid this, the original frames/atoms are kept alongside
+//! the items they were converted into. When converting back to the same format, any that are still
+//! accurately represented by the [`Tag`] (their items are unchanged) are re-emitted as-is, and only
+//! the rest are created from the items.
+//!
+//! Picture frames/atoms are not kept, as their data is already held in the [`Tag`]'s pictures. These
+//! are always created from the pictures.
+
+use crate::tag::item::{ItemKey, ItemValue, TagItem};
+use crate::tag::Tag;
+
+#[cfg(feature = "id3v2")]
+use crate::id3::v2::{Frame, ID3v2Tag};
+#[cfg(feature = "mp4_ilst")]
+use crate::mp4::Atom;
+
+#[derive(Clone)]
+pub(crate) enum Passthrough {
+	/// The tag holds everything but the frames (flags, version, etc.)
+	#[cfg(feature = "id3v2")]
+	ID3v2 {
+		tag: ID3v2Tag,
+		frames: NativeElements<Frame>,
+	},
+	#[cfg(feature = "mp4_ilst")]
+	Ilst(NativeElements<Atom>),
+}
+
+// What a native element was converted into
+#[derive(Clone)]
+struct Origin {
+	keys: Vec<ItemKey>,
+}
+
+/// The elements of a native tag, along with a snapshot of the [`Tag`] they were converted into
+#[derive(Clone)]
+pub(crate) struct NativeElements<T> {
+	elements: Vec<(T, Origin)>,
+	items: Vec<TagItem>,
+}
+
+impl<T> NativeElements<T> {
+	pub(crate) fn new() -> Self {
+		Self {
+			elements: Vec::new(),
+			items: Vec::new(),
+		}
+	}
+
+	/// Record an element, along with the items it was converted into
+	///
+	/// `items_start` is the length of the tag's items prior to converting the element.
+	pub(crate) fn record(&mut self, element: T, tag: &Tag, items_start: usize) {
+		let mut origin = Origin { keys: Vec::new() };
+
+		for item in &tag.items[items_start..] {
+			if !origin.keys.contains(item.key()) {
+				origin.keys.push(item.key().clone());
+			}
+		}
+
+		self.elements.push((element, origin));
+	}
+
+	/// Take a snapshot of the tag, once all elements have been recorded
+	pub(crate) fn finish(mut self, tag: &Tag) -> Self {
+		self.items.clone_from(&tag.items);
+		self
+	}
+
+	/// Returns the elements that `tag` still accurately represents
+	///
+	/// The items of these elements are removed from `tag`, leaving only those that need to be converted.
+	pub(crate) fn retain_unchanged(self, tag: &mut Tag) -> Vec<T> {
+		let mut regenerate = Vec::new();
+		for item in self.items.iter().chain(tag.items.iter()) {
+			let key = item.key();
+			if !regenerate.contains(key) && values(&self.items, key) != values(&tag.items, key) {
+				regenerate.push(key.clone());
+			}
+		}
+
+		// An element has to be converted again if *any* of its items changed, which
+		// means its other items have to be converted with it (ex. "TRCK" => TrackNumber + TrackTotal)
+		let mut keep = vec![true; self.elements.len()];
+		let mut settled = false;
+		while !settled {
+			settled = true;
+
+			for ((_, origin), keep) in self.elements.iter().zip(keep.iter_mut()) {
+				let changed = origin.keys.iter().any(|key| regenerate.contains(key));
+
+				if *keep && changed {
+					*keep = false;
+					settled = false;
+
+					for key in &origin.keys {
+						if !regenerate.contains(key) {
+							regenerate.push(key.clone());
+						}
+					}
+				}
+			}
+		}
+
+		tag.items.retain(|item| regenerate.contains(item.key()));
+
+		self.elements
+			.into_iter()
+			.zip(keep)
+			.filter_map(|((element, _), keep)| keep.then(|| element))
+			.collect()
+	}
+}
+
+// Typed values are compared by their text, as they are converted to text before writing
+fn values(items: &[TagItem], key: &ItemKey) -> Vec<ItemValue> {
+	items
+		.iter()
+		.filter(|item| item.key() == key)
+		.map(|item| item.value().clone().into_text_value())
+		.collect()
+}
@@ -0,0 +1,180 @@
+/// A format-neutral rating
+///
+/// Ratings are stored as a value out of 100, and can be created from or converted to
+/// a 0-5 star scale.
+///
+/// ## Format mappings
+///
+/// * ID3v2: `POPM` (0-255), using the Windows Media Player/MediaMonkey star buckets below
+/// * Vorbis Comments: `RATING` (0-100) or `FMPS_RATING` (0.0-1.0)
+/// * MP4: `----:com.apple.iTunes:rate` (0-100)
+/// * APE: `Rating` (0-100)
+/// * RIFF INFO: `IRTD` (0-100)
+///
+/// ### Popularimeter buckets
+///
+/// When reading a `POPM` frame, the 0-255 value is mapped to stars as follows:
+///
+/// | `POPM` value | Stars | Written as |
+/// |--------------|-------|------------|
+/// | 0            | None  | 0          |
+/// | 1-31         | 1     | 1          |
+/// | 32-95        | 2     | 64         |
+/// | 96-159       | 3     | 128        |
+/// | 160-223      | 4     | 196        |
+/// | 224-255      | 5     | 255        |
+///
+/// Since `POPM` can only represent whole stars, a rating will be rounded to the nearest star
+/// when written to ID3v2. Nonzero ratings are never rounded down to `0`.
+#[derive(Default, Copy, Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Rating(u8);
+
+impl Rating {
+	/// The maximum rating value
+	pub const MAX: u8 = 100;
+	/// The maximum number of stars
+	pub const MAX_STARS: u8 = 5;
+
+	const POPM_STAR_VALUES: [u8; 6] = [0, 1, 64, 128, 196, 255];
+
+	/// Create a `Rating` from a value out of 100
+	///
+	/// Values above 100 are clamped.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use lofty::Rating;
+	///
+	/// assert_eq!(Rating::new(80).stars(), 4);
+	/// assert_eq!(Rating::new(255).value(), 100);
+	/// ```
+	pub fn new(value: u8) -> Self {
+		Self(value.min(Self::MAX))
+	}
+
+	/// Create a `Rating` from a number of stars (0-5)
+	///
+	/// Values above 5 are clamped.
+	pub fn from_stars(stars: u8) -> Self {
+		Self(stars.min(Self::MAX_STARS) * (Self::MAX / Self::MAX_STARS))
+	}
+
+	/// Create a `Rating` from a `POPM` rating (0-255)
+	///
+	/// This will return `None` for `0`, which denotes an unknown rating.
+	///
+	/// See [`Rating`] for the mapping.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use lofty::Rating;
+	///
+	/// assert_eq!(Rating::from_popularimeter(0), None);
+	/// assert_eq!(Rating::from_popularimeter(196).map(Rating::stars), Some(4));
+	/// ```
+	pub fn from_popularimeter(rating: u8) -> Option<Self> {
+		let stars = match rating {
+			0 => return None,
+			1..=31 => 1,
+			32..=95 => 2,
+			96..=159 => 3,
+			160..=223 => 4,
+			224..=255 => 5,
+		};
+
+		Some(Self::from_stars(stars))
+	}
+
+	/// The rating, out of 100
+	pub fn value(self) -> u8 {
+		self.0
+	}
+
+	/// The rating in stars (0-5), rounded to the nearest star
+	pub fn stars(self) -> u8 {
+		let step = Self::MAX / Self::MAX_STARS;
+		(self.0 + step / 2) / step
+	}
+
+	/// The rating as a `POPM` rating (0-255)
+	///
+	/// See [`Rating`] for the mapping. Any nonzero rating is written as at least 1 star,
+	/// since a `POPM` value of `0` denotes an unknown rating.
+	pub fn as_popularimeter(self) -> u8 {
+		if self.0 == 0 {
+			return 0;
+		}
+
+		Self::POPM_STAR_VALUES[self.stars().max(1) as usize]
+	}
+
+	pub(crate) fn from_fmps(rating: &str) -> Option<Self> {
+		let rating = rating.trim().parse::<f32>().ok()?;
+		if !(0.0..=1.0).contains(&rating) {
+			return None;
+		}
+
+		Some(Self((rating * f32::from(Self::MAX)).round() as u8))
+	}
+
+	pub(crate) fn as_fmps(self) -> String {
+		format!("{}", f32::from(self.0) / f32::from(Self::MAX))
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::Rating;
+
+	#[test]
+	fn popularimeter_buckets() {
+		let expected = [
+			(1, 1, 1),
+			(31, 1, 1),
+			(32, 2, 64),
+			(95, 2, 64),
+			(96, 3, 128),
+			(159, 3, 128),
+			(160, 4, 196),
+			(223, 4, 196),
+			(224, 5, 255),
+			(255, 5, 255),
+		];
+
+		for (popm, stars, written) in expected {
+			let rating = Rating::from_popularimeter(popm).unwrap();
+			assert_eq!(rating.stars(), stars);
+			assert_eq!(rating.as_popularimeter(), written);
+		}
+
+		assert_eq!(Rating::from_popularimeter(0), None);
+		assert_eq!(Rating::new(0).as_popularimeter(), 0);
+	}
+
+	#[test]
+	fn popularimeter_low_ratings() {
+		for value in 1..=9 {
+			let popm = Rating::new(value).as_popularimeter();
+			assert_eq!(popm, 1);
+			assert_eq!(Rating::from_popularimeter(popm).map(Rating::stars), Some(1));
+		}
+	}
+
+	#[test]
+	fn stars() {
+		assert_eq!(Rating::from_stars(3).value(), 60);
+		assert_eq!(Rating::from_stars(9).value(), 100);
+		assert_eq!(Rating::new(69).stars(), 3);
+		assert_eq!(Rating::new(70).stars(), 4);
+	}
+
+	#[test]
+	fn fmps() {
+		assert_eq!(Rating::from_fmps("0.8"), Some(Rating::new(80)));
+		assert_eq!(Rating::from_fmps("1.5"), None);
+		assert_eq!(Rating::from_fmps("-1"), None);
+		assert_eq!(Rating::new(60).as_fmps(), "0.6");
+	}
+}
@@ -0,0 +1,297 @@
+use crate::error::{ErrorKind, LoftyError, Result};
+use crate::macros::err;
+
+use std::fmt::{Display, Formatter};
+use std::str::FromStr;
+
+/// A subset of the ISO 8601 timestamp format
+///
+/// This follows the format used by ID3v2.4, where a timestamp may have any level of precision,
+/// from just a year up to the second:
+///
+/// * `yyyy`
+/// * `yyyy-MM`
+/// * `yyyy-MM-dd`
+/// * `yyyy-MM-ddTHH`
+/// * `yyyy-MM-ddTHH:mm`
+/// * `yyyy-MM-ddTHH:mm:ss`
+///
+/// When parsing, a space is also accepted in place of the `T`, and a trailing `Z` is ignored,
+/// as they are commonly found in other formats. A timestamp is always displayed in the format above.
+///
+/// NOTE: A field can only be present if all fields before it are present. When displaying,
+/// anything after the first missing field will be ignored.
+///
+/// # Examples
+///
+/// ```rust
+/// use lofty::Timestamp;
+///
+/// # fn main() -> lofty::Result<()> {
+/// let timestamp: Timestamp = "2022-09-25T14:30".parse()?;
+/// assert_eq!(timestamp.year, 2022);
+/// assert_eq!(timestamp.day, Some(25));
+/// assert_eq!(timestamp.second, None);
+///
+/// assert_eq!(timestamp.to_string(), "2022-09-25T14:30");
+/// # Ok(()) }
+/// ```
+#[derive(Default, Copy, Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Timestamp {
+	/// The year, 0-9999
+	pub year: u16,
+	/// The month, 1-12
+	pub month: Option<u8>,
+	/// The day of the month, 1-31
+	pub day: Option<u8>,
+	/// The hour, 0-23
+	pub hour: Option<u8>,
+	/// The minute, 0-59
+	pub minute: Option<u8>,
+	/// The second, 0-59
+	pub second: Option<u8>,
+}
+
+impl Timestamp {
+	/// The maximum length of a timestamp in characters (`yyyy-MM-ddTHH:mm:ss`)
+	pub const MAX_LENGTH: usize = 19;
+
+	/// Create a `Timestamp` with only a year
+	pub fn from_year(year: u16) -> Self {
+		Self {
+			year,
+			..Self::default()
+		}
+	}
+
+	/// Verify that all fields are within their ranges, and that no field is missing a preceding field
+	///
+	/// # Errors
+	///
+	/// * Any of the conditions in the fields' documentation are not met
+	pub fn verify(&self) -> Result<()> {
+		if self.year > 9999 {
+			err!(BadTimestamp("Year is out of range (0-9999)"));
+		}
+
+		let fields = [
+			(self.month, 1, 12, "Month is out of range (1-12)"),
+			(self.day, 1, 31, "Day is out of range (1-31)"),
+			(self.hour, 0, 23, "Hour is out of range (0-23)"),
+			(self.minute, 0, 59, "Minute is out of range (0-59)"),
+			(self.second, 0, 59, "Second is out of range (0-59)"),
+		];
+
+		let mut previous_present = true;
+		for (field, min, max, message) in fields {
+			match field {
+				Some(_) if !previous_present => {
+					err!(BadTimestamp(
+						"Encountered a field without its preceding field"
+					))
+				},
+				Some(value) if value < min || value > max => {
+					return Err(LoftyError::new(ErrorKind::BadTimestamp(message)))
+				},
+				_ => previous_present = field.is_some(),
+			}
+		}
+
+		Ok(())
+	}
+}
+
+impl FromStr for Timestamp {
+	type Err = LoftyError;
+
+	fn from_str(s: &str) -> Result<Self> {
+		fn parse_field(field: &str, len: usize) -> Result<u16> {
+			if field.len() != len || !field.bytes().all(|b| b.is_ascii_digit()) {
+				err!(BadTimestamp("Expected a fixed number of digits"));
+			}
+
+			Ok(field
+				.bytes()
+				.fold(0, |acc, b| acc * 10 + u16::from(b - b'0')))
+		}
+
+		let s = s.trim();
+		let s = s.strip_suffix('Z').unwrap_or(s);
+
+		let (date, time) = match s.find(['T', ' ']) {
+			Some(pos) => (&s[..pos], Some(&s[pos + 1..])),
+			None => (s, None),
+		};
+
+		let date_fields = date.split('-').collect::<Vec<_>>();
+		let time_fields = time.map(|time| time.split(':').collect::<Vec<_>>());
+
+		if date_fields.len() > 3 {
+			err!(BadTimestamp("Date has too many fields"));
+		}
+
+		// A time can only be present with a full date
+		if let Some(ref time_fields) = time_fields {
+			if date_fields.len() != 3 {
+				err!(BadTimestamp("Timestamp has a time without a full date"));
+			}
+
+			if time_fields.len() > 3 {
+				err!(BadTimestamp("Time has too many fields"));
+			}
+		}
+
+		let mut timestamp = Timestamp {
+			year: parse_field(date_fields[0], 4)?,
+			..Timestamp::default()
+		};
+
+		let remaining_fields = date_fields[1..].iter().chain(time_fields.iter().flatten());
+
+		for (target, field) in [
+			&mut timestamp.month,
+			&mut timestamp.day,
+			&mut timestamp.hour,
+			&mut timestamp.minute,
+			&mut timestamp.second,
+		]
+		.into_iter()
+		.zip(remaining_fields)
+		{
+			*target = Some(parse_field(field, 2)? as u8);
+		}
+
+		timestamp.verify()?;
+		Ok(timestamp)
+	}
+}
+
+impl Display for Timestamp {
+	fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+		write!(f, "{:04}", self.year)?;
+
+		let fields = [
+			("-", self.month),
+			("-", self.day),
+			("T", self.hour),
+			(":", self.minute),
+			(":", self.second),
+		];
+
+		for (separator, field) in fields {
+			match field {
+				Some(value) => write!(f, "{separator}{value:02}")?,
+				None => break,
+			}
+		}
+
+		Ok(())
+	}
+}
+
+// Timestamps are (de)serialized in their ISO 8601 form, ex. "2022-09-25T14:30"
+#[cfg(feature = "serde")]
+impl serde::Serialize for Timestamp {
+	fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+	where
+		S: serde::Serializer,
+	{
+		serializer.collect_str(self)
+	}
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for Timestamp {
+	fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+	where
+		D: serde::Deserializer<'de>,
+	{
+		let timestamp = String::deserialize(deserializer)?;
+		timestamp.parse().map_err(serde::de::Error::custom)
+	}
+}
+
+// Gets the year from a date string, falling back to its first 4 characters if it isn't a valid `Timestamp`
+pub(crate) fn year_from_date(date: &str) -> Option<u32> {
+	match date.parse::<Timestamp>() {
+		Ok(timestamp) => Some(u32::from(timestamp.year)),
+		Err(_) => date.chars().take(4).collect::<String>().parse::<u32>().ok(),
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use crate::Timestamp;
+
+	#[test]
+	fn parse_and_display() {
+		let valid = [
+			"1984",
+			"1984-05",
+			"1984-05-01",
+			"1984-05-01T08",
+			"1984-05-01T08:30",
+			"1984-05-01T08:30:59",
+		];
+
+		for timestamp in valid {
+			assert_eq!(
+				timestamp.parse::<Timestamp>().unwrap().to_string(),
+				timestamp
+			);
+		}
+
+		let timestamp: Timestamp = "1984-05-01 08:30:59Z".parse().unwrap();
+		assert_eq!(
+			timestamp,
+			Timestamp {
+				year: 1984,
+				month: Some(5),
+				day: Some(1),
+				hour: Some(8),
+				minute: Some(30),
+				second: Some(59),
+			}
+		);
+		assert_eq!(timestamp.to_string(), "1984-05-01T08:30:59");
+	}
+
+	#[test]
+	fn parse_invalid() {
+		let invalid = [
+			"",
+			"84",
+			"1984-5",
+			"1984-13",
+			"1984-05-32",
+			"1984-05-01T24",
+			"1984-05T08",
+			"1984-05-01T08:30:59:00",
+			"1984/05/01",
+			"1984-05-01T08:60",
+		];
+
+		for timestamp in invalid {
+			assert!(
+				timestamp.parse::<Timestamp>().is_err(),
+				"{} should be invalid",
+				timestamp
+			);
+		}
+	}
+
+	#[test]
+	fn verify() {
+		let missing_day = Timestamp {
+			year: 1984,
+			month: Some(5),
+			hour: Some(8),
+			..Timestamp::default()
+		};
+		assert!(missing_day.verify().is_err());
+		// Fields after the first missing one are ignored
+		assert_eq!(missing_day.to_string(), "1984-05");
+
+		assert!(Timestamp::from_year(1984).verify().is_ok());
+	}
+}
@@ -1,9 +1,13 @@
 use crate::error::Result;
 use crate::file::FileType;
 use crate::macros::err;
+use crate::tag::rating::Rating;
+use crate::tag::timestamp::Timestamp;
 use crate::tag::{Tag, TagType};
 use crate::{ape, iff, mpeg, wavpack};
 
+#[cfg(feature = "lyrics3")]
+use crate::id3::lyrics3::tag::Lyrics3TagRef;
 #[cfg(feature = "id3v1")]
 use crate::id3::v1::tag::Id3v1TagRef;
 #[cfg(feature = "id3v2")]
@@ -12,6 +16,8 @@ use crate::id3::v2::{self, tag::Id3v2TagRef, ID3v2TagFlags};
 use crate::mp4::Ilst;
 #[cfg(feature = "vorbis_comments")]
 use crate::ogg::tag::{create_vorbis_comments_ref, VorbisCommentsRef};
+#[cfg(feature = "id3v2")]
+use crate::traits::TagExt;
 #[cfg(feature = "ape")]
 use ape::tag::ApeTagRef;
 #[cfg(feature = "aiff_text_chunks")]
@@ -19,11 +25,19 @@ use iff::aiff::tag::AiffTextChunksRef;
 #[cfg(feature = "riff_info_list")]
 use iff::wav::tag::RIFFInfoListRef;
 
+use std::borrow::Cow;
 use std::fs::File;
 use std::io::Write;
 
 #[allow(unreachable_patterns)]
 pub(crate) fn write_tag(tag: &Tag, file: &mut File, file_type: FileType) -> Result<()> {
+	// Only MP4 stores typed values natively, everything else writes them as text
+	let tag = match file_type {
+		FileType::MP4 => Cow::Borrowed(tag),
+		_ => tag.typed_items_as_text(),
+	};
+	let tag: &Tag = &tag;
+
 	match file_type {
 		FileType::AIFF => iff::aiff::write::write_to(file, tag),
 		FileType::APE => ape::write::write_to(file, tag),
@@ -44,6 +58,12 @@ pub(crate) fn write_tag(tag: &Tag, file: &mut File, file_type: FileType) -> Resu
 
 #[allow(unreachable_patterns)]
 pub(crate) fn dump_tag<W: Write>(tag: &Tag, writer: &mut W) -> Result<()> {
+	let tag = match tag.tag_type() {
+		TagType::MP4ilst => Cow::Borrowed(tag),
+		_ => tag.typed_items_as_text(),
+	};
+	let tag: &Tag = &tag;
+
 	match tag.tag_type() {
 		#[cfg(feature = "ape")]
 		TagType::APE => ApeTagRef {
@@ -53,6 +73,10 @@ pub(crate) fn dump_tag<W: Write>(tag: &Tag, writer: &mut W) -> Result<()> {
 		.dump_to(writer),
 		#[cfg(feature = "id3v1")]
 		TagType::ID3v1 => Into::<Id3v1TagRef<'_>>::into(tag).dump_to(writer),
+		#[cfg(feature = "lyrics3")]
+		TagType::Lyrics3 => Into::<Lyrics3TagRef<'_>>::into(tag).dump_to(writer),
+		#[cfg(feature = "id3v2")]
+		TagType::ID3v2 if tag.has_passthrough() => v2::ID3v2Tag::from(tag.clone()).dump_to(writer),
 		#[cfg(feature = "id3v2")]
 		TagType::ID3v2 => Id3v2TagRef {
 			flags: ID3v2TagFlags::default(),
@@ -94,6 +118,83 @@ pub(crate) fn dump_tag<W: Write>(tag: &Tag, writer: &mut W) -> Result<()> {
 	}
 }
 
+// Conversions between the typed `Accessor` values and their text representations
+
+pub(crate) fn parse_number(number: &str) -> Option<u32> {
+	number.trim().parse::<u32>().ok()
+}
+
+pub(crate) fn format_number(number: u32) -> String {
+	number.to_string()
+}
+
+pub(crate) fn parse_date(date: &str) -> Option<Timestamp> {
+	date.parse::<Timestamp>().ok()
+}
+
+pub(crate) fn format_date(date: Timestamp) -> String {
+	date.to_string()
+}
+
+// Parses a ReplayGain gain (e.g. "-6.50 dB"), the unit is optional
+pub(crate) fn parse_replay_gain(gain: &str) -> Option<f32> {
+	let gain = gain.trim();
+	let gain = match gain.len().checked_sub(2) {
+		Some(unit_pos)
+			if gain.is_char_boundary(unit_pos) && gain[unit_pos..].eq_ignore_ascii_case("dB") =>
+		{
+			&gain[..unit_pos]
+		},
+		_ => gain,
+	};
+
+	gain.trim()
+		.parse::<f32>()
+		.ok()
+		.filter(|gain| gain.is_finite())
+}
+
+pub(crate) fn format_replay_gain(gain: f32) -> String {
+	format!("{gain:.2} dB")
+}
+
+pub(crate) fn parse_replay_gain_peak(peak: &str) -> Option<f32> {
+	peak.trim()
+		.parse::<f32>()
+		.ok()
+		.filter(|peak| peak.is_finite())
+}
+
+pub(crate) fn format_replay_gain_peak(peak: f32) -> String {
+	format!("{peak:.6}")
+}
+
+// Parses the common representations of a flag, such as "1" and "true"
+pub(crate) fn parse_flag(flag: &str) -> Option<bool> {
+	let flag = flag.trim();
+
+	if flag == "1" || flag.eq_ignore_ascii_case("true") || flag.eq_ignore_ascii_case("yes") {
+		Some(true)
+	} else if flag == "0" || flag.eq_ignore_ascii_case("false") || flag.eq_ignore_ascii_case("no") {
+		Some(false)
+	} else {
+		None
+	}
+}
+
+pub(crate) fn format_flag(flag: bool) -> String {
+	String::from(if flag { "1" } else { "0" })
+}
+
+// Ratings are out of 100, anything above that is clamped
+pub(crate) fn parse_rating(rating: &str) -> Option<Rating> {
+	parse_number(rating).map(|rating| Rating::new(rating.min(100) as u8))
+}
+
+pub(crate) fn format_rating(rating: Rating) -> String {
+	rating.value().to_string()
+}
+
 #[cfg(test)]
 // Used for tag conversion tests
 pub(crate) mod test_utils {
@@ -24,6 +24,14 @@ macro_rules! accessor_trait {
 		///
 		/// This attempts to only provide methods for items that all tags have in common,
 		/// but there may be exceptions.
+		///
+		/// Notes on the typed accessors:
+		///
+		/// * `date` is the full recording date, see [`Timestamp`](crate::Timestamp)
+		/// * ReplayGain gains are in dB, and are written as `"-6.50 dB"`. Peaks are written as `"0.988547"`.
+		/// * `rating` is format-neutral, see [`Rating`](crate::Rating) for how it maps to each format
+		///
+		/// Any items that a tag is unable to represent will always return `None`, and will not be set.
 		pub trait Accessor {
 			$(
 				accessor_trait! { @GETTER [$($name)+] $($ty),+ }
@@ -115,8 +123,21 @@ accessor_trait! {
 	[track]<u32>,			[track total]<u32>,
 	[disk]<u32>,		  	[disk total]<u32>,
 	[year]<u32>,			[comment]<&str, String>,
+	[album artist]<&str, String>,	[composer]<&str, String>,
+	[date]<Timestamp>,				[bpm]<u32>,
+	[lyrics]<&str, String>,
+	[title sort order]<&str, String>,			[artist sort order]<&str, String>,
+	[album sort order]<&str, String>,			[album artist sort order]<&str, String>,
+	[composer sort order]<&str, String>,
+	[replay gain track gain]<f32>,				[replay gain track peak]<f32>,
+	[replay gain album gain]<f32>,				[replay gain album peak]<f32>,
+	[isrc]<&str, String>,			[label]<&str, String>,
+	[catalog number]<&str, String>,	[compilation]<bool>,
+	[rating]<Rating>,
 }
 
+use crate::tag::rating::Rating;
+use crate::tag::timestamp::Timestamp;
 use crate::tag::Tag;
 
 use std::fs::File;
@@ -195,6 +216,54 @@ pub trait TagExt: Accessor + Into<Tag> + Sized {
 	///
 	/// NOTE: This will **not** remove any format-specific extras, such as flags
 	fn clear(&mut self);
+
+	/// Export the tag as text, in `KEY=value` lines
+	///
+	/// If `picture_dir` is provided, the pictures are written to files in it. Otherwise, they are not exported.
+	///
+	/// See [`export`](crate::export) for a description of the format.
+	///
+	/// # Errors
+	///
+	/// * `writer` is not writable
+	/// * A picture file could not be written
+	fn export_text<W: std::io::Write>(
+		&self,
+		writer: &mut W,
+		picture_dir: Option<&Path>,
+	) -> crate::error::Result<()>
+	where
+		Self: Clone,
+	{
+		crate::export::export_tag(&self.clone().into(), writer, picture_dir)
+	}
+
+	/// Import text produced by [`TagExt::export_text`], replacing the tag's items
+	///
+	/// Items that can't be exported, such as binary items, are kept. If `picture_dir` is provided,
+	/// the tag's pictures are replaced with those referenced in the text. Otherwise, they are left untouched.
+	///
+	/// See [`export`](crate::export) for a description of the format.
+	///
+	/// # Errors
+	///
+	/// * `reader` is not readable, or contains invalid UTF-8
+	/// * A line is malformed (see [`ErrorKind::TextDecode`](crate::error::ErrorKind::TextDecode))
+	/// * A picture file could not be read
+	fn import_text<R: std::io::BufRead>(
+		&mut self,
+		reader: &mut R,
+		picture_dir: Option<&Path>,
+	) -> crate::error::Result<()>
+	where
+		Self: Clone + From<Tag>,
+	{
+		let mut tag: Tag = self.clone().into();
+		crate::export::import_tag(&mut tag, reader, picture_dir)?;
+
+		*self = tag.into();
+		Ok(())
+	}
 }
 
 // TODO: https://github.com/rust-lang/rust/issues/59359
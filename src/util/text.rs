@@ -8,6 +8,7 @@ use byteorder::ReadBytesExt;
 
 /// The text encoding for use in ID3v2 frames
 #[derive(Debug, Clone, Eq, PartialEq, Copy, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[repr(u8)]
 pub enum TextEncoding {
 	/// ISO-8859-1
@@ -10,7 +10,7 @@ use crate::id3::v1::tag::ID3v1Tag;
 use lofty_attr::LoftyFile;
 
 // Exports
-pub use properties::WavPackProperties;
+pub use properties::{WavPackCompressionMode, WavPackProperties};
 
 /// A WavPack file
 #[derive(LoftyFile, Default)]
@@ -1,4 +1,5 @@
 use crate::error::Result;
+use crate::iff::ChannelMask;
 use crate::macros::{decode_err, err, parse_mode_choice, try_vec};
 use crate::probe::ParsingMode;
 use crate::properties::FileProperties;
@@ -6,9 +7,22 @@ use crate::properties::FileProperties;
 use std::io::{Read, Seek, SeekFrom};
 use std::time::Duration;
 
-use byteorder::{LittleEndian, ReadBytesExt};
+use byteorder::{BigEndian, LittleEndian, ReadBytesExt};
+
+/// The compression mode used to encode a WavPack file
+#[allow(missing_docs)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum WavPackCompressionMode {
+	Fast,
+	Normal,
+	High,
+	VeryHigh,
+}
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[allow(clippy::struct_excessive_bools)]
 #[non_exhaustive]
 /// A WavPack file's audio properties
 pub struct WavPackProperties {
@@ -20,8 +34,14 @@ pub struct WavPackProperties {
 	pub(crate) channels: u8,
 	pub(crate) bit_depth: u8,
 	pub(crate) lossless: bool,
+	pub(crate) float: bool,
+	pub(crate) dsd: bool,
+	pub(crate) correction_file: bool,
+	pub(crate) channel_mask: Option<ChannelMask>,
+	pub(crate) signature: Option<u128>,
+	pub(crate) compression_mode: Option<WavPackCompressionMode>,
+	pub(crate) extra_mode: Option<u8>,
 }
-
 impl From<WavPackProperties> for FileProperties {
 	fn from(input: WavPackProperties) -> Self {
 		Self {
@@ -61,15 +81,67 @@ impl WavPackProperties {
 		self.channels
 	}
 
+	/// Bits per sample
+	pub fn bit_depth(&self) -> u8 {
+		self.bit_depth
+	}
+
 	/// WavPack version
 	pub fn version(&self) -> u16 {
 		self.version
 	}
 
 	/// Whether the audio is lossless
+	///
+	/// This is `false` for files encoded in hybrid mode, even when a correction file exists.
 	pub fn is_lossless(&self) -> bool {
 		self.lossless
 	}
+
+	/// Whether the audio is encoded in hybrid mode
+	pub fn is_hybrid(&self) -> bool {
+		!self.lossless
+	}
+
+	/// Whether the encoder created a correction (`.wvc`) file alongside this one
+	///
+	/// NOTE: This is only known if the encoder stored its configuration in the file, and
+	/// doesn't mean the correction file still exists.
+	pub fn has_correction_file(&self) -> bool {
+		self.correction_file
+	}
+
+	/// Whether the samples are IEEE floating point
+	pub fn is_float(&self) -> bool {
+		self.float
+	}
+
+	/// Whether the audio is DSD
+	pub fn is_dsd(&self) -> bool {
+		self.dsd
+	}
+
+	/// The speaker positions of the channels, if specified
+	pub fn channel_mask(&self) -> Option<ChannelMask> {
+		self.channel_mask
+	}
+
+	/// The MD5 signature of the unencoded audio, if stored by the encoder
+	pub fn signature(&self) -> Option<u128> {
+		self.signature
+	}
+
+	/// The compression mode, if the encoder stored its configuration in the file
+	pub fn compression_mode(&self) -> Option<WavPackCompressionMode> {
+		self.compression_mode
+	}
+
+	/// The level of extra processing (`-x`) used, if any
+	///
+	/// This will be `Some(0)` if extra processing was used, but the level is unknown.
+	pub fn extra_mode(&self) -> Option<u8> {
+		self.extra_mode
+	}
 }
 
 // Thanks MultimediaWiki :)
@@ -84,6 +156,7 @@ const FLAG_FINAL_BLOCK: u32 = 0x1000;
 const FLAG_MONO: u32 = 0x0004;
 const FLAG_DSD: u32 = 0x8000_0000;
 const FLAG_HYBRID_COMPRESSION: u32 = 8; // Hybrid profile (lossy compression)
+const FLAG_FLOAT_DATA: u32 = 0x80;
 
 // https://wiki.multimedia.cx/index.php?title=WavPack#Metadata
 
@@ -93,6 +166,16 @@ const ID_FLAG_LARGE_SIZE: u8 = 0x80;
 const ID_MULTICHANNEL: u8 = 0x0D;
 const ID_NON_STANDARD_SAMPLE_RATE: u8 = 0x27;
 const ID_DSD: u8 = 0xE;
+const ID_CONFIG_BLOCK: u8 = 0x25;
+const ID_MD5_CHECKSUM: u8 = 0x26;
+
+// https://github.com/dbry/WavPack/blob/master/include/wavpack.h
+
+const CONFIG_FAST_FLAG: u32 = 0x200;
+const CONFIG_HIGH_FLAG: u32 = 0x800;
+const CONFIG_VERY_HIGH_FLAG: u32 = 0x1000;
+const CONFIG_CREATE_WVC: u32 = 0x80000;
+const CONFIG_EXTRA_MODE: u32 = 0x0200_0000;
 
 const MIN_STREAM_VERSION: u16 = 0x402;
 const MAX_STREAM_VERSION: u16 = 0x410;
@@ -111,46 +194,48 @@ where
 
 	let mut offset = 0;
 	let mut total_samples = 0;
-	loop {
+	let mut first_frame_read = false;
+	while offset + 32 <= stream_length {
 		reader.seek(SeekFrom::Start(offset))?;
 
 		let block_header;
 		match parse_wv_header(reader) {
 			Ok(header) => block_header = header,
-			_ => break,
+			// Anything after the first frame that isn't a block (junk, padding, etc.) is the end of the stream
+			Err(_) if first_frame_read => break,
+			Err(e) => {
+				parse_mode_choice!(
+					parse_mode,
+					STRICT: return Err(e),
+					DEFAULT: break
+				);
+			},
 		}
 
-		// Just skip any block with no samples
-		if block_header.samples == 0 {
-			offset += u64::from(block_header.block_size + 8);
+		offset += u64::from(block_header.block_size + 8);
+
+		// Blocks without samples only hold metadata, such as the MD5 signature, which is
+		// written after all of the audio. Otherwise, we only need the first frame.
+		if block_header.samples > 0 && first_frame_read {
 			continue;
 		}
 
-		let flags = block_header.flags;
-
-		let sample_rate_idx = ((flags >> 23) & 0xF) as usize;
-		let sample_rate = SAMPLE_RATES[sample_rate_idx];
-
-		// In the case of non-standard sample rates and DSD audio, we need to actually read the
-		// block to get the sample rate
-		if sample_rate == 0 || flags & FLAG_DSD == FLAG_DSD {
-			let mut block_contents = try_vec![0; (block_header.block_size - 24) as usize];
-			if reader.read_exact(&mut block_contents).is_err() {
-				parse_mode_choice!(
-					parse_mode,
-					STRICT: decode_err!(@BAIL WavPack, "Block size mismatch"),
-					DEFAULT: break
-				);
-			}
+		let mut block_contents = try_vec![0; (block_header.block_size - 24) as usize];
+		if reader.read_exact(&mut block_contents).is_err() {
+			parse_mode_choice!(
+				parse_mode,
+				STRICT: decode_err!(@BAIL WavPack, "Block size mismatch"),
+				DEFAULT: break
+			);
+		}
 
-			if get_extended_meta_info(reader, &mut properties, block_contents.len() as u64).is_err()
-			{
-				break;
-			}
-		} else {
-			properties.sample_rate = sample_rate;
+		if block_header.samples == 0 {
+			read_metadata_sub_blocks(&block_contents, &mut properties, parse_mode)?;
+			continue;
 		}
 
+		let flags = block_header.flags;
+
 		if (flags & FLAG_INITIAL_BLOCK) == FLAG_INITIAL_BLOCK {
 			if block_header.version < MIN_STREAM_VERSION
 				|| block_header.version > MAX_STREAM_VERSION
@@ -162,24 +247,31 @@ where
 				);
 			}
 
+			let sample_rate_idx = ((flags >> 23) & 0xF) as usize;
+			properties.sample_rate = SAMPLE_RATES[sample_rate_idx];
+
 			total_samples = block_header.total_samples;
 			properties.bit_depth = ((((flags & BYTES_PER_SAMPLE_MASK) + 1) * 8) - ((flags & BIT_DEPTH_SHIFT_MASK) >> BIT_DEPTH_SHL)) as u8;
 			properties.version = block_header.version;
 			properties.lossless = flags & FLAG_HYBRID_COMPRESSION == 0;
-		}
+			properties.float = flags & FLAG_FLOAT_DATA == FLAG_FLOAT_DATA;
+			properties.dsd = flags & FLAG_DSD == FLAG_DSD;
 
-		let is_mono = flags & FLAG_MONO > 0;
-		properties.channels = if is_mono { 1 } else { 2 };
+			// This will be replaced if the block has channel info, for files with more than 2 channels
+			properties.channels = if flags & FLAG_MONO > 0 { 1 } else { 2 };
 
-		if flags & FLAG_FINAL_BLOCK == FLAG_FINAL_BLOCK {
-			break;
+			// Non-standard sample rates, DSD audio, channel info, and the encoder's configuration
+			// are all stored in metadata sub-blocks
+			read_metadata_sub_blocks(&block_contents, &mut properties, parse_mode)?;
 		}
 
-		offset += u64::from(block_header.block_size + 8);
+		if flags & FLAG_FINAL_BLOCK == FLAG_FINAL_BLOCK {
+			first_frame_read = true;
+		}
 	}
 
 	if total_samples > 0 && properties.sample_rate > 0 {
-		let length = u64::from(total_samples * 1000 / properties.sample_rate);
+		let length = u64::from(total_samples) * 1000 / u64::from(properties.sample_rate);
 		properties.duration = Duration::from_millis(length);
 		properties.audio_bitrate = crate::div_ceil(stream_length * 8, length) as u32;
 
@@ -207,7 +299,6 @@ struct WVHeader {
 	flags: u32,
 }
 
-// TODO: for now, all errors are just discarded
 fn parse_wv_header<R>(reader: &mut R) -> Result<WVHeader>
 where
 	R: Read + Seek,
@@ -248,77 +339,130 @@ where
 	})
 }
 
-fn get_extended_meta_info<R>(
-	reader: &mut R,
+fn read_metadata_sub_blocks(
+	block_contents: &[u8],
 	properties: &mut WavPackProperties,
-	block_size: u64,
-) -> Result<()>
-where
-	R: Read + Seek,
-{
-	while reader.stream_position()? < block_size {
+	parse_mode: ParsingMode,
+) -> Result<()> {
+	if let Err(e) = get_extended_meta_info(&mut &*block_contents, properties) {
+		parse_mode_choice!(
+			parse_mode,
+			STRICT: return Err(e),
+			// Anything read before the malformed sub-block is kept
+			DEFAULT: {}
+		);
+	}
+
+	Ok(())
+}
+
+fn get_extended_meta_info(reader: &mut &[u8], properties: &mut WavPackProperties) -> Result<()> {
+	// The DSD rate multiplier has to be applied after any non-standard sample rate
+	let mut dsd_rate_shift = None;
+
+	while !reader.is_empty() {
 		let id = reader.read_u8()?;
 
 		let is_large = id & ID_FLAG_LARGE_SIZE > 0;
-		let mut size = if is_large {
+		let size = if is_large {
 			reader.read_u24::<LittleEndian>()? << 1
 		} else {
 			u32::from(reader.read_u8()?) << 1
-		};
+		} as usize;
+
+		if size > reader.len() {
+			decode_err!(@BAIL WavPack, "Encountered a metadata sub-block larger than its block");
+		}
+
+		let (mut content, remaining) = reader.split_at(size);
+		*reader = remaining;
 
 		if id & ID_FLAG_ODD_SIZE > 0 {
-			size -= 1;
+			if content.is_empty() {
+				decode_err!(@BAIL WavPack, "Encountered an invalid metadata sub-block size");
+			}
+
+			content = &content[..size - 1];
 		}
 
 		match id & 0x3F {
 			ID_NON_STANDARD_SAMPLE_RATE => {
-				properties.sample_rate = reader.read_u24::<LittleEndian>()?;
+				if content.len() < 3 {
+					decode_err!(@BAIL WavPack, "Encountered an invalid sample rate block size");
+				}
+
+				properties.sample_rate = content.read_u24::<LittleEndian>()?;
 			},
 			ID_DSD => {
-				if size <= 1 {
+				if content.len() <= 1 {
 					decode_err!(@BAIL WavPack, "Encountered an invalid DSD block size");
 				}
 
-				let rate_multiplier = u32::from(reader.read_u8()?);
-				if let (sample_rate, false) =
-					properties.sample_rate.overflowing_shl(rate_multiplier)
-				{
-					properties.sample_rate = sample_rate;
-				}
-
-				reader.seek(SeekFrom::Current(i64::from(size - 1)))?;
+				dsd_rate_shift = Some(u32::from(content[0]));
 			},
 			ID_MULTICHANNEL => {
-				if size <= 1 {
-					decode_err!(@BAIL WavPack, "Unable to extract channel information");
+				if content.is_empty() || content.len() > 7 {
+					decode_err!(@BAIL WavPack, "Encountered invalid channel info size");
 				}
 
-				properties.channels = reader.read_u8()?;
-				let s = size - 2;
-				match s {
-					0..=3 => {
-						reader.seek(SeekFrom::Current(i64::from(s + 1)))?;
-						continue;
-					},
-					4 | 5 => {},
-					_ => decode_err!(@BAIL WavPack, "Encountered invalid channel info size"),
+				let (channels, mask) = if content.len() >= 6 {
+					// Extended form, for up to 4096 channels
+					// Channel count (12 bits), stream count (12 bits), channel mask (24 or 32 bits)
+					let channels = (u16::from(content[0]) | (u16::from(content[2] & 0xF) << 8)) + 1;
+					(channels, &content[3..])
+				} else {
+					(u16::from(content[0]), &content[1..])
+				};
+
+				properties.channels = u8::try_from(channels).unwrap_or(u8::MAX);
+
+				if !mask.is_empty() {
+					let mask = mask
+						.iter()
+						.enumerate()
+						.fold(0, |mask, (i, b)| mask | (u32::from(*b) << (i * 8)));
+
+					properties.channel_mask = Some(ChannelMask(mask));
+				}
+			},
+			ID_CONFIG_BLOCK => {
+				if content.len() < 3 {
+					decode_err!(@BAIL WavPack, "Encountered an invalid config block size");
 				}
 
-				reader.seek(SeekFrom::Current(1))?;
+				let config = content.read_u24::<LittleEndian>()? << 8;
 
-				properties.channels |= reader.read_u8()? & 0xF;
-				properties.channels += 1;
+				properties.compression_mode = Some(if config & CONFIG_FAST_FLAG != 0 {
+					WavPackCompressionMode::Fast
+				} else if config & CONFIG_VERY_HIGH_FLAG != 0 {
+					WavPackCompressionMode::VeryHigh
+				} else if config & CONFIG_HIGH_FLAG != 0 {
+					WavPackCompressionMode::High
+				} else {
+					WavPackCompressionMode::Normal
+				});
 
-				// Skip the Microsoft channel mask
-				reader.seek(SeekFrom::Current(i64::from(s - 1)))?;
+				properties.correction_file = config & CONFIG_CREATE_WVC != 0;
+
+				if config & CONFIG_EXTRA_MODE != 0 {
+					// The level is only stored by newer encoders
+					properties.extra_mode = Some(content.first().copied().unwrap_or(0));
+				}
 			},
-			_ => {
-				reader.seek(SeekFrom::Current(i64::from(size)))?;
+			ID_MD5_CHECKSUM => {
+				if content.len() != 16 {
+					decode_err!(@BAIL WavPack, "Encountered an invalid MD5 checksum size");
+				}
+
+				properties.signature = Some(content.read_u128::<BigEndian>()?);
 			},
+			_ => {},
 		}
+	}
 
-		if id & ID_FLAG_ODD_SIZE > 0 {
-			reader.seek(SeekFrom::Current(1))?;
+	if let Some(shift) = dsd_rate_shift {
+		if let (sample_rate, false) = properties.sample_rate.overflowing_shl(shift) {
+			properties.sample_rate = sample_rate;
 		}
 	}
 
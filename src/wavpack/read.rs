@@ -4,8 +4,8 @@ use crate::ape::constants::APE_PREAMBLE;
 use crate::ape::header::read_ape_header;
 use crate::ape::tag::read::read_ape_tag;
 use crate::error::Result;
-use crate::id3::{find_id3v1, find_lyrics3v2, ID3FindResults};
-use crate::probe::ParseOptions;
+use crate::id3::{find_id3v1, find_lyrics3, ID3FindResults};
+use crate::probe::{ParseOptions, ParsingMode};
 
 use std::io::{Read, Seek, SeekFrom};
 
@@ -32,11 +32,11 @@ where
 		}
 	}
 
-	// Next, check for a Lyrics3v2 tag, and skip over it, as it's no use to us
-	let ID3FindResults(lyrics3_header, lyrics3v2_size) = find_lyrics3v2(reader)?;
+	// Next, check for a Lyrics3 tag, and skip over it, as it's no use to us
+	let ID3FindResults(lyrics3_header, _) = find_lyrics3(reader, false, ParsingMode::Strict)?;
 
-	if lyrics3_header.is_some() {
-		stream_length -= u64::from(lyrics3v2_size);
+	if let Some(lyrics3_header) = lyrics3_header {
+		stream_length -= u64::from(lyrics3_header.size);
 	}
 
 	// Next, search for an APE tag footer
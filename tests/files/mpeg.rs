@@ -103,3 +103,334 @@ fn remove_id3v1() {
 fn remove_ape() {
 	crate::remove_tag!("tests/files/assets/minimal/full_test.mp3", TagType::APE);
 }
+
+#[test]
+fn read_appended_id3v2() {
+	use lofty::id3::v2::ID3v2TagLocation;
+	use lofty::mpeg::MPEGFile;
+	use lofty::AudioFile;
+
+	let mut file = temp_file!("tests/files/assets/id3v2_appended.mp3");
+	let mpeg_file = MPEGFile::read_from(&mut file, ParseOptions::new()).unwrap();
+
+	let id3v2 = mpeg_file.id3v2().unwrap();
+	assert_eq!(id3v2.location(), ID3v2TagLocation::Appended);
+	assert_eq!(id3v2.artist(), Some("Foo artist"));
+	assert_eq!(id3v2.title(), Some("Foo title"));
+
+	// The appended tag sits before the ID3v1 tag
+	assert!(mpeg_file.id3v1().is_some());
+	assert!(mpeg_file.properties().duration().as_millis() > 0);
+}
+
+#[test]
+fn read_prepended_and_appended_id3v2() {
+	use lofty::id3::v2::ID3v2TagLocation;
+	use lofty::mpeg::MPEGFile;
+	use lofty::AudioFile;
+
+	// The prepended tag has a `SEEK` frame pointing to the appended tag
+	let mut file = temp_file!("tests/files/assets/id3v2_prepended_and_appended.mp3");
+	let mpeg_file = MPEGFile::read_from(&mut file, ParseOptions::new()).unwrap();
+
+	let id3v2 = mpeg_file.id3v2().unwrap();
+	assert_eq!(id3v2.location(), ID3v2TagLocation::PrependedAndAppended);
+	assert_eq!(id3v2.len(), 2);
+	assert!(id3v2.get("SEEK").is_none());
+
+	// The artist is replaced by the update tag
+	assert_eq!(id3v2.artist(), Some("Foo artist"));
+	assert_eq!(id3v2.title(), Some("Foo title"));
+}
+
+#[test]
+fn write_removes_appended_id3v2() {
+	use lofty::id3::v2::ID3v2TagLocation;
+	use lofty::mpeg::MPEGFile;
+	use lofty::AudioFile;
+
+	let mut file = temp_file!("tests/files/assets/id3v2_prepended_and_appended.mp3");
+	let mut mpeg_file = MPEGFile::read_from(&mut file, ParseOptions::new()).unwrap();
+
+	let id3v2 = mpeg_file.id3v2_mut().unwrap();
+	id3v2.set_artist(String::from("Bar artist"));
+
+	file.rewind().unwrap();
+	id3v2.save_to(&mut file).unwrap();
+
+	file.rewind().unwrap();
+	let mpeg_file = MPEGFile::read_from(&mut file, ParseOptions::new()).unwrap();
+
+	let id3v2 = mpeg_file.id3v2().unwrap();
+	assert_eq!(id3v2.location(), ID3v2TagLocation::Prepended);
+	assert_eq!(id3v2.artist(), Some("Bar artist"));
+	assert_eq!(id3v2.title(), Some("Foo title"));
+	assert!(mpeg_file.id3v1().is_some());
+}
+
+#[test]
+fn read_lyrics3v2() {
+	use lofty::id3::lyrics3::Lyrics3Version;
+	use lofty::mpeg::MPEGFile;
+	use lofty::AudioFile;
+
+	// The Lyrics3v2 tag sits between the APE and ID3v1 tags
+	let mut file = temp_file!("tests/files/assets/lyrics3v2.mp3");
+	let mpeg_file = MPEGFile::read_from(&mut file, ParseOptions::new()).unwrap();
+
+	let lyrics3 = mpeg_file.lyrics3().unwrap();
+	assert_eq!(lyrics3.original_version(), Lyrics3Version::V2);
+	assert_eq!(lyrics3.lyrics.as_deref(), Some("Foo lyrics\r\nBar lyrics"));
+	assert_eq!(
+		lyrics3.title(),
+		Some("Foo title that is longer than thirty bytes")
+	);
+	assert_eq!(lyrics3.artist(), Some("Bar artist"));
+
+	assert!(mpeg_file.id3v1().is_some());
+	assert!(mpeg_file.ape().is_some());
+}
+
+#[test]
+fn read_malformed_lyrics3v2() {
+	use lofty::mpeg::MPEGFile;
+	use lofty::{AudioFile, ParsingMode};
+
+	let mut content = std::fs::read("tests/files/assets/lyrics3v2.mp3").unwrap();
+
+	// Give the "EAR" field a size larger than the tag
+	let field = content
+		.windows(8)
+		.position(|window| window == b"EAR00010")
+		.unwrap();
+	content[field + 3..field + 8].copy_from_slice(b"99999");
+
+	assert!(MPEGFile::read_from(
+		&mut std::io::Cursor::new(&content),
+		ParseOptions::new().parsing_mode(ParsingMode::Strict)
+	)
+	.is_err());
+
+	// The tag is skipped, leaving the surrounding tags intact
+	let mpeg_file = MPEGFile::read_from(
+		&mut std::io::Cursor::new(&content),
+		ParseOptions::new().parsing_mode(ParsingMode::Relaxed),
+	)
+	.unwrap();
+
+	assert!(mpeg_file.lyrics3().is_none());
+	assert!(mpeg_file.id3v1().is_some());
+	assert!(mpeg_file.ape().is_some());
+}
+
+#[test]
+fn read_lyrics3v2_invalid_size() {
+	use lofty::mpeg::MPEGFile;
+	use lofty::{AudioFile, ParsingMode};
+
+	let mut content = std::fs::read("tests/files/assets/lyrics3v2.mp3").unwrap();
+
+	// Replace the size in the footer with something that isn't a number
+	let footer = content
+		.windows(9)
+		.rposition(|window| window == b"LYRICS200")
+		.unwrap();
+	content[footer - 6..footer].copy_from_slice(b"abcdef");
+
+	assert!(MPEGFile::read_from(
+		&mut std::io::Cursor::new(&content),
+		ParseOptions::new().parsing_mode(ParsingMode::Strict)
+	)
+	.is_err());
+
+	let mpeg_file = MPEGFile::read_from(
+		&mut std::io::Cursor::new(&content),
+		ParseOptions::new().parsing_mode(ParsingMode::Relaxed),
+	)
+	.unwrap();
+
+	assert!(mpeg_file.lyrics3().is_none());
+	assert!(mpeg_file.id3v1().is_some());
+}
+
+#[test]
+fn write_lyrics3v2() {
+	let mut file = temp_file!("tests/files/assets/lyrics3v2.mp3");
+
+	let mut tagged_file = Probe::new(&mut file)
+		.options(ParseOptions::new().read_properties(false))
+		.guess_file_type()
+		.unwrap()
+		.read()
+		.unwrap();
+
+	let tag = tagged_file.tag_mut(TagType::Lyrics3).unwrap();
+	tag.insert_text(ItemKey::Lyrics, String::from("Baz lyrics"));
+
+	file.rewind().unwrap();
+	tag.save_to(&mut file).unwrap();
+
+	file.rewind().unwrap();
+	let tagged_file = Probe::new(&mut file)
+		.options(ParseOptions::new().read_properties(false))
+		.guess_file_type()
+		.unwrap()
+		.read()
+		.unwrap();
+
+	let tag = tagged_file.tag(TagType::Lyrics3).unwrap();
+	assert_eq!(tag.get_string(&ItemKey::Lyrics), Some("Baz lyrics"));
+	assert_eq!(tag.artist(), Some("Bar artist"));
+
+	// The surrounding tags are untouched
+	crate::verify_artist!(tagged_file, tag, TagType::ID3v1, "Bar artist", 1);
+	crate::verify_artist!(tagged_file, tag, TagType::APE, "Baz artist", 1);
+}
+
+#[test]
+fn remove_lyrics3() {
+	crate::remove_tag!("tests/files/assets/lyrics3v2.mp3", TagType::Lyrics3);
+
+	let mut file = temp_file!("tests/files/assets/lyrics3v2.mp3");
+	TagType::Lyrics3.remove_from(&mut file).unwrap();
+
+	file.rewind().unwrap();
+	let tagged_file = Probe::new(&mut file)
+		.options(ParseOptions::new().read_properties(false))
+		.guess_file_type()
+		.unwrap()
+		.read()
+		.unwrap();
+
+	// The ID3v1 tag is still found at the end of the file
+	crate::verify_artist!(tagged_file, tag, TagType::ID3v1, "Bar artist", 1);
+}
+
+#[test]
+fn scan_frames() {
+	use lofty::mpeg::{BitrateMode, MPEGFile};
+	use lofty::AudioFile;
+	use std::io::Cursor;
+
+	let full_test = std::fs::read("tests/files/assets/minimal/full_test.mp3").unwrap();
+
+	// Remove the Xing header frame, and add some junk (with a false frame sync) between two frames
+	let mut stripped = full_test[..31].to_vec();
+	stripped.extend(&full_test[223..1000]);
+	stripped.extend([0; 50]);
+	stripped.extend([0xFF, 0xFB, 0x00, 0x00]);
+	stripped.extend(&full_test[1000..]);
+
+	let options = ParseOptions::new().scan_mpeg_frames(true);
+
+	// The estimate is based on the bitrate of the first frame
+	let mpeg_file = MPEGFile::read_from(&mut Cursor::new(&stripped), ParseOptions::new()).unwrap();
+	assert!(mpeg_file.properties().vbr_header().is_none());
+	assert_eq!(mpeg_file.properties().frame_count(), None);
+	assert_eq!(mpeg_file.properties().bitrate_mode(), None);
+	assert_ne!(mpeg_file.properties().duration().as_millis(), 1464);
+
+	let mpeg_file = MPEGFile::read_from(&mut Cursor::new(&stripped), options).unwrap();
+	assert_eq!(mpeg_file.properties().frame_count(), Some(61));
+	assert_eq!(
+		mpeg_file.properties().bitrate_mode(),
+		Some(BitrateMode::Vbr)
+	);
+	assert_eq!(mpeg_file.properties().duration().as_millis(), 1464);
+	assert_eq!(mpeg_file.properties().audio_bitrate(), 61);
+
+	// The Xing header frame isn't counted
+	let mpeg_file = MPEGFile::read_from(&mut Cursor::new(&full_test), options).unwrap();
+	assert_eq!(mpeg_file.properties().frame_count(), Some(61));
+	assert_eq!(mpeg_file.properties().duration().as_millis(), 1464);
+}
+
+#[test]
+fn verify_stream() {
+	use lofty::mpeg::{Layer, MPEGFile, StreamProblem};
+	use std::io::Cursor;
+
+	fn verify(data: &[u8]) -> lofty::mpeg::StreamReport {
+		MPEGFile::verify(&mut Cursor::new(data)).unwrap()
+	}
+
+	let mp3 = std::fs::read("tests/files/assets/minimal/full_test.mp3").unwrap();
+	let mp1 = std::fs::read("tests/files/assets/minimal/full_test.mp1").unwrap();
+
+	// The MP3 frames are located at 31..11527, and the MP1 frames are 576 bytes each
+	let report = verify(&mp3);
+	assert!(report.is_valid());
+	assert_eq!(report.frames(), 62);
+	assert_eq!((report.stream_start(), report.stream_end()), (31, 11527));
+
+	let report = verify(&mp1);
+	assert!(report.is_valid());
+	assert_eq!(report.crc_checked_frames(), 49);
+
+	// Junk and a corrupted frame header between frames
+	let mut data = mp3[..1087].to_vec();
+	data.extend([0; 10]);
+	data.extend(&mp3[1087..1375]);
+	data.extend([0xFF, 0xFF, 0xFF, 0xFF]);
+	data.extend(&mp3[1375..11527]);
+
+	let report = verify(&data);
+	assert_eq!(report.frames(), 62);
+	assert_eq!(
+		report.problems(),
+		&[
+			StreamProblem::Junk {
+				offset: 1087,
+				len: 10
+			},
+			StreamProblem::LostSync {
+				offset: 1385,
+				len: 4
+			},
+		]
+	);
+
+	// A truncated final frame
+	let report = verify(&mp3[..11481]);
+	assert_eq!(report.frames(), 61);
+	assert_eq!(
+		report.problems(),
+		&[StreamProblem::TruncatedFrame {
+			offset: 11431,
+			expected_len: 96,
+			available_len: 50,
+		}]
+	);
+
+	// A change from Layer III at 48kHz to Layer I at 32kHz
+	let mut data = mp3[..11527].to_vec();
+	data.extend(&mp1[..576 * 2]);
+
+	let report = verify(&data);
+	assert_eq!(report.frames(), 64);
+	assert_eq!(
+		report.problems(),
+		&[
+			StreamProblem::LayerChange {
+				offset: 11527,
+				from: Layer::Layer3,
+				to: Layer::Layer1
+			},
+			StreamProblem::SampleRateChange {
+				offset: 11527,
+				from: 48000,
+				to: 32000
+			},
+		]
+	);
+
+	// A corrupted bit allocation in a protected frame
+	let mut data = mp1.clone();
+	data[576 + 6] ^= 0xFF;
+
+	let report = verify(&data);
+	assert!(matches!(
+		report.problems(),
+		[StreamProblem::CrcMismatch { offset: 576, .. }]
+	));
+}
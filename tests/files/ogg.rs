@@ -164,3 +164,75 @@ fn flac_with_id3v2() {
 fn flac_remove_id3v2() {
 	crate::remove_tag!("tests/files/assets/flac_with_id3v2.flac", TagType::ID3v2);
 }
+
+#[test]
+fn opus_r128_gain() {
+	use lofty::ogg::OpusFile;
+	use lofty::AudioFile;
+
+	let mut file = temp_file!("tests/files/assets/minimal/full_test.opus");
+
+	let mut tagged_file = Probe::new(&mut file)
+		.options(ParseOptions::new().read_properties(false))
+		.guess_file_type()
+		.unwrap()
+		.read()
+		.unwrap();
+
+	let tag = tagged_file.primary_tag_mut().unwrap();
+	tag.insert_text(ItemKey::ReplayGainTrackGain, String::from("-6.50 dB"));
+
+	file.rewind().unwrap();
+	tag.save_to(&mut file).unwrap();
+
+	// ReplayGain is stored as an R128 gain, relative to -23 LUFS
+	file.rewind().unwrap();
+	let opus_file = OpusFile::read_from(&mut file, ParseOptions::new()).unwrap();
+
+	let vorbis_comments = opus_file.vorbis_comments();
+	assert_eq!(vorbis_comments.get("R128_TRACK_GAIN"), Some("-2944"));
+	assert!(vorbis_comments.get("REPLAYGAIN_TRACK_GAIN").is_none());
+
+	// And converted back when reading into a `Tag`
+	file.rewind().unwrap();
+	let tagged_file = Probe::new(&mut file)
+		.guess_file_type()
+		.unwrap()
+		.read()
+		.unwrap();
+
+	assert_eq!(
+		tagged_file
+			.primary_tag()
+			.unwrap()
+			.get_string(&ItemKey::ReplayGainTrackGain),
+		Some("-6.500 dB")
+	);
+
+	// R128 gains are unchanged after being read into a `Tag` and written back
+	let mut vorbis_comments = opus_file.vorbis_comments().clone();
+	vorbis_comments.insert(String::from("R128_TRACK_GAIN"), String::from("-2943"), true);
+
+	file.rewind().unwrap();
+	vorbis_comments.save_to(&mut file).unwrap();
+
+	file.rewind().unwrap();
+	let mut tagged_file = Probe::new(&mut file)
+		.guess_file_type()
+		.unwrap()
+		.read()
+		.unwrap();
+
+	let tag = tagged_file.primary_tag_mut().unwrap();
+	tag.insert_text(ItemKey::TrackTitle, String::from("Bar title"));
+
+	file.rewind().unwrap();
+	tag.save_to(&mut file).unwrap();
+
+	file.rewind().unwrap();
+	let opus_file = OpusFile::read_from(&mut file, ParseOptions::new()).unwrap();
+	assert_eq!(
+		opus_file.vorbis_comments().get("R128_TRACK_GAIN"),
+		Some("-2943")
+	);
+}
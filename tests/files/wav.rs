@@ -68,3 +68,47 @@ fn remove_riff_info() {
 		TagType::RIFFInfo
 	);
 }
+
+#[test]
+fn write_native_id3v2_frames() {
+	use lofty::id3::v2::{Frame, FrameFlags, FrameValue, ID3v2Tag, TextEncoding};
+	use lofty::iff::WavFile;
+	use lofty::{Accessor, AudioFile};
+
+	let mut file = temp_file!("tests/files/assets/minimal/wav_format_pcm.wav");
+
+	// The encoding can only be kept through the native data
+	let album = Frame::new(
+		"TALB",
+		FrameValue::Text {
+			encoding: TextEncoding::UTF16,
+			value: String::from("Foo album"),
+		},
+		FrameFlags::default(),
+	)
+	.unwrap();
+
+	let mut id3v2 = ID3v2Tag::default();
+	id3v2.insert(album.clone());
+	id3v2.save_to(&mut file).unwrap();
+
+	file.rewind().unwrap();
+	let mut tagged_file = Probe::new(&mut file)
+		.options(ParseOptions::new().read_properties(false))
+		.guess_file_type()
+		.unwrap()
+		.read()
+		.unwrap();
+
+	let tag = tagged_file.tag_mut(TagType::ID3v2).unwrap();
+	tag.set_artist(String::from("Baz artist"));
+
+	file.rewind().unwrap();
+	tag.save_to(&mut file).unwrap();
+
+	file.rewind().unwrap();
+	let parsed = WavFile::read_from(&mut file, ParseOptions::new().read_properties(false)).unwrap();
+	let id3v2 = parsed.id3v2().unwrap();
+	assert_eq!(id3v2.artist(), Some("Baz artist"));
+	assert_eq!(id3v2.get("TALB").map(Frame::content), Some(album.content()));
+}
@@ -0,0 +1,175 @@
+#![cfg(feature = "serde")]
+
+use lofty::ape::{ApeFile, ApeItem, ApeTag};
+use lofty::flac::FlacFile;
+use lofty::id3::v2::{ID3v2Tag, ID3v2Version};
+use lofty::iff::{AiffFile, WavFile};
+use lofty::mp4::{Atom, AtomData, AtomIdent, Ilst, Mp4File};
+use lofty::mpeg::MPEGFile;
+use lofty::ogg::{OpusFile, SpeexFile, VorbisFile};
+use lofty::wavpack::WavPackFile;
+use lofty::{
+	Accessor, AudioFile, ItemKey, ItemValue, MimeType, ParseOptions, Picture, PictureType, Tag,
+	TagItem, TagType, Timestamp, WithoutPictureData,
+};
+
+use std::fmt::Debug;
+use std::fs::File;
+
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+
+// Serializes and deserializes a value, verifying that nothing changed in the process
+fn round_trip<T: Serialize + DeserializeOwned>(value: &T) -> T {
+	let json = serde_json::to_string(value).unwrap();
+	let deserialized: T = serde_json::from_str(&json).unwrap();
+	assert_eq!(serde_json::to_string(&deserialized).unwrap(), json);
+
+	deserialized
+}
+
+fn round_trip_eq<T: Serialize + DeserializeOwned + PartialEq + Debug>(value: &T) {
+	assert_eq!(&round_trip(value), value);
+}
+
+fn read<F: AudioFile>(path: &str) -> F {
+	let mut file = File::open(path).unwrap();
+	F::read_from(&mut file, ParseOptions::new()).unwrap()
+}
+
+#[test]
+fn tags_and_properties() {
+	let paths = [
+		"tests/files/assets/minimal/full_test.aiff",
+		"tests/files/assets/minimal/full_test.ape",
+		"tests/files/assets/minimal/full_test.flac",
+		"tests/files/assets/minimal/full_test.mp3",
+		"tests/files/assets/minimal/full_test.ogg",
+		"tests/files/assets/minimal/full_test.opus",
+		"tests/files/assets/minimal/full_test.spx",
+		"tests/files/assets/minimal/full_test.wv",
+		"tests/files/assets/minimal/m4a_codec_aac.m4a",
+		"tests/files/assets/minimal/wav_format_pcm.wav",
+	];
+
+	for path in paths {
+		let tagged_file = lofty::read_from_path(path).unwrap();
+
+		for tag in tagged_file.tags() {
+			let deserialized = round_trip(tag);
+			assert_eq!(deserialized.tag_type(), tag.tag_type());
+			assert_eq!(deserialized.items(), tag.items());
+			assert_eq!(deserialized.pictures(), tag.pictures());
+		}
+
+		round_trip_eq(tagged_file.properties());
+	}
+}
+
+#[test]
+fn format_properties() {
+	round_trip_eq(read::<AiffFile>("tests/files/assets/minimal/full_test.aiff").properties());
+	round_trip_eq(read::<ApeFile>("tests/files/assets/minimal/full_test.ape").properties());
+	round_trip_eq(read::<FlacFile>("tests/files/assets/minimal/full_test.flac").properties());
+	round_trip_eq(read::<MPEGFile>("tests/files/assets/minimal/full_test.mp3").properties());
+	round_trip_eq(read::<Mp4File>("tests/files/assets/minimal/m4a_codec_aac.m4a").properties());
+	round_trip_eq(read::<OpusFile>("tests/files/assets/minimal/full_test.opus").properties());
+	round_trip_eq(read::<SpeexFile>("tests/files/assets/minimal/full_test.spx").properties());
+	round_trip_eq(read::<VorbisFile>("tests/files/assets/minimal/full_test.ogg").properties());
+	round_trip_eq(read::<WavFile>("tests/files/assets/minimal/wav_format_pcm.wav").properties());
+	round_trip_eq(read::<WavPackFile>("tests/files/assets/minimal/full_test.wv").properties());
+}
+
+#[test]
+fn native_tags() {
+	let mpeg_file = read::<MPEGFile>("tests/files/assets/minimal/full_test.mp3");
+	round_trip_eq(mpeg_file.id3v2().unwrap());
+	round_trip_eq(mpeg_file.ape().unwrap());
+
+	let mp4_file = read::<Mp4File>("tests/files/assets/minimal/m4a_codec_aac.m4a");
+	round_trip_eq(mp4_file.ilst().unwrap());
+
+	let flac_file = read::<FlacFile>("tests/files/assets/minimal/full_test.flac");
+	round_trip_eq(flac_file.vorbis_comments().unwrap());
+
+	// A tag with frames that have no generic equivalent
+	let mut id3v2 = ID3v2Tag::default();
+	id3v2.set_title(String::from("Foo title"));
+	id3v2.set_date("2022-09-25T14:30".parse().unwrap());
+	id3v2.set_rating(lofty::Rating::from_stars(4));
+	id3v2.set_genre(String::from("(17)Rock"));
+	assert_eq!(round_trip(&id3v2).original_version(), ID3v2Version::V4);
+	round_trip_eq(&id3v2);
+}
+
+#[test]
+fn stable_representation() {
+	let mut tag = Tag::new(TagType::ID3v2);
+	tag.set_title(String::from("Foo title"));
+	tag.insert_item(TagItem::new(
+		ItemKey::RecordingDate,
+		ItemValue::Date(Timestamp {
+			year: 2022,
+			month: Some(9),
+			..Timestamp::default()
+		}),
+	));
+	tag.push_picture(Picture::new_unchecked(
+		PictureType::CoverFront,
+		MimeType::Png,
+		Some(String::from("Foo")),
+		vec![1, 2, 3],
+	));
+
+	assert_eq!(
+		serde_json::to_string(&tag).unwrap(),
+		concat!(
+			r#"{"tag_type":"ID3v2","#,
+			r#""pictures":[{"pic_type":"CoverFront","mime_type":"Png","description":"Foo","data":"AQID"}],"#,
+			r#""items":[{"item_key":"TrackTitle","item_value":{"Text":"Foo title"}},"#,
+			r#"{"item_key":"RecordingDate","item_value":{"Date":"2022-09"}}]}"#
+		)
+	);
+
+	// Picture data can be left out
+	let without_data = serde_json::to_string(&WithoutPictureData(&tag)).unwrap();
+	assert!(without_data.contains(
+		r#""pictures":[{"pic_type":"CoverFront","mime_type":"Png","description":"Foo"}]"#
+	));
+
+	let deserialized: Tag = serde_json::from_str(&without_data).unwrap();
+	assert_eq!(deserialized.pictures()[0].data(), &[] as &[u8]);
+	assert_eq!(deserialized.title(), Some("Foo title"));
+}
+
+// Native tag items are deserialized through their constructors, so invalid input has to be rejected
+#[test]
+fn invalid_native_items() {
+	fn assert_rejected<T: Serialize + DeserializeOwned>(value: &T, from: &str, to: &str) {
+		let json = serde_json::to_string(value).unwrap();
+		assert!(json.contains(from));
+		assert!(serde_json::from_str::<T>(&json.replace(from, to)).is_err());
+	}
+
+	let mut ape = ApeTag::default();
+	ape.insert(ApeItem::new(String::from("Foo"), ItemValue::Text(String::from("Bar"))).unwrap());
+	round_trip_eq(&ape);
+	assert_rejected(&ape, r#""key":"Foo""#, r#""key":"ID3""#);
+	assert_rejected(&ape, r#""key":"Foo""#, r#""key":"F""#);
+
+	let mut id3v2 = ID3v2Tag::default();
+	id3v2.set_title(String::from("Foo title"));
+	assert_rejected(&id3v2, r#"{"Valid":"TIT2"}"#, r#"{"Valid":"tit2"}"#);
+	assert_rejected(&id3v2, r#"{"Valid":"TIT2"}"#, r#"{"Valid":"TT2"}"#);
+	assert_rejected(&id3v2, r#"{"Valid":"TIT2"}"#, r#"{"Outdated":"TIT2"}"#);
+	// A text value can't be used for a picture frame
+	assert_rejected(&id3v2, r#"{"Valid":"TIT2"}"#, r#"{"Valid":"APIC"}"#);
+
+	let mut ilst = Ilst::default();
+	ilst.insert_atom(Atom::new(
+		AtomIdent::Fourcc(*b"\xa9nam"),
+		AtomData::UTF8(String::from("Foo title")),
+	));
+	round_trip_eq(&ilst);
+	assert_rejected(&ilst, r#""data":{"UTF8":"Foo title"}"#, r#""data":[]"#);
+}